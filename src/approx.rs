@@ -1,28 +1,83 @@
 use std::borrow::Cow;
+use std::cmp::Ordering;
 use std::ffi::c_int;
 use std::hint::unreachable_unchecked;
 
 use crate::{
     err::{BindingErrorCode, ErrorKind, RegexError, Result},
+    exec::check_len,
+    owned::IntoOwnedMatches,
     tre, Regex, RegexecFlags,
 };
 
 pub type RegApproxMatchStr<'a> = RegApproxMatch<&'a str, Result<Cow<'a, str>>>;
 pub type RegApproxMatchBytes<'a> = RegApproxMatch<&'a [u8], Cow<'a, [u8]>>;
+pub type RegApproxMatchStrOwned = RegApproxMatch<String, Result<String>>;
+pub type RegApproxMatchBytesOwned = RegApproxMatch<Vec<u8>, Vec<u8>>;
 
 /// Regex params passed to approximate matching functions such as [`regaexec`]
+///
+/// # Edits at the ends of a match
+/// In TRE's terms, an insertion is an extra character in the string which is not in the
+/// pattern, and a deletion is a character of the pattern which is missing from the string.
+///
+/// TRE has no setting for how edits at the start or end of the string are treated; it follows
+/// from whether the pattern is anchored:
+/// * Without anchors, text before or after the match is simply left out of it, at no cost. So
+///   `hello` matches `"hello "` exactly, with the match ending before the space.
+/// * With anchors, that text has to be part of the match, so each extra character is an
+///   insertion. So `^hello$` only matches `"hello "` if one insertion is allowed by `cost_ins`,
+///   `max_ins`, `max_cost`, and `max_err`. A trailing space is enough to turn a match into no
+///   match when no insertions are allowed.
+/// * A missing character at the end of the string is a deletion either way, as the pattern
+///   still has to be matched in full. So both `hello` and `^hello$` need one deletion to match
+///   `"hell"`.
 #[cfg(feature = "approx")]
 #[derive(Copy, Clone, Debug)]
 pub struct RegApproxParams(tre::regaparams_t);
 
 impl RegApproxParams {
     /// Creates a new empty [`RegApproxParams`] object.
+    ///
+    /// Every field is set to zero. In particular, this means a `max_cost` of zero, so no edits at
+    /// all are permitted and only exact matches will be found until the costs and limits are set.
+    /// See [`RegApproxParams::tre_defaults`] for TRE's own defaults.
     #[must_use]
     #[inline]
     pub fn new() -> Self {
         Self(tre::regaparams_t::default())
     }
 
+    /// Creates a new [`RegApproxParams`] object with TRE's default parameters.
+    ///
+    /// These are filled in by [`tre_regaparams_default`](tre_regex_sys::tre_regaparams_default):
+    /// every cost (`cost_ins`, `cost_del`, and `cost_subst`) is set to 1, and every limit
+    /// (`max_cost`, `max_ins`, `max_del`, `max_subst`, and `max_err`) is set to [`c_int::MAX`],
+    /// meaning unlimited.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::ffi::c_int;
+    /// use tre_regex::RegApproxParams;
+    ///
+    /// let params = RegApproxParams::tre_defaults();
+    /// assert_eq!(params.get().cost_ins, 1);
+    /// assert_eq!(params.get().max_cost, c_int::MAX);
+    ///
+    /// // Only tighten the overall budget
+    /// let params = params.max_cost(2);
+    /// ```
+    #[must_use]
+    pub fn tre_defaults() -> Self {
+        let mut params = tre::regaparams_t::default();
+
+        // SAFETY: params is a valid regaparams_t, which is only written to.
+        unsafe {
+            tre::tre_regaparams_default(&mut params);
+        }
+        Self(params)
+    }
+
     /// Sets the [`cost_ins`](tre_regex_sys::regaparams_t::cost_ins) element.
     #[must_use]
     #[inline]
@@ -116,6 +171,27 @@ impl Default for RegApproxParams {
     }
 }
 
+/// Summary of the edits needed for an approximate match, returned by
+/// [`RegApproxMatch::edit_stats`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct EditStats {
+    /// Cost of the match
+    pub cost: c_int,
+
+    /// Number of insertions
+    pub num_ins: c_int,
+
+    /// Number of deletions
+    pub num_del: c_int,
+
+    /// Number of substitutions
+    pub num_subst: c_int,
+
+    /// Total number of edits (insertions, deletions, and substitutions), saturating at
+    /// [`c_int::MAX`]
+    pub total_edits: c_int,
+}
+
 /// This struct is returned by [`regaexec`] and friends.
 ///
 /// The match results from this function are very complex. See the [TRE documentation] for details
@@ -129,6 +205,8 @@ pub struct RegApproxMatch<Data, Res> {
     data: Data,
     matches: Vec<Option<Res>>,
     amatch: tre::regamatch_t,
+    start: Option<usize>,
+    params: RegApproxParams,
 }
 
 impl<Data, Res> RegApproxMatch<Data, Res> {
@@ -137,6 +215,39 @@ impl<Data, Res> RegApproxMatch<Data, Res> {
             data,
             matches,
             amatch,
+            start: None,
+            params: RegApproxParams::new(),
+        }
+    }
+
+    /// Records the offset of the start of the whole match, for [`RegApproxMatch::cmp_by_cost`].
+    pub(crate) fn with_start(self, start: Option<usize>) -> Self {
+        Self { start, ..self }
+    }
+
+    /// Records the parameters the match was made with, for [`RegApproxMatch::effective_params`].
+    pub(crate) fn with_params(self, params: RegApproxParams) -> Self {
+        Self { params, ..self }
+    }
+
+    /// Converts the data and every match, keeping everything else, for
+    /// [`IntoOwnedMatches::into_owned`].
+    pub(crate) fn map_owned<D, R>(
+        self,
+        data: impl FnOnce(Data) -> D,
+        res: impl FnMut(Res) -> R,
+    ) -> RegApproxMatch<D, R> {
+        let mut res = res;
+        RegApproxMatch {
+            data: data(self.data),
+            matches: self
+                .matches
+                .into_iter()
+                .map(|matched| matched.map(&mut res))
+                .collect(),
+            amatch: self.amatch,
+            start: self.start,
+            params: self.params,
         }
     }
 
@@ -160,6 +271,147 @@ impl<Data, Res> RegApproxMatch<Data, Res> {
         self.amatch.num_subst
     }
 
+    /// Gets the cost and number of each kind of edit of the match, all at once
+    pub const fn edit_stats(&self) -> EditStats {
+        EditStats {
+            cost: self.amatch.cost,
+            num_ins: self.amatch.num_ins,
+            num_del: self.amatch.num_del,
+            num_subst: self.amatch.num_subst,
+            total_edits: self
+                .amatch
+                .num_ins
+                .saturating_add(self.amatch.num_del)
+                .saturating_add(self.amatch.num_subst),
+        }
+    }
+
+    /// Describes the edits needed for the match in words, such as `"1 insertion, 2 substitutions
+    /// (cost 3)"`.
+    ///
+    /// Kinds of edit which were not needed are left out, and each count is pluralised as needed.
+    /// The cost is only given if it is not zero. An exact match is described as `"exact match"`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use tre_regex::{RegApproxParams, RegcompFlags, RegexecFlags, Regex};
+    ///
+    /// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    /// let regaexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    /// let regaexec_params = RegApproxParams::tre_defaults().max_cost(2);
+    ///
+    /// let compiled_reg = Regex::new("^colour$", regcomp_flags)?;
+    /// let result = compiled_reg.regaexec("culour", &regaexec_params, 1, regaexec_flags)?;
+    /// assert_eq!(result.describe_edits(), "1 substitution (cost 1)");
+    ///
+    /// let result = compiled_reg.regaexec("colour", &regaexec_params, 1, regaexec_flags)?;
+    /// assert_eq!(result.describe_edits(), "exact match");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn describe_edits(&self) -> String {
+        let kinds = [
+            (self.amatch.num_ins, "insertion"),
+            (self.amatch.num_del, "deletion"),
+            (self.amatch.num_subst, "substitution"),
+        ];
+        let edits: Vec<String> = kinds
+            .iter()
+            .filter(|(count, _)| *count != 0)
+            .map(|(count, kind)| {
+                let plural = if *count == 1 { "" } else { "s" };
+                format!("{count} {kind}{plural}")
+            })
+            .collect();
+
+        match (edits.is_empty(), self.amatch.cost) {
+            (true, 0) => "exact match".to_string(),
+            (true, cost) => format!("cost {cost}"),
+            (false, 0) => edits.join(", "),
+            (false, cost) => format!("{} (cost {cost})", edits.join(", ")),
+        }
+    }
+
+    /// Gets the offset of the start of the whole match in the underlying data, if any
+    pub const fn start(&self) -> Option<usize> {
+        self.start
+    }
+
+    /// Gets the approximate matching parameters this match was made with.
+    ///
+    /// TRE does not report the parameters it used back in
+    /// [`regamatch_t`](tre_regex_sys::regamatch_t) or anywhere else; the [`RegApproxParams`] are
+    /// passed to it by value and used as they are for the whole search. So these are always the
+    /// parameters that were passed in, kept alongside the match so that its [`EditStats`] can be
+    /// compared against the budget that allowed it.
+    ///
+    /// Note that TRE checks every limit independently, and a limit of zero forbids that kind of
+    /// edit outright. A match can therefore fail with a generous `max_cost` if, for example,
+    /// `max_err` or `max_subst` is still zero, as it is after [`RegApproxParams::new`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use tre_regex::{RegApproxParams, RegcompFlags, RegexecFlags, Regex};
+    ///
+    /// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    /// let regaexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    /// let regaexec_params = RegApproxParams::new().cost_subst(1).max_cost(2).max_err(2);
+    ///
+    /// let compiled_reg = Regex::new("^hello$", regcomp_flags)?;
+    /// let result = compiled_reg.regaexec("hullo", &regaexec_params, 1, regaexec_flags)?;
+    ///
+    /// let params = result.effective_params().get();
+    /// assert_eq!(params.max_cost, 2);
+    /// assert!(result.cost() <= params.max_cost);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub const fn effective_params(&self) -> &RegApproxParams {
+        &self.params
+    }
+
+    /// Compares two approximate matches by ascending [cost](RegApproxMatch::cost), then by
+    /// [start offset](RegApproxMatch::start).
+    ///
+    /// The comparison is purely cost-based; the matched text and the underlying data are ignored,
+    /// so matches comparing as [`Ordering::Equal`] need not be equal. This is why
+    /// [`RegApproxMatch`] does not implement [`Ord`]; pass this to [`slice::sort_by`] instead, or
+    /// wrap matches in a type ordering by it to use a [`BinaryHeap`](std::collections::BinaryHeap).
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use tre_regex::{RegApproxMatch, RegApproxParams, RegcompFlags, RegexecFlags, Regex};
+    ///
+    /// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    /// let regaexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    /// let regaexec_params = RegApproxParams::new().cost_subst(1).max_cost(2).max_err(2);
+    ///
+    /// let compiled_reg = Regex::new("^hello$", regcomp_flags)?;
+    /// let mut results = vec![
+    ///     compiled_reg.regaexec("hxllx", &regaexec_params, 1, regaexec_flags)?,
+    ///     compiled_reg.regaexec("hello", &regaexec_params, 1, regaexec_flags)?,
+    ///     compiled_reg.regaexec("hullo", &regaexec_params, 1, regaexec_flags)?,
+    /// ];
+    /// results.sort_by(RegApproxMatch::cmp_by_cost);
+    ///
+    /// let costs: Vec<_> = results.iter().map(|result| result.cost()).collect();
+    /// assert_eq!(costs, vec![0, 1, 2]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn cmp_by_cost(&self, other: &Self) -> Ordering {
+        self.cost()
+            .cmp(&other.cost())
+            .then_with(|| self.start.cmp(&other.start))
+    }
+
     /// Gets an immutable reference to the underlying data
     pub const fn get_orig_data(&self) -> &Data {
         &self.data
@@ -170,12 +422,66 @@ impl<Data, Res> RegApproxMatch<Data, Res> {
         &self.matches
     }
 
+    /// Returns an iterator over the matches, as references to the data.
+    pub fn iter(&self) -> std::slice::Iter<'_, Option<Res>> {
+        self.matches.iter()
+    }
+
     /// Gets a reference to the underlying [`regamatch_t`](tre_regex_sys::regamatch_t) object.
     pub const fn get_regamatch(&self) -> &tre::regamatch_t {
         &self.amatch
     }
 }
 
+impl IntoOwnedMatches for RegApproxMatchStr<'_> {
+    type Owned = RegApproxMatchStrOwned;
+
+    fn into_owned(self) -> Self::Owned {
+        self.map_owned(str::to_owned, |res| res.map(Cow::into_owned))
+    }
+}
+
+impl IntoOwnedMatches for RegApproxMatchBytes<'_> {
+    type Owned = RegApproxMatchBytesOwned;
+
+    fn into_owned(self) -> Self::Owned {
+        self.map_owned(<[u8]>::to_vec, Cow::into_owned)
+    }
+}
+
+impl<'m, Data, Res> IntoIterator for &'m RegApproxMatch<Data, Res> {
+    type Item = &'m Option<Res>;
+    type IntoIter = std::slice::Iter<'m, Option<Res>>;
+
+    /// Iterates over the matches, as with [`RegApproxMatch::get_matches`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use tre_regex::{RegApproxParams, RegcompFlags, RegexecFlags, Regex};
+    ///
+    /// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    /// let regaexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    /// let regaexec_params = RegApproxParams::new().cost_subst(1).max_cost(1).max_err(1);
+    ///
+    /// let compiled_reg = Regex::new("^(hello)$", regcomp_flags)?;
+    /// let result = compiled_reg.regaexec("hullo", &regaexec_params, 2, regaexec_flags)?;
+    ///
+    /// for matched in &result {
+    ///     if let Some(Ok(substr)) = matched {
+    ///         println!("Match: {substr}");
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
 impl Regex {
     /// Performs an approximate regex search on the passed string, returning `nmatches` results.
     ///
@@ -230,10 +536,10 @@ impl Regex {
     ///     regaexec_flags      // Flags
     /// )?;
     ///
-    /// for (i, matched) in result.get_matches().into_iter().enumerate() {
+    /// for matched in &result {
     ///     match matched {
-    ///         Some(substr) => println!("Match {i}: {}", substr.as_ref().unwrap()),
-    ///         None => println!("Match {i}: <None>"),
+    ///         Some(substr) => println!("Match: {}", substr.as_ref().unwrap()),
+    ///         None => println!("Match: <None>"),
     ///     }
     /// }
     /// # Ok(())
@@ -255,13 +561,9 @@ impl Regex {
             let Some(pmatch) = pmatch else { result.push(None); continue; };
 
             result.push(Some(match pmatch {
-                Cow::Borrowed(pmatch) => match std::str::from_utf8(pmatch) {
-                    Ok(s) => Ok(s.into()),
-                    Err(e) => Err(RegexError::new(
-                        ErrorKind::Binding(BindingErrorCode::ENCODING),
-                        &format!("UTF-8 encoding error: {e}"),
-                    )),
-                },
+                Cow::Borrowed(pmatch) => std::str::from_utf8(pmatch)
+                    .map(Cow::Borrowed)
+                    .map_err(RegexError::from),
                 // SAFETY: cannot get here, we only have borrowed values.
                 _ => unsafe { unreachable_unchecked() },
             }));
@@ -271,7 +573,9 @@ impl Regex {
             string,
             result,
             *match_results.get_regamatch(),
-        ))
+        )
+        .with_start(match_results.start())
+        .with_params(*match_results.effective_params()))
     }
 
     /// Performs an approximate regex search on the passed bytes, returning `nmatches` results.
@@ -292,7 +596,8 @@ impl Regex {
     /// returned.
     ///
     /// # Errors
-    /// If an error is encountered during matching, it returns a [`RegexError`].
+    /// If an error is encountered during matching, it returns a [`RegexError`]. An error is also
+    /// returned if `data` is too long; see [Input length](crate::Regex#input-length).
     ///
     /// # Caveats
     /// Unless copied, the match results must live at least as long as `data`. This is because they are
@@ -326,13 +631,13 @@ impl Regex {
     ///     regaexec_flags      // Flags
     /// )?;
     ///
-    /// for (i, matched) in result.get_matches().into_iter().enumerate() {
+    /// for matched in &result {
     ///     match matched {
     ///         Some(substr) => println!(
-    ///             "Match {i}: {}",
+    ///             "Match: {}",
     ///             std::str::from_utf8(substr).unwrap()
     ///         ),
-    ///         None => println!("Match {i}: <None>"),
+    ///         None => println!("Match: <None>"),
     ///     }
     /// }
     /// # Ok(())
@@ -351,6 +656,7 @@ impl Regex {
                 "Attempted to unwrap a vacant Regex object"
             ));
         };
+        check_len(data.len())?;
         let mut match_vec: Vec<tre::regmatch_t> =
             vec![tre::regmatch_t { rm_so: 0, rm_eo: 0 }; nmatches];
         let mut amatch = tre::regamatch_t {
@@ -373,9 +679,10 @@ impl Regex {
             )
         };
         if result != 0 {
-            return Err(self.regerror(result));
+            return Err(self.exec_error(result));
         }
 
+        let start = match_vec.first().and_then(|pmatch| usize::try_from(pmatch.rm_so).ok());
         let mut result: Vec<Option<Cow<'a, [u8]>>> = Vec::with_capacity(nmatches);
         for pmatch in match_vec {
             if pmatch.rm_so < 0 || pmatch.rm_eo < 0 {
@@ -392,7 +699,208 @@ impl Regex {
             result.push(Some(Cow::Borrowed(&data[start_offset..end_offset])));
         }
 
-        Ok(RegApproxMatchBytes::new(data, result, amatch))
+        Ok(RegApproxMatchBytes::new(data, result, amatch)
+            .with_start(start)
+            .with_params(*params))
+    }
+
+    /// Finds the closest approximate match of the pattern in `string`, however many edits it
+    /// takes.
+    ///
+    /// This uses [`RegApproxParams::tre_defaults`], so every edit costs 1 and neither the cost nor
+    /// the number of edits is capped. Every capture group is returned.
+    ///
+    /// # Arguments
+    /// * `string`: string to match against `compiled_reg`
+    /// * `flags`: [`RegexecFlags`] to pass to [`tre_regaexec`](tre_regex_sys::tre_regaexec).
+    ///
+    /// # Returns
+    /// The lowest-cost match, together with its [`EditStats`].
+    ///
+    /// # Errors
+    /// If an error is encountered during matching, it returns a [`RegexError`]. As any text can be
+    /// edited into a match, there is no error for the lack of a match.
+    ///
+    /// # Caveats
+    /// An unbounded search cannot rule anything out early, so TRE has to track every possible
+    /// match at every position of `string` at every cost. This can be much slower than a search
+    /// with a small `max_cost`, especially for long patterns and haystacks.
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use tre_regex::{RegcompFlags, RegexecFlags, Regex};
+    ///
+    /// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    /// let regaexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    ///
+    /// let compiled_reg = Regex::new("^necessary$", regcomp_flags)?;
+    /// let (result, stats) = compiled_reg.regaexec_best("neccesary", regaexec_flags)?;
+    ///
+    /// assert_eq!(stats.cost, 2);
+    /// assert_eq!(result.get_matches().len(), 1);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`RegexError`]: crate::RegexError
+    pub fn regaexec_best<'a>(
+        &self,
+        string: &'a str,
+        flags: RegexecFlags,
+    ) -> Result<(RegApproxMatchStr<'a>, EditStats)> {
+        let nmatches = self.captures_len()?;
+        let result = self.regaexec(string, &RegApproxParams::tre_defaults(), nmatches, flags)?;
+        let stats = result.edit_stats();
+        Ok((result, stats))
+    }
+
+    /// Performs an approximate regex search, returning every capture group.
+    ///
+    /// This is the same as [`Regex::regaexec`] with `nmatches` set to [`Regex::captures_len`].
+    ///
+    /// # Arguments
+    /// * `string`: string to match against `compiled_reg`
+    /// * `params`: see [`RegApproxParams`]
+    /// * `flags`: [`RegexecFlags`] to pass to [`tre_regaexec`](tre_regex_sys::tre_regaexec).
+    ///
+    /// # Returns
+    /// If no error was found, a [`RegApproxMatchStr`] with one entry per capture group will be
+    /// returned.
+    ///
+    /// # Errors
+    /// If an error is encountered during matching, it returns a [`RegexError`]. Match results may
+    /// also return errors, if decoding into UTF-8 was requested.
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use tre_regex::{RegcompFlags, RegexecFlags, RegApproxParams, Regex};
+    ///
+    /// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    /// let regaexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    /// let regaexec_params = RegApproxParams::new()
+    ///     .cost_ins(1)
+    ///     .cost_del(1)
+    ///     .cost_subst(1)
+    ///     .max_cost(2)
+    ///     .max_err(2);
+    ///
+    /// let compiled_reg = Regex::new("^(hello) (world)$", regcomp_flags)?;
+    /// let result = compiled_reg.regaexec_auto("helo wrld", &regaexec_params, regaexec_flags)?;
+    ///
+    /// assert_eq!(result.get_matches().len(), 3);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`RegexError`]: crate::RegexError
+    pub fn regaexec_auto<'a>(
+        &self,
+        string: &'a str,
+        params: &RegApproxParams,
+        flags: RegexecFlags,
+    ) -> Result<RegApproxMatchStr<'a>> {
+        let nmatches = self.captures_len()?;
+        self.regaexec(string, params, nmatches, flags)
+    }
+}
+
+/// An approximate matcher, bundling a [`Regex`] with its matching configuration.
+///
+/// This keeps the [`RegApproxParams`], [`RegexecFlags`], and number of matches in one place, so
+/// that each search only needs the haystack.
+///
+/// # Examples
+/// ```
+/// # use tre_regex::Result;
+/// # fn main() -> Result<()> {
+/// use tre_regex::{ApproxMatcher, RegApproxParams, RegcompFlags, Regex};
+///
+/// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+/// let regaexec_params = RegApproxParams::new()
+///     .cost_ins(1)
+///     .cost_del(1)
+///     .cost_subst(1)
+///     .max_cost(1)
+///     .max_err(1);
+///
+/// let compiled_reg = Regex::new("^(colou?r)$", regcomp_flags)?;
+/// let matcher = ApproxMatcher::new(&compiled_reg, regaexec_params).nmatches(2);
+///
+/// for word in ["color", "colour", "culour"] {
+///     let result = matcher.exec(word)?;
+///     println!("{word}: cost {}", result.cost());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct ApproxMatcher<'r> {
+    regex: &'r Regex,
+    params: RegApproxParams,
+    flags: RegexecFlags,
+    nmatches: usize,
+}
+
+impl<'r> ApproxMatcher<'r> {
+    /// Creates a new [`ApproxMatcher`], returning one match (the whole match) with no flags.
+    ///
+    /// # Arguments
+    /// * `regex`: the compiled [`Regex`] to match with.
+    /// * `params`: [`RegApproxParams`] to use for every search.
+    #[must_use]
+    pub const fn new(regex: &'r Regex, params: RegApproxParams) -> Self {
+        Self {
+            regex,
+            params,
+            flags: RegexecFlags::new(),
+            nmatches: 1,
+        }
+    }
+
+    /// Sets the [`RegexecFlags`] to use for every search.
+    #[must_use]
+    #[inline]
+    pub const fn flags(self, flags: RegexecFlags) -> Self {
+        Self { flags, ..self }
+    }
+
+    /// Sets the number of matches to return from every search, including the whole match.
+    #[must_use]
+    #[inline]
+    pub const fn nmatches(self, nmatches: usize) -> Self {
+        Self { nmatches, ..self }
+    }
+
+    /// Performs an approximate regex search on the passed string.
+    ///
+    /// This is [`Regex::regaexec`] with the stored configuration.
+    ///
+    /// # Errors
+    /// If an error is encountered during matching, it returns a [`RegexError`].
+    ///
+    /// [`RegexError`]: crate::RegexError
+    #[inline]
+    pub fn exec<'a>(&self, string: &'a str) -> Result<RegApproxMatchStr<'a>> {
+        self.regex
+            .regaexec(string, &self.params, self.nmatches, self.flags)
+    }
+
+    /// Performs an approximate regex search on the passed bytes.
+    ///
+    /// This is [`Regex::regaexec_bytes`] with the stored configuration.
+    ///
+    /// # Errors
+    /// If an error is encountered during matching, it returns a [`RegexError`].
+    ///
+    /// [`RegexError`]: crate::RegexError
+    #[inline]
+    pub fn exec_bytes<'a>(&self, data: &'a [u8]) -> Result<RegApproxMatchBytes<'a>> {
+        self.regex
+            .regaexec_bytes(data, &self.params, self.nmatches, self.flags)
     }
 }
 
@@ -453,10 +961,10 @@ impl Regex {
 ///     regaexec_flags      // Flags
 /// )?;
 ///
-/// for (i, matched) in result.get_matches().into_iter().enumerate() {
+/// for matched in &result {
 ///     match matched {
-///         Some(substr) => println!("Match {i}: {}", substr.as_ref().unwrap()),
-///         None => println!("Match {i}: <None>"),
+///         Some(substr) => println!("Match: {}", substr.as_ref().unwrap()),
+///         None => println!("Match: <None>"),
 ///     }
 /// }
 /// # Ok(())
@@ -529,13 +1037,13 @@ pub fn regaexec<'a>(
 ///     regaexec_flags      // Flags
 /// )?;
 ///
-/// for (i, matched) in result.get_matches().into_iter().enumerate() {
+/// for matched in &result {
 ///     match matched {
 ///         Some(substr) => println!(
-///             "Match {i}: {}",
+///             "Match: {}",
 ///             std::str::from_utf8(substr).unwrap()
 ///         ),
-///         None => println!("Match {i}: <None>"),
+///         None => println!("Match: <None>"),
 ///     }
 /// }
 /// # Ok(())
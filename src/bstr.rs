@@ -0,0 +1,130 @@
+use std::borrow::Cow;
+
+use ::bstr::BStr;
+
+use crate::{err::Result, flags::RegexecFlags, Regex};
+
+pub type RegMatchBStr<'a> = Vec<Option<Cow<'a, BStr>>>;
+
+impl Regex {
+    /// Performs a regex search on the passed [`BStr`], returning `nmatches` results.
+    ///
+    /// This is [`regexec_bytes`] for users of the [`bstr`](::bstr) crate: the matches are
+    /// [`BStr`] slices, so they can be displayed and searched like strings even if they are not
+    /// valid UTF-8.
+    ///
+    /// # Arguments
+    /// * `data`: [`BStr`] to match against `compiled_reg`
+    /// * `nmatches`: number of matches to return
+    /// * `flags`: [`RegexecFlags`] to pass to [`tre_regnexec`](tre_regex_sys::tre_regnexec).
+    ///
+    /// # Returns
+    /// If no error was found, a [`Vec`] of [`Option`]s will be returned.
+    ///
+    /// If a given match index is empty, The `Option` will be `None`. Otherwise, the `Option` will
+    /// contain a [`BStr`].
+    ///
+    /// # Errors
+    /// If an error is encountered during matching, it returns a [`RegexError`]. An error is also
+    /// returned if `data` is too long; see [Input length](crate::Regex#input-length).
+    ///
+    /// # Caveats
+    /// Unless copied, the match results must live at least as long as `data`. This is because
+    /// they are slices into `data` under the hood, for efficiency.
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use bstr::ByteSlice;
+    /// use tre_regex::{RegcompFlags, RegexecFlags, Regex};
+    ///
+    /// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    /// let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    ///
+    /// let compiled_reg = Regex::new("^([[:alpha:]]+) (.*)$", regcomp_flags)?;
+    /// let matches = compiled_reg.regexec_bstr(b"hello w\xffrld".as_bstr(), 3, regexec_flags)?;
+    ///
+    /// for (i, matched) in matches.into_iter().enumerate() {
+    ///     match matched {
+    ///         Some(substr) => println!("Match {i}: {substr}"),
+    ///         None => println!("Match {i}: <None>"),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`regexec_bytes`]: crate::regexec_bytes
+    /// [`RegexError`]: crate::RegexError
+    pub fn regexec_bstr<'a>(
+        &self,
+        data: &'a BStr,
+        nmatches: usize,
+        flags: RegexecFlags,
+    ) -> Result<RegMatchBStr<'a>> {
+        let match_results = self.regexec_bytes(data, nmatches, flags)?;
+
+        Ok(match_results
+            .into_iter()
+            .map(|pmatch| {
+                pmatch.map(|pmatch| match pmatch {
+                    Cow::Borrowed(pmatch) => Cow::Borrowed(BStr::new(pmatch)),
+                    Cow::Owned(pmatch) => Cow::Owned(pmatch.into()),
+                })
+            })
+            .collect())
+    }
+}
+
+/// Performs a regex search on the passed [`BStr`], returning `nmatches` results.
+///
+/// This is a thin wrapper around [`Regex::regexec_bstr`].
+///
+/// # Arguments
+/// * `compiled_reg`: the compiled [`Regex`] object.
+/// * `data`: [`BStr`] to match against `compiled_reg`
+/// * `nmatches`: number of matches to return
+/// * `flags`: [`RegexecFlags`] to pass to [`tre_regnexec`](tre_regex_sys::tre_regnexec).
+///
+/// # Returns
+/// If no error was found, a [`Vec`] of [`Option`]s will be returned.
+///
+/// If a given match index is empty, The `Option` will be `None`. Otherwise, a [`BStr`] will be
+/// returned.
+///
+/// # Errors
+/// If an error is encountered during matching, it returns a [`RegexError`].
+///
+/// # Caveats
+/// Unless copied, the match results must live at least as long as `data`. This is because they are
+/// slices into `data` under the hood, for efficiency.
+///
+/// # Examples
+/// ```
+/// # use tre_regex::Result;
+/// # fn main() -> Result<()> {
+/// use bstr::ByteSlice;
+/// use tre_regex::{RegcompFlags, RegexecFlags, regcomp, regexec_bstr};
+///
+/// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+/// let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+///
+/// let compiled_reg = regcomp("w.rld", regcomp_flags)?;
+/// let matches = regexec_bstr(&compiled_reg, b"hello w\xffrld".as_bstr(), 1, regexec_flags)?;
+///
+/// assert_eq!(matches[0].as_deref(), Some(b"w\xffrld".as_bstr()));
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`RegexError`]: crate::RegexError
+#[inline]
+pub fn regexec_bstr<'a>(
+    compiled_reg: &Regex,
+    data: &'a BStr,
+    nmatches: usize,
+    flags: RegexecFlags,
+) -> Result<RegMatchBStr<'a>> {
+    compiled_reg.regexec_bstr(data, nmatches, flags)
+}
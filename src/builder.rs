@@ -0,0 +1,136 @@
+use crate::{
+    err::Result,
+    flags::{RegFlags, RegcompFlags},
+    Regex,
+};
+
+/// A builder for [`Regex`] objects, for setting compilation options by name rather than with
+/// [`RegcompFlags`] constants.
+///
+/// Every option is off by default, which gives a case-sensitive basic (obsolete) regex, as with
+/// [`RegcompFlags::new`]. Most users will want [`RegexBuilder::extended`].
+///
+/// # Examples
+/// ```
+/// # use tre_regex::Result;
+/// # fn main() -> Result<()> {
+/// use tre_regex::{RegexBuilder, RegexecFlags};
+///
+/// let compiled_reg = RegexBuilder::new()
+///     .extended(true)
+///     .case_insensitive(true)
+///     .build("^hello (world|there)$")?;
+///
+/// assert!(compiled_reg.is_match("Hello World", RegexecFlags::new())?);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct RegexBuilder {
+    flags: RegcompFlags,
+}
+
+impl RegexBuilder {
+    /// Creates a new [`RegexBuilder`] with every option off.
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            flags: RegcompFlags::new(),
+        }
+    }
+
+    /// Sets or clears `flag`.
+    #[must_use]
+    #[inline]
+    const fn set(self, flag: RegFlags, enabled: bool) -> Self {
+        Self {
+            flags: if enabled {
+                self.flags.add(flag)
+            } else {
+                self.flags.remove(flag)
+            },
+        }
+    }
+
+    /// Uses extended POSIX regex syntax ([`RegcompFlags::EXTENDED`]).
+    #[must_use]
+    #[inline]
+    pub const fn extended(self, enabled: bool) -> Self {
+        self.set(RegcompFlags::EXTENDED, enabled)
+    }
+
+    /// Matches case-insensitively ([`RegcompFlags::ICASE`]).
+    #[must_use]
+    #[inline]
+    pub const fn case_insensitive(self, enabled: bool) -> Self {
+        self.set(RegcompFlags::ICASE, enabled)
+    }
+
+    /// Treats the pattern as a literal string, with no special characters
+    /// ([`RegcompFlags::LITERAL`]).
+    #[must_use]
+    #[inline]
+    pub const fn literal(self, enabled: bool) -> Self {
+        self.set(RegcompFlags::LITERAL, enabled)
+    }
+
+    /// Enables newline-sensitive matching ([`RegcompFlags::NEWLINE`]).
+    #[must_use]
+    #[inline]
+    pub const fn newline(self, enabled: bool) -> Self {
+        self.set(RegcompFlags::NEWLINE, enabled)
+    }
+
+    /// Only reports whether the pattern matched, not where ([`RegcompFlags::NOSUB`]).
+    #[must_use]
+    #[inline]
+    pub const fn nosub(self, enabled: bool) -> Self {
+        self.set(RegcompFlags::NOSUB, enabled)
+    }
+
+    /// Makes repetition operators non-greedy by default ([`RegcompFlags::UNGREEDY`]).
+    #[must_use]
+    #[inline]
+    pub const fn ungreedy(self, enabled: bool) -> Self {
+        self.set(RegcompFlags::UNGREEDY, enabled)
+    }
+
+    /// Gets the [`RegcompFlags`] the options so far correspond to.
+    #[must_use]
+    #[inline]
+    pub const fn flags(self) -> RegcompFlags {
+        self.flags
+    }
+
+    /// Compiles `pattern` with the options set on this builder.
+    ///
+    /// This is [`Regex::new`] with the flags from [`RegexBuilder::flags`].
+    ///
+    /// # Errors
+    /// Returns a [`RegexError`] if the pattern could not be compiled.
+    ///
+    /// [`RegexError`]: crate::RegexError
+    pub fn build(&self, pattern: &str) -> Result<Regex> {
+        Regex::new(pattern, self.flags)
+    }
+
+    /// Compiles the byte pattern `pattern` with the options set on this builder.
+    ///
+    /// This is [`Regex::new_bytes`] with the flags from [`RegexBuilder::flags`].
+    ///
+    /// # Errors
+    /// Returns a [`RegexError`] if the pattern could not be compiled.
+    ///
+    /// [`RegexError`]: crate::RegexError
+    pub fn build_bytes(&self, pattern: &[u8]) -> Result<Regex> {
+        Regex::new_bytes(pattern, self.flags)
+    }
+}
+
+impl Default for RegexBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,158 @@
+use crate::{flags::RegcompFlags, Regex};
+
+/// A set of bytes, matched with a lookup table instead of TRE.
+///
+/// This is a fast path for patterns which are a single bracket expression of ASCII characters,
+/// such as `[,;:\t]` or `[^a-z0-9_]`. Testing whether a byte is in the set is a single table
+/// lookup, which is much cheaper than calling into TRE for every byte of a tokenizer's input.
+///
+/// Only simple bracket expressions are supported: single characters and ranges, optionally
+/// negated. Character classes (`[:alpha:]`), equivalence classes (`[=a=]`) and collating symbols
+/// (`[.a.]`) are not, nor are non-ASCII characters.
+///
+/// # Caveats
+/// A `ByteSet` works on bytes, not characters. A negated set contains every non-ASCII byte, so
+/// it will find the individual bytes of multibyte UTF-8 characters.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ByteSet {
+    table: [bool; 256],
+}
+
+impl ByteSet {
+    /// Builds a [`ByteSet`] from a pattern, if the pattern is a simple bracket expression.
+    ///
+    /// # Arguments
+    /// * `reg`: regular expression to convert, as a string.
+    /// * `flags`: [`RegcompFlags`] the pattern would be compiled with. [`RegcompFlags::ICASE`]
+    ///   and [`RegcompFlags::NEWLINE`] are taken into account.
+    ///
+    /// # Returns
+    /// `None` if `reg` is not a simple bracket expression (see the [type documentation](Self)),
+    /// or if [`RegcompFlags::LITERAL`] is set. Otherwise, the [`ByteSet`] matching the same bytes.
+    ///
+    /// # Examples
+    /// ```
+    /// use tre_regex::{ByteSet, RegcompFlags};
+    ///
+    /// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    ///
+    /// let delimiters = ByteSet::new("[,;:\t]", regcomp_flags).expect("not a simple class");
+    /// assert!(delimiters.contains(b';'));
+    /// assert!(!delimiters.contains(b'a'));
+    /// assert_eq!(delimiters.find(b"key:value"), Some(3));
+    ///
+    /// assert!(ByteSet::new("[[:space:]]", regcomp_flags).is_none());
+    /// ```
+    #[must_use]
+    pub fn new(reg: &str, flags: RegcompFlags) -> Option<Self> {
+        if flags.get() & RegcompFlags::LITERAL != 0 {
+            return None;
+        }
+
+        let inner = reg.as_bytes().strip_prefix(b"[")?.strip_suffix(b"]")?;
+        let (negated, inner) = inner
+            .strip_prefix(b"^")
+            .map_or((false, inner), |inner| (true, inner));
+        if inner.is_empty() {
+            return None;
+        }
+
+        let mut table = [false; 256];
+        let mut i = 0;
+        while i < inner.len() {
+            let c = inner[i];
+            if !c.is_ascii()
+                || (c == b'[' && matches!(inner.get(i + 1), Some(b':' | b'=' | b'.')))
+                || (c == b']' && i != 0)
+            {
+                // Not a simple class, or the bracket expression ends before the pattern does.
+                return None;
+            }
+
+            if inner.get(i + 1) == Some(&b'-') && i + 2 < inner.len() {
+                let end = inner[i + 2];
+
+                // A ] after the - closes the bracket expression, so it cannot end a range.
+                if !end.is_ascii() || end < c || end == b'[' || end == b']' {
+                    return None;
+                }
+                for b in c..=end {
+                    table[usize::from(b)] = true;
+                }
+                i += 3;
+            } else {
+                table[usize::from(c)] = true;
+                i += 1;
+            }
+        }
+
+        if flags.get() & RegcompFlags::ICASE != 0 {
+            for b in b'a'..=b'z' {
+                let upper = b.to_ascii_uppercase();
+                let either = table[usize::from(b)] || table[usize::from(upper)];
+                table[usize::from(b)] = either;
+                table[usize::from(upper)] = either;
+            }
+        }
+
+        if negated {
+            for entry in &mut table {
+                *entry = !*entry;
+            }
+            if flags.get() & RegcompFlags::NEWLINE != 0 {
+                table[usize::from(b'\n')] = false;
+            }
+        }
+
+        Some(Self { table })
+    }
+
+    /// Checks whether `b` is in the set.
+    #[must_use]
+    #[inline]
+    pub const fn contains(&self, b: u8) -> bool {
+        self.table[b as usize]
+    }
+
+    /// Finds the first byte in `haystack` which is in the set.
+    ///
+    /// # Returns
+    /// The offset of the first byte in the set, or `None` if there is none.
+    #[must_use]
+    #[inline]
+    pub fn find(&self, haystack: &[u8]) -> Option<usize> {
+        haystack.iter().position(|&b| self.contains(b))
+    }
+}
+
+impl Regex {
+    /// Gets a [`ByteSet`] matching the same bytes as this object, if its pattern is simple enough.
+    ///
+    /// See [`ByteSet::new`] for what is supported.
+    ///
+    /// # Returns
+    /// `None` if the object has no stored pattern, or if the pattern is not a simple bracket
+    /// expression.
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use tre_regex::{RegcompFlags, Regex};
+    ///
+    /// let regcomp_flags = RegcompFlags::new()
+    ///     .add(RegcompFlags::EXTENDED)
+    ///     .add(RegcompFlags::ICASE);
+    ///
+    /// let compiled_reg = Regex::new("[a-f]", regcomp_flags)?;
+    /// let hex_letters = compiled_reg.byte_set().expect("not a simple class");
+    /// assert!(hex_letters.contains(b'C'));
+    /// assert!(!hex_letters.contains(b'g'));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn byte_set(&self) -> Option<ByteSet> {
+        ByteSet::new(self.as_str()?, self.flags)
+    }
+}
@@ -0,0 +1,633 @@
+use std::str::FromStr;
+
+use crate::{
+    err::{BindingErrorCode, ErrorKind, RegexError, Result},
+    exec::match_range,
+    flags::RegexecFlags,
+    tre, Regex,
+};
+
+/// Absolute start and end offsets of each group of a match.
+type Locations = Vec<Option<(usize, usize)>>;
+
+/// Capture groups of a single match in a string.
+///
+/// Group 0 is the whole match; groups 1 and up are the parenthesised subexpressions. Offsets are
+/// byte offsets into the original string.
+///
+/// A `Captures` object can also be used as a reusable buffer with [`Regex::captures_read`]; see
+/// [`Captures::with_len`].
+#[derive(Clone, Debug)]
+pub struct Captures<'h> {
+    string: &'h str,
+    locs: Locations,
+    pmatch: Vec<tre::regmatch_t>,
+}
+
+/// Checks every range in `locs` falls on character boundaries of `string`.
+fn check_boundaries(string: &str, mut locs: impl Iterator<Item = (usize, usize)>) -> Result<()> {
+    if let Some((start, end)) =
+        locs.find(|&(start, end)| !string.is_char_boundary(start) || !string.is_char_boundary(end))
+    {
+        return Err(RegexError::new(
+            ErrorKind::Binding(BindingErrorCode::ENCODING),
+            &format!("UTF-8 encoding error: match {start}..{end} is not on a character boundary"),
+        ));
+    }
+
+    Ok(())
+}
+
+impl<'h> Captures<'h> {
+    /// Builds a new [`Captures`] object, checking every group falls on character boundaries.
+    pub(crate) fn new(string: &'h str, locs: Locations) -> Result<Self> {
+        let captures = Self {
+            string,
+            locs,
+            pmatch: Vec::new(),
+        };
+        captures.check_boundaries()?;
+        Ok(captures)
+    }
+
+    /// Creates an empty buffer for [`Regex::captures_read`], with room for `len` groups
+    /// (including group 0).
+    ///
+    /// Every group is `None` until the buffer is filled in by a match.
+    #[must_use]
+    pub fn with_len(len: usize) -> Self {
+        Self {
+            string: "",
+            locs: vec![None; len],
+            pmatch: vec![
+                tre::regmatch_t {
+                    rm_so: -1,
+                    rm_eo: -1
+                };
+                len
+            ],
+        }
+    }
+
+    /// Clears every group, releasing the borrow of the string so the buffer can be reused with
+    /// another string.
+    ///
+    /// The storage is kept, so this does not allocate. As a buffer passed to
+    /// [`Regex::captures_read`] borrows the string it was last filled in from, this is needed to
+    /// reuse one buffer for strings that do not all outlive it, such as lines read in a loop.
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use tre_regex::{Captures, RegcompFlags, RegexecFlags, Regex};
+    ///
+    /// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    /// let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    ///
+    /// let compiled_reg = Regex::new("^([[:alpha:]]+)=([[:digit:]]+)$", regcomp_flags)?;
+    /// let mut buf = Captures::with_len(3);
+    /// for i in 0..3 {
+    ///     let line = format!("key={i}");
+    ///     let mut caps = buf.reset();
+    ///     assert!(compiled_reg.captures_read(&mut caps, &line, regexec_flags)?);
+    ///     assert_eq!(caps.get(2), Some(i.to_string().as_str()));
+    ///     buf = caps.reset();
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn reset<'n>(mut self) -> Captures<'n> {
+        self.locs.fill(None);
+        Captures {
+            string: "",
+            locs: self.locs,
+            pmatch: self.pmatch,
+        }
+    }
+
+    /// Checks every group falls on character boundaries.
+    fn check_boundaries(&self) -> Result<()> {
+        check_boundaries(self.string, self.locs.iter().flatten().copied())
+    }
+
+    /// Gets the text of group `i`, or `None` if the group did not participate in the match.
+    #[must_use]
+    pub fn get(&self, i: usize) -> Option<&'h str> {
+        let (start, end) = self.pos(i)?;
+        Some(&self.string[start..end])
+    }
+
+    /// Parses the text of group `i` with [`FromStr`].
+    ///
+    /// # Errors
+    /// A [`BindingErrorCode::CAPTURE_PARSE`] error is returned if the group did not participate
+    /// in the match, or if its text could not be parsed.
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use tre_regex::{RegcompFlags, RegexecFlags, Regex};
+    ///
+    /// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    /// let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    ///
+    /// let compiled_reg = Regex::new("([[:digit:]]+)ms", regcomp_flags)?;
+    /// let caps = compiled_reg.captures_at("125ms", 0, 2, regexec_flags)?;
+    /// let millis: u64 = caps.expect("match").parse(1)?;
+    ///
+    /// assert_eq!(millis, 125);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`BindingErrorCode::CAPTURE_PARSE`]: crate::BindingErrorCode::CAPTURE_PARSE
+    pub fn parse<T: FromStr>(&self, i: usize) -> Result<T> {
+        let Some(text) = self.get(i) else {
+            return Err(RegexError::new(
+                ErrorKind::Binding(BindingErrorCode::CAPTURE_PARSE),
+                &format!("Capture group {i} did not participate in the match"),
+            ));
+        };
+
+        text.parse().map_err(|_| {
+            RegexError::new(
+                ErrorKind::Binding(BindingErrorCode::CAPTURE_PARSE),
+                &format!("Could not parse capture group {i} ({text:?})"),
+            )
+        })
+    }
+
+    /// Gets the start and end byte offsets of group `i`, or `None` if the group did not
+    /// participate in the match.
+    #[must_use]
+    #[inline]
+    pub fn pos(&self, i: usize) -> Option<(usize, usize)> {
+        self.locs.get(i).copied().flatten()
+    }
+
+    /// Gets the number of groups, including group 0.
+    #[must_use]
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.locs.len()
+    }
+
+    /// Returns `true` if there are no groups at all.
+    #[must_use]
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.locs.is_empty()
+    }
+
+    /// Returns an iterator over the text of every group, in order.
+    pub fn iter(&self) -> impl Iterator<Item = Option<&'h str>> + '_ {
+        (0..self.len()).map(|i| self.get(i))
+    }
+
+    /// Gets the text of the whole match (group 0).
+    ///
+    /// # Returns
+    /// `None` if there was no match, as in a [`Captures::with_len`] buffer that has not been
+    /// filled in.
+    #[must_use]
+    #[inline]
+    pub fn overall(&self) -> Option<&'h str> {
+        self.get(0)
+    }
+
+    /// Returns an iterator over the text of the parenthesised subgroups, starting at group 1.
+    ///
+    /// This leaves out the whole match, so the first item is the first subgroup.
+    pub fn subgroups(&self) -> impl Iterator<Item = Option<&'h str>> + '_ {
+        (1..self.len()).map(|i| self.get(i))
+    }
+
+    /// Copies the text of every group which participated in the match into owned strings.
+    ///
+    /// Groups which did not participate are left out, so the index of a string in the result is
+    /// not necessarily its group number.
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use tre_regex::{RegcompFlags, RegexecFlags, Regex};
+    ///
+    /// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    /// let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    ///
+    /// let compiled_reg = Regex::new("([a-z]+)(-([0-9]+))?", regcomp_flags)?;
+    /// let caps = compiled_reg.captures_at("abc", 0, 4, regexec_flags)?.expect("match");
+    ///
+    /// assert_eq!(caps.into_owned_present(), vec!["abc".to_string(), "abc".to_string()]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn into_owned_present(self) -> Vec<String> {
+        self.iter().flatten().map(str::to_owned).collect()
+    }
+
+    /// Gets the string the captures were taken from.
+    #[must_use]
+    #[inline]
+    pub const fn get_orig_string(&self) -> &'h str {
+        self.string
+    }
+}
+
+/// Builds a struct from a [`Captures`], parsing each field from a capture group.
+///
+/// Each field is given as `field: group`, and is parsed from the text of that group with
+/// [`Captures::parse`], so its type must implement [`FromStr`]. POSIX patterns have no named
+/// groups, so groups are given by number.
+///
+/// This evaluates to a [`Result`](crate::Result) of the struct, with the error from the first
+/// field that failed to parse.
+///
+/// # Examples
+/// ```
+/// # use tre_regex::Result;
+/// # fn main() -> Result<()> {
+/// use tre_regex::{from_captures, RegcompFlags, RegexecFlags, Regex};
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Request {
+///     method: String,
+///     status: u16,
+///     bytes: usize,
+/// }
+///
+/// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+/// let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+///
+/// let compiled_reg = Regex::new("^([A-Z]+) [^ ]+ ([0-9]{3}) ([0-9]+)$", regcomp_flags)?;
+/// let caps = compiled_reg
+///     .captures_at("GET /index.html 200 5120", 0, 4, regexec_flags)?
+///     .expect("match");
+///
+/// let request = from_captures!(caps, Request { method: 1, status: 2, bytes: 3 })?;
+/// assert_eq!(
+///     request,
+///     Request { method: "GET".to_string(), status: 200, bytes: 5120 }
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[macro_export]
+macro_rules! from_captures {
+    ($caps:expr, $($ty:ident)::+ { $($field:ident: $group:expr),* $(,)? }) => {
+        'from_captures: {
+            let caps: &$crate::Captures<'_> = &$caps;
+            $(
+                let $field = match caps.parse($group) {
+                    ::core::result::Result::Ok(value) => value,
+                    ::core::result::Result::Err(e) => {
+                        break 'from_captures ::core::result::Result::Err(e)
+                    }
+                };
+            )*
+            ::core::result::Result::Ok($($ty)::+ { $($field),* })
+        }
+    };
+}
+
+impl PartialEq for Captures<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.string == other.string && self.locs == other.locs
+    }
+}
+
+impl Eq for Captures<'_> {}
+
+impl Regex {
+    /// Attempts to match `string` anchored at the byte offset `pos`, returning the capture groups.
+    ///
+    /// The search runs over `string[pos..]`, so `^` matches at `pos` (unless
+    /// [`RegexecFlags::NOTBOL`] is passed). A match is only reported if group 0 begins exactly at
+    /// `pos`; a match found further along is ignored. Prefixing the pattern with `^` lets TRE stop
+    /// searching early when there is no match at `pos`.
+    ///
+    /// This is the building block for lexers that consume input left to right.
+    ///
+    /// # Arguments
+    /// * `string`: string to match against `compiled_reg`
+    /// * `pos`: byte offset to anchor the match at
+    /// * `nmatches`: number of groups to return, including group 0
+    /// * `flags`: [`RegexecFlags`] to pass to [`tre_regnexec`](tre_regex_sys::tre_regnexec).
+    ///
+    /// # Returns
+    /// `None` if the pattern does not match at `pos`, otherwise the [`Captures`] of the match.
+    ///
+    /// # Errors
+    /// If an error is encountered during matching, it returns a [`RegexError`]. An error is also
+    /// returned if `pos` is out of bounds or not on a character boundary, or if any group does not
+    /// fall on character boundaries.
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use tre_regex::{RegcompFlags, RegexecFlags, Regex};
+    ///
+    /// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    /// let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    ///
+    /// let compiled_reg = Regex::new("^([[:alpha:]]+)=([[:digit:]]+)", regcomp_flags)?;
+    /// let caps = compiled_reg
+    ///     .captures_at("x, key=42", 3, 3, regexec_flags)?
+    ///     .expect("no match at offset 3");
+    ///
+    /// assert_eq!(caps.get(1), Some("key"));
+    /// assert_eq!(caps.get(2), Some("42"));
+    /// assert!(compiled_reg.captures_at("x, key=42", 2, 3, regexec_flags)?.is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`RegexError`]: crate::RegexError
+    pub fn captures_at<'h>(
+        &self,
+        string: &'h str,
+        pos: usize,
+        nmatches: usize,
+        flags: RegexecFlags,
+    ) -> Result<Option<Captures<'h>>> {
+        if pos > string.len() {
+            return Err(RegexError::new(
+                ErrorKind::Binding(BindingErrorCode::OUT_OF_BOUNDS),
+                &format!(
+                    "Offset {pos} is out of bounds for input of length {}",
+                    string.len()
+                ),
+            ));
+        }
+        if !string.is_char_boundary(pos) {
+            return Err(RegexError::new(
+                ErrorKind::Binding(BindingErrorCode::ENCODING),
+                &format!("UTF-8 encoding error: offset {pos} is not on a character boundary"),
+            ));
+        }
+
+        let Some(locs) = self.locs_at(string.as_bytes(), pos, nmatches, flags)? else {
+            return Ok(None);
+        };
+        Captures::new(string, locs).map(Some)
+    }
+
+    /// Attempts to match `data` anchored at the byte offset `pos`, returning the capture groups.
+    ///
+    /// This is the byte counterpart of [`Regex::captures_at`], for lexing data which may not be
+    /// UTF-8, such as binary formats. As there, the search runs over `data[pos..]`, so `^`
+    /// matches at `pos` unless [`RegexecFlags::NOTBOL`] is passed, and a match is only reported
+    /// if group 0 begins exactly at `pos`.
+    ///
+    /// # Arguments
+    /// * `data`: [`u8`] slice to match against `compiled_reg`
+    /// * `pos`: byte offset to anchor the match at
+    /// * `nmatches`: number of groups to return, including group 0
+    /// * `flags`: [`RegexecFlags`] to pass to [`tre_regnexec`](tre_regex_sys::tre_regnexec).
+    ///
+    /// # Returns
+    /// `None` if the pattern does not match at `pos`, otherwise a [`Vec`] of `nmatches` groups. A
+    /// group which did not participate in the match is `None`.
+    ///
+    /// # Errors
+    /// If an error is encountered during matching, it returns a [`RegexError`]. An error is also
+    /// returned if `pos` is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use tre_regex::{RegcompFlags, RegexecFlags, Regex};
+    ///
+    /// let regcomp_flags = RegcompFlags::new()
+    ///     .add(RegcompFlags::EXTENDED)
+    ///     .add(RegcompFlags::USEBYTES);
+    /// let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    ///
+    /// let compiled_reg = Regex::new("^\x01([^\x02]*)\x02", regcomp_flags)?;
+    /// let data = b"\xff\x01\xfe\xfd\x02";
+    /// let groups = compiled_reg
+    ///     .captures_at_bytes(data, 1, 2, regexec_flags)?
+    ///     .expect("no match at offset 1");
+    ///
+    /// assert_eq!(groups[1], Some(&b"\xfe\xfd"[..]));
+    /// assert!(compiled_reg.captures_at_bytes(data, 0, 2, regexec_flags)?.is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`RegexError`]: crate::RegexError
+    pub fn captures_at_bytes<'h>(
+        &self,
+        data: &'h [u8],
+        pos: usize,
+        nmatches: usize,
+        flags: RegexecFlags,
+    ) -> Result<Option<Vec<Option<&'h [u8]>>>> {
+        if pos > data.len() {
+            return Err(RegexError::new(
+                ErrorKind::Binding(BindingErrorCode::OUT_OF_BOUNDS),
+                &format!(
+                    "Offset {pos} is out of bounds for input of length {}",
+                    data.len()
+                ),
+            ));
+        }
+
+        let Some(locs) = self.locs_at(data, pos, nmatches, flags)? else {
+            return Ok(None);
+        };
+        Ok(Some(
+            locs.into_iter()
+                .map(|loc| loc.map(|(start, end)| &data[start..end]))
+                .collect(),
+        ))
+    }
+
+    /// Matches `data` anchored at `pos`, returning the absolute offsets of `nmatches` groups.
+    ///
+    /// # Returns
+    /// `None` if the pattern does not match at `pos`.
+    fn locs_at(
+        &self,
+        data: &[u8],
+        pos: usize,
+        nmatches: usize,
+        flags: RegexecFlags,
+    ) -> Result<Option<Locations>> {
+        let mut match_vec = vec![tre::regmatch_t { rm_so: 0, rm_eo: 0 }; nmatches.max(1)];
+        if !self.exec_at(data, pos, &mut match_vec, flags)? {
+            return Ok(None);
+        }
+        if match_range(match_vec[0], pos).map(|(start, _)| start) != Some(pos) {
+            return Ok(None);
+        }
+
+        Ok(Some(
+            match_vec
+                .into_iter()
+                .take(nmatches)
+                .map(|pmatch| match_range(pmatch, pos))
+                .collect(),
+        ))
+    }
+
+    /// Matches `string`, returning the text of groups `1..=N` as a fixed-size array.
+    ///
+    /// This is meant for patterns with a known shape, where the result can be destructured
+    /// directly instead of indexing into a [`Captures`] object. Group 0 (the whole match) is not
+    /// included. An array is returned rather than a tuple, as Rust cannot be generic over the
+    /// length of a tuple; it destructures in the same way.
+    ///
+    /// # Arguments
+    /// * `string`: string to match against `compiled_reg`
+    /// * `flags`: [`RegexecFlags`] to pass to [`tre_regnexec`](tre_regex_sys::tre_regnexec).
+    ///
+    /// # Returns
+    /// `None` if the pattern did not match, otherwise an array of `N` groups. A group which did
+    /// not participate in the match is `None`.
+    ///
+    /// # Errors
+    /// If the pattern has fewer than `N` capture groups, a [`BindingErrorCode::GROUP_COUNT`]
+    /// error is returned without attempting a match. If an error is encountered during matching,
+    /// it returns a [`RegexError`]. An error is also returned if any group does not fall on
+    /// character boundaries.
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use tre_regex::{RegcompFlags, RegexecFlags, Regex};
+    ///
+    /// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    /// let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    ///
+    /// let compiled_reg = Regex::new("^([0-9-]+) ([A-Z]+) (.*)$", regcomp_flags)?;
+    /// let [date, level, msg] = compiled_reg
+    ///     .captures_array("2023-01-02 WARN disk full", regexec_flags)?
+    ///     .expect("no match");
+    ///
+    /// assert_eq!(date, Some("2023-01-02"));
+    /// assert_eq!(level, Some("WARN"));
+    /// assert_eq!(msg, Some("disk full"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`RegexError`]: crate::RegexError
+    pub fn captures_array<'h, const N: usize>(
+        &self,
+        string: &'h str,
+        flags: RegexecFlags,
+    ) -> Result<Option<[Option<&'h str>; N]>> {
+        let ngroups = self.captures_len()? - 1;
+        if ngroups < N {
+            return Err(RegexError::new(
+                ErrorKind::Binding(BindingErrorCode::GROUP_COUNT),
+                &format!("Requested {N} capture groups, but the pattern only has {ngroups}"),
+            ));
+        }
+
+        let mut match_vec = vec![tre::regmatch_t { rm_so: 0, rm_eo: 0 }; N + 1];
+        if !self.exec_at(string.as_bytes(), 0, &mut match_vec, flags)? {
+            return Ok(None);
+        }
+
+        let locs = match_vec
+            .into_iter()
+            .map(|pmatch| match_range(pmatch, 0))
+            .collect();
+        let caps = Captures::new(string, locs)?;
+        Ok(Some(std::array::from_fn(|i| caps.get(i + 1))))
+    }
+
+    /// Matches `string`, writing the capture groups into an existing [`Captures`] buffer.
+    ///
+    /// This is like [`Regex::regexec`], but reuses the storage in `caps` instead of allocating
+    /// new results for every call, which matters in hot loops such as parsers. The number of
+    /// groups filled in is the length of `caps`, as given to [`Captures::with_len`] or found by an
+    /// earlier match. Since `caps` borrows `string` afterwards, use [`Captures::reset`] to reuse it
+    /// with strings that do not outlive it.
+    ///
+    /// # Arguments
+    /// * `caps`: buffer to write the capture groups into
+    /// * `string`: string to match against `compiled_reg`
+    /// * `flags`: [`RegexecFlags`] to pass to [`tre_regnexec`](tre_regex_sys::tre_regnexec).
+    ///
+    /// # Returns
+    /// `true` if the pattern matched, `false` otherwise. If there was no match, every group in
+    /// `caps` is `None`.
+    ///
+    /// # Errors
+    /// If an error is encountered during matching, it returns a [`RegexError`]. An error is also
+    /// returned if any group does not fall on character boundaries. On error,
+    /// every group in `caps` is `None`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use tre_regex::{Captures, RegcompFlags, RegexecFlags, Regex};
+    ///
+    /// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    /// let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    ///
+    /// let compiled_reg = Regex::new("^([[:alpha:]]+)=([[:digit:]]+)$", regcomp_flags)?;
+    /// let mut caps = Captures::with_len(3);
+    /// for line in ["a=1", "junk", "b=22"] {
+    ///     if compiled_reg.captures_read(&mut caps, line, regexec_flags)? {
+    ///         println!("{:?} is {:?}", caps.get(1), caps.get(2));
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`RegexError`]: crate::RegexError
+    pub fn captures_read<'h>(
+        &self,
+        caps: &mut Captures<'h>,
+        string: &'h str,
+        flags: RegexecFlags,
+    ) -> Result<bool> {
+        if caps.pmatch.len() < caps.locs.len() {
+            caps.pmatch.resize(
+                caps.locs.len(),
+                tre::regmatch_t {
+                    rm_so: -1,
+                    rm_eo: -1,
+                },
+            );
+        }
+        // Clear the old groups first, so that on error the buffer is left empty rather than
+        // pointing into the wrong string.
+        caps.string = string;
+        caps.locs.fill(None);
+
+        let matched = self.exec_at(string.as_bytes(), 0, &mut caps.pmatch, flags)?;
+        if !matched {
+            caps.locs.resize(caps.pmatch.len(), None);
+            return Ok(false);
+        }
+
+        // Validate before writing anything, so a caller ignoring the error cannot slice off a
+        // character boundary through `caps`.
+        check_boundaries(
+            string,
+            caps.pmatch
+                .iter()
+                .filter_map(|&pmatch| match_range(pmatch, 0)),
+        )?;
+
+        caps.locs.clear();
+        caps.locs
+            .extend(caps.pmatch.iter().map(|&pmatch| match_range(pmatch, 0)));
+        Ok(true)
+    }
+}
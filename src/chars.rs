@@ -0,0 +1,195 @@
+use std::ops::Range;
+
+use crate::{err::Result, flags::RegexecFlags, Regex};
+
+impl Regex {
+    /// Returns an iterator over all successive non-overlapping matches in a stream of [`char`]s.
+    ///
+    /// The characters are read `chunk` at a time into a buffer, which is searched as with
+    /// [`Regex::find_stream`]. A match is only reported once at least `chunk` more characters have
+    /// been read after it (or the stream has ended), so that more input cannot change it. Each item
+    /// is the range of character indices of the match in the stream, counting from zero.
+    ///
+    /// Empty matches are handled as with [`Regex::find_iter`].
+    ///
+    /// # Arguments
+    /// * `chars`: stream of characters to match against `compiled_reg`
+    /// * `chunk`: number of characters to read at a time, and the longest match that is always
+    ///   found correctly; zero is treated as one
+    /// * `flags`: [`RegexecFlags`] to pass to [`tre_regnexec`](tre_regex_sys::tre_regnexec).
+    ///
+    /// # Returns
+    /// A [`CharMatches`] iterator yielding [`Result`]s of character ranges.
+    ///
+    /// # Errors
+    /// If an error is encountered during matching, the iterator yields a [`RegexError`] and then
+    /// stops.
+    ///
+    /// # Caveats
+    /// A match longer than `chunk` characters may be reported differently than in a search of the
+    /// whole stream at once. The buffer holds the text from the start of the last match onwards,
+    /// so a match that keeps growing, such as `.*` over a stream without line breaks, keeps
+    /// buffering until it ends.
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use tre_regex::{RegcompFlags, RegexecFlags, Regex};
+    ///
+    /// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    /// let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    ///
+    /// let compiled_reg = Regex::new("[[:digit:]]+", regcomp_flags)?;
+    /// let chars = "é 1 22 333".chars();
+    /// let ranges = compiled_reg
+    ///     .find_iter_chars(chars, 4, regexec_flags)
+    ///     .collect::<Result<Vec<_>>>()?;
+    ///
+    /// assert_eq!(ranges, vec![2..3, 4..6, 7..10]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`RegexError`]: crate::RegexError
+    pub fn find_iter_chars<I: Iterator<Item = char>>(
+        &self,
+        chars: I,
+        chunk: usize,
+        flags: RegexecFlags,
+    ) -> CharMatches<'_, I> {
+        CharMatches {
+            regex: self,
+            chars,
+            chunk: chunk.max(1),
+            flags,
+            buf: String::new(),
+            base: 0,
+            pos: 0,
+            last_end: None,
+            exhausted: false,
+            done: false,
+        }
+    }
+}
+
+/// Iterator over successive matches in a stream of characters, returned by
+/// [`Regex::find_iter_chars`].
+#[derive(Debug)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct CharMatches<'r, I> {
+    regex: &'r Regex,
+    chars: I,
+    chunk: usize,
+    flags: RegexecFlags,
+    buf: String,
+
+    // Character index in the stream of the start of buf
+    base: usize,
+
+    // Byte offsets into buf
+    pos: usize,
+    last_end: Option<usize>,
+
+    exhausted: bool,
+    done: bool,
+}
+
+impl<I: Iterator<Item = char>> CharMatches<'_, I> {
+    /// Reads the next chunk of characters into the buffer.
+    fn fill(&mut self) {
+        let len = self.buf.len();
+        self.buf.extend(self.chars.by_ref().take(self.chunk));
+        if self.buf[len..].chars().count() < self.chunk {
+            self.exhausted = true;
+        }
+    }
+
+    /// Drops the text before the search position, except for the character just before it, which
+    /// is kept for context.
+    fn discard(&mut self) {
+        let Some((cut, _)) = self.buf[..self.pos].char_indices().next_back() else {
+            return;
+        };
+
+        self.base += self.buf[..cut].chars().count();
+        self.buf.drain(..cut);
+        self.pos -= cut;
+        self.last_end = self.last_end.and_then(|end| end.checked_sub(cut));
+    }
+
+    /// Converts a byte offset into the buffer to a character index in the stream.
+    fn char_index(&self, offset: usize) -> usize {
+        self.base + self.buf[..offset].chars().count()
+    }
+}
+
+impl<I: Iterator<Item = char>> Iterator for CharMatches<'_, I> {
+    type Item = Result<Range<usize>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.done && self.pos <= self.buf.len() {
+            // The start of the buffer is only the start of the stream before anything is dropped.
+            let flags = if self.base > 0 {
+                self.flags.add(RegexecFlags::NOTBOL)
+            } else {
+                self.flags
+            };
+            let data = self.buf.as_bytes();
+            let found = match self
+                .regex
+                .find_stream(data, self.pos, !self.exhausted, flags)
+            {
+                Ok(found) => found,
+                Err(e) => {
+                    self.done = true;
+                    return Some(Err(e));
+                }
+            };
+
+            let Some(found) = found else {
+                if self.exhausted {
+                    break;
+                }
+
+                // Nothing matches yet, but a match may begin in the last chunk.
+                let keep = self.buf[self.pos..]
+                    .char_indices()
+                    .rev()
+                    .nth(self.chunk - 1)
+                    .map_or(self.pos, |(i, _)| self.pos + i);
+                self.pos = keep;
+                self.discard();
+                self.fill();
+                continue;
+            };
+
+            let (start, end) = (found.range.start, found.range.end);
+            if !self.exhausted && self.buf[end..].chars().count() < self.chunk {
+                // More input could still change this match.
+                self.discard();
+                self.fill();
+                continue;
+            }
+
+            if start == end {
+                self.pos = self.buf[end..]
+                    .chars()
+                    .next()
+                    .map_or(end + 1, |c| end + c.len_utf8());
+                if self.last_end == Some(end) {
+                    // Empty match right after the previous match; skip it.
+                    continue;
+                }
+            } else {
+                self.pos = end;
+            }
+
+            self.last_end = Some(end);
+            return Some(Ok(self.char_index(start)..self.char_index(end)));
+        }
+
+        self.done = true;
+        None
+    }
+}
@@ -1,8 +1,9 @@
 use std::ffi::c_char;
 use std::mem;
+use std::str::FromStr;
 
 use crate::{
-    err::{regerror, Result},
+    err::{regerror, BindingErrorCode, ErrorKind, ErrorPhase, RegexError, Result},
     flags::RegcompFlags,
     tre, Regex,
 };
@@ -52,6 +53,266 @@ impl Regex {
         Self::new_bytes(reg.as_bytes(), flags)
     }
 
+    /// Compiles an extended regex for matching against multi-line text, one line at a time.
+    ///
+    /// [`RegcompFlags::EXTENDED`] and [`RegcompFlags::NEWLINE`] are added to `extra_flags`. With
+    /// [`RegcompFlags::NEWLINE`]:
+    /// * `^` matches at the start of the string and just after every newline
+    /// * `$` matches at the end of the string and just before every newline
+    /// * `.` and non-matching bracket expressions such as `[^a]` do not match a newline
+    ///
+    /// # Arguments
+    /// * `reg`: regular expression to compile, as a string.
+    /// * `extra_flags`: additional [`RegcompFlags`] to pass to the function.
+    ///
+    /// # Returns
+    /// An opaque [`Regex`] object will be returned. It will be freed automatically when dropped.
+    ///
+    /// # Errors
+    /// Will return a [`RegexError`] upon failure.
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use tre_regex::{RegcompFlags, RegexecFlags, Regex};
+    ///
+    /// let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    ///
+    /// let compiled_reg = Regex::new_multiline("^ERROR: (.*)$", RegcompFlags::new())?;
+    /// let matches = compiled_reg.regexec("INFO: ok\nERROR: disk full\n", 2, regexec_flags)?;
+    ///
+    /// assert_eq!(*matches[1].as_ref().unwrap().as_ref().unwrap(), "disk full");
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`RegexError`]: crate::RegexError
+    pub fn new_multiline(reg: &str, extra_flags: RegcompFlags) -> Result<Self> {
+        let flags = extra_flags
+            .add(RegcompFlags::EXTENDED)
+            .add(RegcompFlags::NEWLINE);
+        Self::new(reg, flags)
+    }
+
+    /// Combines several patterns into one regex which matches any of them.
+    ///
+    /// Each pattern is first compiled on its own to check it, then they are joined into a single
+    /// alternation, `(p1)|(p2)|...` (or `\(p1\)\|\(p2\)...` without
+    /// [`RegcompFlags::EXTENDED`]), which is compiled once. Searching with the result is usually
+    /// much faster than trying each pattern in turn.
+    ///
+    /// # Group numbering
+    /// Every pattern is wrapped in a capture group, so which pattern matched can be found from
+    /// which of these groups took part in the match. The groups of a pattern follow its wrapping
+    /// group, so the wrapping group of pattern `i` is `1 + n_0 + 1 + n_1 + 1 + ... + n_(i-1)`,
+    /// where `n_j` is the number of groups in pattern `j` (see [`count_groups`]). If no pattern has
+    /// groups of its own, pattern `i` is simply group `i + 1`.
+    ///
+    /// With [`RegcompFlags::LITERAL`], each pattern is escaped and matched literally instead, and
+    /// so has no groups of its own.
+    ///
+    /// # Arguments
+    /// * `patterns`: regular expressions to combine, as strings.
+    /// * `flags`: [`RegcompFlags`] to pass to the function.
+    ///
+    /// # Returns
+    /// An opaque [`Regex`] object will be returned. It will be freed automatically when dropped.
+    ///
+    /// # Errors
+    /// Will return a [`RegexError`] if any pattern fails to compile on its own, or if compiling the
+    /// combined pattern fails. A [`BindingErrorCode::NO_PATTERN`] error is returned if `patterns`
+    /// is empty.
+    ///
+    /// # Caveats
+    /// Backreferences are not renumbered, so a backreference in any pattern but the first refers
+    /// to the wrong group. Anchors keep their meaning, since each pattern is grouped separately.
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use tre_regex::{count_groups, RegcompFlags, RegexecFlags, Regex};
+    ///
+    /// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    /// let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    ///
+    /// let patterns = ["^([0-9]+)$", "^[a-z]+$", "^(x)(y)$"];
+    /// let compiled_reg = Regex::union(&patterns, regcomp_flags)?;
+    ///
+    /// // Find the wrapping group of each pattern
+    /// let mut groups = Vec::new();
+    /// let mut next = 1;
+    /// for pattern in patterns {
+    ///     groups.push(next);
+    ///     next += count_groups(pattern, regcomp_flags)? + 1;
+    /// }
+    /// assert_eq!(groups, vec![1, 3, 4]);
+    ///
+    /// let matches = compiled_reg.regexec("abc", compiled_reg.captures_len()?, regexec_flags)?;
+    /// let which = groups.iter().position(|&group| matches[group].is_some());
+    /// assert_eq!(which, Some(1));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`count_groups`]: crate::count_groups
+    /// [`BindingErrorCode::NO_PATTERN`]: crate::BindingErrorCode::NO_PATTERN
+    /// [`RegexError`]: crate::RegexError
+    pub fn union(patterns: &[&str], flags: RegcompFlags) -> Result<Self> {
+        if patterns.is_empty() {
+            return Err(RegexError::new(
+                ErrorKind::Binding(BindingErrorCode::NO_PATTERN),
+                "Attempted to combine an empty list of patterns",
+            ));
+        }
+
+        let literal = flags.get() & RegcompFlags::LITERAL != 0;
+        let extended = literal || flags.get() & RegcompFlags::EXTENDED != 0;
+        let (open, close, or): (&[u8], &[u8], &[u8]) = if extended {
+            (b"(", b")", b"|")
+        } else {
+            (b"\\(", b"\\)", b"\\|")
+        };
+
+        let mut combined = Vec::new();
+        for (i, pattern) in patterns.iter().enumerate() {
+            if i > 0 {
+                combined.extend_from_slice(or);
+            }
+            combined.extend_from_slice(open);
+            if literal {
+                for &c in pattern.as_bytes() {
+                    if METACHARACTERS.contains(&c) {
+                        combined.push(b'\\');
+                    }
+                    combined.push(c);
+                }
+            } else {
+                // Check each pattern alone, so that one cannot break out of its group.
+                Self::new(pattern, flags)?;
+                combined.extend_from_slice(pattern.as_bytes());
+            }
+            combined.extend_from_slice(close);
+        }
+
+        let flags = if literal {
+            flags
+                .remove(RegcompFlags::LITERAL)
+                .add(RegcompFlags::EXTENDED)
+        } else {
+            flags
+        };
+        Self::new_bytes(&combined, flags)
+    }
+
+    /// Compiles a regex after checking it does not use backreferences.
+    ///
+    /// TRE has no flag to disable backreferences, which can make matching very slow. This scans
+    /// the source of `reg` for `\1` through `\9`, and rejects the pattern without compiling it if
+    /// any are found. An escaped backslash (`\\`) is skipped over, so `\\1` is accepted.
+    ///
+    /// **WARNING**: this is a heuristic on the pattern source, not a guarantee. It errs on the
+    /// side of rejecting: a `\1` inside a bracket expression, or in a pattern compiled with
+    /// [`RegcompFlags::LITERAL`], is rejected even though it is not a backreference there.
+    ///
+    /// # Arguments
+    /// * `reg`: regular expression to compile, as a string.
+    /// * `flags`: [`RegcompFlags`] to pass to the function.
+    ///
+    /// # Returns
+    /// An opaque [`Regex`] object will be returned. It will be freed automatically when dropped.
+    ///
+    /// # Errors
+    /// Will return a [`RegexError`] if `reg` appears to contain a backreference, or if compilation
+    /// fails.
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use tre_regex::{RegcompFlags, Regex};
+    ///
+    /// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    ///
+    /// assert!(Regex::new_restricted("(a+)b", regcomp_flags).is_ok());
+    /// assert!(Regex::new_restricted("(a+)b\\1", regcomp_flags).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`RegexError`]: crate::RegexError
+    pub fn new_restricted(reg: &str, flags: RegcompFlags) -> Result<Self> {
+        let mut bytes = reg.bytes().enumerate();
+        while let Some((_, c)) = bytes.next() {
+            if c != b'\\' {
+                continue;
+            }
+
+            // Whatever follows a backslash is escaped, including another backslash.
+            if let Some((i, b'1'..=b'9')) = bytes.next() {
+                return Err(RegexError::new(
+                    ErrorKind::Binding(BindingErrorCode::RESTRICTED),
+                    &format!("Backreference at offset {} is not allowed", i - 1),
+                )
+                .with_pattern(reg)
+                .with_phase(ErrorPhase::Compile));
+            }
+        }
+
+        Self::new(reg, flags)
+    }
+
+    /// Compiles a regex for ASCII case-insensitive matching with [`Regex::regexec_ascii_ci`].
+    ///
+    /// Rather than using [`RegcompFlags::ICASE`], the pattern is lowercased before compiling, and
+    /// [`Regex::regexec_ascii_ci`] lowercases the input before matching. For ASCII-heavy data this
+    /// can be faster than TRE's own case-insensitive matching.
+    ///
+    /// **WARNING**: only ASCII letters are folded; other characters must match exactly. Escape
+    /// sequences such as `\W` are left alone, but everything else in the pattern is lowercased,
+    /// so character classes which depend on case (such as `[[:upper:]]`) will not work as
+    /// expected. Plain [`Regex::regexec`] on the resulting object is *not* case-insensitive.
+    ///
+    /// # Arguments
+    /// * `reg`: regular expression to compile, as a string.
+    /// * `flags`: [`RegcompFlags`] to pass to the function.
+    ///
+    /// # Returns
+    /// An opaque [`Regex`] object will be returned. It will be freed automatically when dropped.
+    ///
+    /// # Errors
+    /// Will return a [`RegexError`] upon failure.
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use tre_regex::{RegcompFlags, RegexecFlags, Regex};
+    ///
+    /// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    /// let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    ///
+    /// let compiled_reg = Regex::new_ascii_ci("ERROR: ([A-Z]+)", regcomp_flags)?;
+    /// let matches = compiled_reg.regexec_ascii_ci("Error: Disk full", 2, regexec_flags)?;
+    ///
+    /// assert_eq!(*matches[1].as_ref().unwrap().as_ref().unwrap(), "Disk");
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`RegexError`]: crate::RegexError
+    pub fn new_ascii_ci(reg: &str, flags: RegcompFlags) -> Result<Self> {
+        let mut lowered = String::with_capacity(reg.len());
+        let mut escaped = false;
+        for c in reg.chars() {
+            lowered.push(if escaped { c } else { c.to_ascii_lowercase() });
+            escaped = !escaped && c == '\\';
+        }
+
+        Self::new(&lowered, flags)
+    }
+
     /// Compiles a regex contained in a `u8` slice and wraps it in a `Regex` object.
     ///
     /// # Arguments
@@ -108,13 +369,361 @@ impl Regex {
         };
 
         // SAFETY: tre::tre_regcomp fully initalises compiled_reg
-        let compiled_reg = Self(Some(unsafe { unwrapped_compiled_reg.assume_init() }));
+        let compiled_reg = Self {
+            regex: Some(unsafe { unwrapped_compiled_reg.assume_init() }),
+            pattern: Some(reg.into()),
+            flags,
+            literal: is_literal(reg, flags),
+            #[cfg(feature = "wchar")]
+            wide_pattern: None,
+            owned: true,
+        };
         if result != 0 {
-            return Err(regerror(&compiled_reg, result));
+            return Err(regerror(&compiled_reg, result)
+                .with_pattern(&String::from_utf8_lossy(reg))
+                .with_phase(ErrorPhase::Compile));
         }
 
         Ok(compiled_reg)
     }
+
+    /// Compiles a new regex into this `Regex` object, replacing the existing one.
+    ///
+    /// The new regex is compiled first; if that succeeds, the old compiled regex is freed with
+    /// [`tre_regfree`](tre_regex_sys::tre_regfree) and replaced. If compilation fails, the object
+    /// is left untouched.
+    ///
+    /// # Arguments
+    /// * `reg`: regular expression to compile, as a string.
+    /// * `flags`: [`RegcompFlags`] to pass to the function.
+    ///
+    /// # Errors
+    /// Will return a [`RegexError`] upon failure.
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use tre_regex::{RegcompFlags, RegexecFlags, Regex};
+    ///
+    /// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    /// let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    ///
+    /// let mut compiled_reg = Regex::new("hello", regcomp_flags)?;
+    /// compiled_reg.recompile("world", regcomp_flags)?;
+    ///
+    /// let matches = compiled_reg.regexec("hello world", 1, regexec_flags)?;
+    /// assert_eq!(matches[0].as_ref().unwrap().as_ref().unwrap(), "world");
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`RegexError`]: crate::RegexError
+    #[inline]
+    pub fn recompile(&mut self, reg: &str, flags: RegcompFlags) -> Result<()> {
+        self.recompile_bytes(reg.as_bytes(), flags)
+    }
+
+    /// Compiles a new regex contained in a `u8` slice into this `Regex` object, replacing the
+    /// existing one.
+    ///
+    /// See [`Regex::recompile`] for details.
+    ///
+    /// # Arguments
+    /// * `reg`: regular expression to compile, as a string.
+    /// * `flags`: [`RegcompFlags`] to pass to the function.
+    ///
+    /// # Errors
+    /// Will return a [`RegexError`] upon failure.
+    ///
+    /// [`RegexError`]: crate::RegexError
+    pub fn recompile_bytes(&mut self, reg: &[u8], flags: RegcompFlags) -> Result<()> {
+        let compiled_reg = Self::new_bytes(reg, flags)?;
+
+        // Dropping the old value frees it with tre_regfree.
+        *self = compiled_reg;
+        Ok(())
+    }
+
+    /// Makes a copy of this `Regex` object by compiling its pattern again.
+    ///
+    /// The copy is compiled from the stored source pattern and the [`RegcompFlags`] originally
+    /// used, so it is completely independent of this object. A pattern which was compiled from a
+    /// wide string is compiled as a wide string again.
+    ///
+    /// # Returns
+    /// A new [`Regex`] object, compiled from the same pattern.
+    ///
+    /// # Errors
+    /// Will return a [`RegexError`] if the object has no stored pattern (for instance, if it was
+    /// created with [`Regex::new_from`]), or if compilation fails.
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use tre_regex::{RegcompFlags, Regex};
+    ///
+    /// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    /// let compiled_reg = Regex::new("^(hello).*(world)$", regcomp_flags)?;
+    /// let copy = compiled_reg.try_clone()?;
+    ///
+    /// assert_eq!(copy.as_str(), compiled_reg.as_str());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`RegexError`]: crate::RegexError
+    pub fn try_clone(&self) -> Result<Self> {
+        #[cfg(feature = "wchar")]
+        if let Some(pattern) = &self.wide_pattern {
+            return Self::new_wide(pattern, self.flags);
+        }
+
+        let Some(pattern) = self.as_bytes() else {
+            return Err(RegexError::new(
+                ErrorKind::Binding(BindingErrorCode::NO_PATTERN),
+                "Attempted to recompile a Regex object with no stored pattern"
+            ));
+        };
+
+        Self::new_bytes(pattern, self.flags)
+    }
+
+    /// Gets a rough estimate of the size of the automaton TRE built for this object.
+    ///
+    /// TRE does not expose the size of its compiled automaton, so this is a heuristic based on
+    /// the stored pattern: every literal, bracket expression and operator counts for one, and a
+    /// bounded repeat such as `(ab){3}` counts its operand once for every repetition, since TRE
+    /// expands it into that many copies. Nested repeats multiply.
+    ///
+    /// This is an estimate, not a guarantee of memory use or matching time; it only grows with
+    /// the size of the automaton. To check an untrusted pattern against a budget before compiling
+    /// it, use [`Regex::estimate_complexity`].
+    ///
+    /// # Returns
+    /// The estimated complexity. If the object has no stored pattern (for instance, if it was
+    /// created with [`Regex::new_from`]), only the number of capture groups is taken into
+    /// account.
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use tre_regex::{RegcompFlags, Regex};
+    ///
+    /// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    /// let small = Regex::new("(ab)+", regcomp_flags)?;
+    /// let large = Regex::new("((ab){100}){100}", regcomp_flags)?;
+    ///
+    /// assert!(large.complexity_hint() > 100 * small.complexity_hint());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn complexity_hint(&self) -> usize {
+        self.as_bytes().map_or_else(
+            || self.get().map_or(0, |compiled_reg| compiled_reg.re_nsub + 1),
+            |pattern| estimate_complexity(pattern, self.flags),
+        )
+    }
+
+    /// Estimates the size of the automaton TRE would build for `reg` compiled with `flags`,
+    /// without compiling it.
+    ///
+    /// This is the same heuristic as [`Regex::complexity_hint`], and is meant for setting a budget
+    /// on untrusted patterns before handing them to TRE. It is an estimate, not a guarantee, and
+    /// it does not check that `reg` is valid.
+    ///
+    /// # Arguments
+    /// * `reg`: regular expression to estimate
+    /// * `flags`: [`RegcompFlags`] the pattern would be compiled with.
+    ///
+    /// # Returns
+    /// The estimated complexity.
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use tre_regex::{RegcompFlags, Regex};
+    ///
+    /// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    /// let untrusted = "((ab){255}){255}";
+    ///
+    /// if Regex::estimate_complexity(untrusted, regcomp_flags) > 10_000 {
+    ///     println!("Pattern is too complex");
+    /// } else {
+    ///     let compiled_reg = Regex::new(untrusted, regcomp_flags)?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn estimate_complexity(reg: &str, flags: RegcompFlags) -> usize {
+        estimate_complexity(reg.as_bytes(), flags)
+    }
+}
+
+impl FromStr for Regex {
+    type Err = RegexError;
+
+    /// Compiles an extended regex, so that patterns can be parsed with [`str::parse`].
+    ///
+    /// The pattern is always compiled with [`RegcompFlags::EXTENDED`] and no other flags. To use
+    /// basic syntax, or any other flags, use [`Regex::new`] instead.
+    ///
+    /// # Errors
+    /// Will return a [`RegexError`] upon failure.
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use tre_regex::{RegexecFlags, Regex};
+    ///
+    /// let compiled_reg: Regex = "^(hello|goodbye) world$".parse()?;
+    ///
+    /// assert!(compiled_reg.is_match("hello world", RegexecFlags::new())?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn from_str(s: &str) -> Result<Self> {
+        Self::new(s, RegcompFlags::new().add(RegcompFlags::EXTENDED))
+    }
+}
+
+/// Characters which may have a special meaning in a pattern, in either basic or extended syntax.
+const METACHARACTERS: &[u8] = b"\\.[]()*+?{}|^$";
+
+/// Checks whether `reg` compiled with `flags` matches only itself, so that matching is a plain
+/// substring search.
+///
+/// This is conservative: some patterns which are literal to TRE (such as `a+` in basic syntax)
+/// are not detected.
+fn is_literal(reg: &[u8], flags: RegcompFlags) -> bool {
+    if flags.get() & RegcompFlags::ICASE != 0
+        || reg.is_empty()
+        || std::str::from_utf8(reg).is_err()
+    {
+        return false;
+    }
+
+    flags.get() & RegcompFlags::LITERAL != 0 || !reg.iter().any(|c| METACHARACTERS.contains(c))
+}
+
+/// Estimates the size of the automaton for `reg` compiled with `flags`.
+///
+/// See [`Regex::complexity_hint`].
+fn estimate_complexity(reg: &[u8], flags: RegcompFlags) -> usize {
+    if flags.get() & RegcompFlags::LITERAL != 0 {
+        return reg.len();
+    }
+
+    let extended = flags.get() & RegcompFlags::EXTENDED != 0;
+
+    // Weight of the current sequence, and of its last atom (the operand of a repeat).
+    let mut total = 0usize;
+    let mut last = 0usize;
+    let mut groups = Vec::new();
+
+    let mut i = 0;
+    while i < reg.len() {
+        // In basic syntax, operators are escaped; in extended syntax, escapes are literals.
+        let escaped = reg[i] == b'\\' && i + 1 < reg.len();
+        i += usize::from(escaped);
+        let c = reg[i];
+        let operator = extended != escaped;
+        i += 1;
+
+        match c {
+            b'(' if operator => {
+                groups.push((total, last));
+                total = 0;
+                last = 0;
+            }
+            b')' if operator => {
+                let Some((outer_total, _)) = groups.pop() else { continue; };
+                last = total.saturating_add(1);
+                total = outer_total.saturating_add(last);
+            }
+            b'*' if !escaped => {
+                total = total.saturating_add(1);
+            }
+            b'+' | b'?' if operator => {
+                total = total.saturating_add(1);
+            }
+            b'{' if operator => {
+                let close: &[u8] = if extended { b"}" } else { b"\\}" };
+                let Some(len) = reg[i..].windows(close.len()).position(|w| w == close) else {
+                    continue;
+                };
+                let bounds = &reg[i..i + len];
+                i += len + close.len();
+
+                // Approximate matching costs such as `{~2}` do not repeat anything.
+                let count = if bounds.iter().all(|&b| b.is_ascii_digit() || b == b',') {
+                    let mut parts = bounds.split(|&b| b == b',');
+                    let min = parts.next().and_then(parse_count).unwrap_or(0);
+                    match parts.next() {
+                        Some([]) => min + 1,
+                        Some(max) => parse_count(max).unwrap_or(min),
+                        None => min,
+                    }
+                } else {
+                    1
+                };
+
+                let repeated = last.saturating_mul(count);
+                total = total.saturating_sub(last).saturating_add(repeated);
+                last = repeated;
+                total = total.saturating_add(1);
+            }
+            b'[' if !escaped => {
+                // Skip to the end of the bracket expression; a leading ] is part of the set.
+                let mut j = i + usize::from(reg.get(i) == Some(&b'^'));
+                j += usize::from(reg.get(j) == Some(&b']'));
+                while j < reg.len() && reg[j] != b']' {
+                    if reg[j] == b'[' && matches!(reg.get(j + 1), Some(b':' | b'=' | b'.')) {
+                        let delim = reg[j + 1];
+                        j += 2;
+                        while j + 1 < reg.len() && !(reg[j] == delim && reg[j + 1] == b']') {
+                            j += 1;
+                        }
+                        j += 2;
+                    } else {
+                        j += 1;
+                    }
+                }
+                i = (j + 1).min(reg.len());
+                total = total.saturating_add(1);
+                last = 1;
+            }
+            b'|' if operator => {
+                total = total.saturating_add(1);
+                last = 0;
+            }
+            _ => {
+                total = total.saturating_add(1);
+                last = 1;
+            }
+        }
+    }
+
+    // Unbalanced parentheses cannot compile, but count what was seen anyway.
+    while let Some((outer_total, _)) = groups.pop() {
+        total = outer_total.saturating_add(total);
+    }
+
+    total
+}
+
+/// Parses a repeat count, capped at the largest count TRE accepts.
+fn parse_count(count: &[u8]) -> Option<usize> {
+    const DUP_MAX: usize = 255;
+
+    let count = std::str::from_utf8(count).ok()?.parse::<usize>().ok()?;
+    Some(count.min(DUP_MAX))
 }
 
 /// Compiles a regex.
@@ -205,3 +814,74 @@ pub fn regcomp(reg: &str, flags: RegcompFlags) -> Result<Regex> {
 pub fn regcomp_bytes(reg: &[u8], flags: RegcompFlags) -> Result<Regex> {
     Regex::new_bytes(reg, flags)
 }
+
+/// Compiles each of a batch of regexes independently.
+///
+/// Unlike compiling them one by one with `?`, this does not stop at the first error, so every
+/// bad pattern can be reported at once.
+///
+/// # Arguments
+/// * `patterns`: regular expressions to compile, as strings.
+/// * `flags`: [`RegcompFlags`] to pass to the function.
+///
+/// # Returns
+/// A [`Vec`] with one [`Result`] per pattern, in the same order as `patterns`. Each is either the
+/// compiled [`Regex`], or the [`RegexError`] from compiling it.
+///
+/// # Examples
+/// ```
+/// use tre_regex::{compile_all, RegcompFlags};
+///
+/// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+/// let results = compile_all(&["^a+$", "[b", "c{2}", "(d"], regcomp_flags);
+///
+/// for (i, result) in results.iter().enumerate() {
+///     if let Err(e) = result {
+///         println!("Pattern {i} is invalid: {e}");
+///     }
+/// }
+/// ```
+///
+/// [`RegexError`]: crate::RegexError
+#[must_use]
+pub fn compile_all(patterns: &[&str], flags: RegcompFlags) -> Vec<Result<Regex>> {
+    patterns
+        .iter()
+        .map(|pattern| Regex::new(pattern, flags))
+        .collect()
+}
+
+/// Compiles `pattern` only to count its capture groups.
+///
+/// The compiled regex is dropped before returning. This is useful for validating a pattern as it
+/// is being edited, such as to show how many groups it defines.
+///
+/// # Arguments
+/// * `pattern`: regular expression to compile
+/// * `flags`: [`RegcompFlags`] to pass to the function.
+///
+/// # Returns
+/// The number of parenthesised capture groups in `pattern`, not counting group 0 (the whole
+/// match). See [`Regex::captures_len`] for the count including it.
+///
+/// # Errors
+/// If `pattern` could not be compiled, the [`RegexError`] from compiling it is returned.
+///
+/// # Examples
+/// ```
+/// # use tre_regex::Result;
+/// # fn main() -> Result<()> {
+/// use tre_regex::{count_groups, RegcompFlags};
+///
+/// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+///
+/// assert_eq!(count_groups("^([a-z]+)=(([0-9]+)|none)$", regcomp_flags)?, 3);
+/// assert!(count_groups("([a-z]+", regcomp_flags).is_err());
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`RegexError`]: crate::RegexError
+pub fn count_groups(pattern: &str, flags: RegcompFlags) -> Result<usize> {
+    Ok(Regex::new(pattern, flags)?.captures_len()? - 1)
+}
@@ -1,5 +1,7 @@
+use std::error::Error;
 use std::ffi::{c_char, c_int, c_uint, CString};
 use std::fmt;
+use std::mem;
 use std::ptr::null_mut;
 
 use crate::{tre, Regex};
@@ -21,6 +23,180 @@ impl BindingErrorCode {
 
     /// An attempt was made to unwrap a vacant [`Regex`] object
     pub const REGEX_VACANT: Self = Self(3);
+
+    /// An attempt was made to recompile a [`Regex`] object with no stored pattern
+    pub const NO_PATTERN: Self = Self(4);
+
+    /// A pattern used syntax rejected by [`Regex::new_restricted`]
+    pub const RESTRICTED: Self = Self(5);
+
+    /// The input was too long for TRE to report match offsets in
+    pub const INPUT_TOO_LONG: Self = Self(6);
+
+    /// The pattern has fewer capture groups than were requested
+    pub const GROUP_COUNT: Self = Self(7);
+
+    /// No rule of a [`Scanner`](crate::Scanner) matched the input
+    pub const NO_TOKEN: Self = Self(8);
+
+    /// A capture group did not participate in the match, or its text could not be parsed
+    pub const CAPTURE_PARSE: Self = Self(9);
+
+    /// An offset or range passed in, or reported by TRE, lies outside the input
+    pub const OUT_OF_BOUNDS: Self = Self(10);
+
+    /// Gets a short, human-readable description of this error code.
+    #[must_use]
+    pub const fn description(&self) -> &'static str {
+        match *self {
+            Self::CSTRING => "C string conversion failed",
+            Self::ENCODING => "invalid encoding",
+            Self::REGEX_VACANT => "vacant regex",
+            Self::NO_PATTERN => "no stored pattern",
+            Self::RESTRICTED => "restricted syntax",
+            Self::INPUT_TOO_LONG => "input too long",
+            Self::GROUP_COUNT => "not enough groups",
+            Self::NO_TOKEN => "no matching token",
+            Self::CAPTURE_PARSE => "capture parse failed",
+            Self::OUT_OF_BOUNDS => "offset out of bounds",
+            _ => "binding error",
+        }
+    }
+}
+
+/// Error codes returned by TRE, as a Rust enum.
+///
+/// This mirrors [`reg_errcode_t`](tre_regex_sys::reg_errcode_t), without depending on the
+/// representation used by [`tre_regex_sys`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum TreErrorCode {
+    /// No match was found (`REG_NOMATCH`)
+    NoMatch,
+
+    /// Invalid regular expression (`REG_BADPAT`)
+    BadPattern,
+
+    /// Unknown collating element (`REG_ECOLLATE`)
+    Collate,
+
+    /// Unknown character class name (`REG_ECTYPE`)
+    CharClass,
+
+    /// Trailing backslash (`REG_EESCAPE`)
+    Escape,
+
+    /// Invalid backreference (`REG_ESUBREG`)
+    SubReg,
+
+    /// Missing `]` (`REG_EBRACK`)
+    Bracket,
+
+    /// Missing `)` (`REG_EPAREN`)
+    Paren,
+
+    /// Missing `}` (`REG_EBRACE`)
+    Brace,
+
+    /// Invalid contents of `{}` (`REG_BADBR`)
+    BadBrace,
+
+    /// Invalid character range (`REG_ERANGE`)
+    Range,
+
+    /// Out of memory (`REG_ESPACE`)
+    Space,
+
+    /// Invalid use of repetition operators (`REG_BADRPT`)
+    BadRepeat,
+}
+
+impl TreErrorCode {
+    /// Converts a [`reg_errcode_t`](tre_regex_sys::reg_errcode_t) into a `TreErrorCode`.
+    ///
+    /// # Returns
+    /// The matching `TreErrorCode`, or `None` for `REG_OK` and unknown codes.
+    #[must_use]
+    pub const fn from_raw(code: tre::reg_errcode_t) -> Option<Self> {
+        Some(match code {
+            tre::reg_errcode_t::REG_NOMATCH => Self::NoMatch,
+            tre::reg_errcode_t::REG_BADPAT => Self::BadPattern,
+            tre::reg_errcode_t::REG_ECOLLATE => Self::Collate,
+            tre::reg_errcode_t::REG_ECTYPE => Self::CharClass,
+            tre::reg_errcode_t::REG_EESCAPE => Self::Escape,
+            tre::reg_errcode_t::REG_ESUBREG => Self::SubReg,
+            tre::reg_errcode_t::REG_EBRACK => Self::Bracket,
+            tre::reg_errcode_t::REG_EPAREN => Self::Paren,
+            tre::reg_errcode_t::REG_EBRACE => Self::Brace,
+            tre::reg_errcode_t::REG_BADBR => Self::BadBrace,
+            tre::reg_errcode_t::REG_ERANGE => Self::Range,
+            tre::reg_errcode_t::REG_ESPACE => Self::Space,
+            tre::reg_errcode_t::REG_BADRPT => Self::BadRepeat,
+            _ => return None,
+        })
+    }
+
+    /// Converts this code back into a [`reg_errcode_t`](tre_regex_sys::reg_errcode_t).
+    #[must_use]
+    pub const fn to_raw(self) -> tre::reg_errcode_t {
+        match self {
+            Self::NoMatch => tre::reg_errcode_t::REG_NOMATCH,
+            Self::BadPattern => tre::reg_errcode_t::REG_BADPAT,
+            Self::Collate => tre::reg_errcode_t::REG_ECOLLATE,
+            Self::CharClass => tre::reg_errcode_t::REG_ECTYPE,
+            Self::Escape => tre::reg_errcode_t::REG_EESCAPE,
+            Self::SubReg => tre::reg_errcode_t::REG_ESUBREG,
+            Self::Bracket => tre::reg_errcode_t::REG_EBRACK,
+            Self::Paren => tre::reg_errcode_t::REG_EPAREN,
+            Self::Brace => tre::reg_errcode_t::REG_EBRACE,
+            Self::BadBrace => tre::reg_errcode_t::REG_BADBR,
+            Self::Range => tre::reg_errcode_t::REG_ERANGE,
+            Self::Space => tre::reg_errcode_t::REG_ESPACE,
+            Self::BadRepeat => tre::reg_errcode_t::REG_BADRPT,
+        }
+    }
+
+    /// Gets a short, human-readable description of this error code.
+    #[must_use]
+    pub const fn description(self) -> &'static str {
+        match self {
+            Self::NoMatch => "no match",
+            Self::BadPattern => "invalid pattern",
+            Self::Collate => "unknown collating element",
+            Self::CharClass => "unknown character class",
+            Self::Escape => "trailing backslash",
+            Self::SubReg => "invalid backreference",
+            Self::Bracket => "bad bracket expression",
+            Self::Paren => "unbalanced parentheses",
+            Self::Brace => "unbalanced braces",
+            Self::BadBrace => "invalid repetition count",
+            Self::Range => "invalid character range",
+            Self::Space => "out of memory",
+            Self::BadRepeat => "invalid use of repetition operator",
+        }
+    }
+
+    /// Gets a stable key identifying the category of this error code.
+    ///
+    /// Unlike the error messages from TRE, these keys will not change between versions, so they
+    /// are suitable for use as translation keys.
+    #[must_use]
+    pub const fn category(self) -> &'static str {
+        match self {
+            Self::NoMatch => "no_match",
+            Self::BadPattern => "invalid_pattern",
+            Self::Collate => "invalid_collating_element",
+            Self::CharClass => "invalid_character_class",
+            Self::Escape => "trailing_backslash",
+            Self::SubReg => "invalid_backreference",
+            Self::Bracket => "unbalanced_bracket",
+            Self::Paren => "unbalanced_parenthesis",
+            Self::Brace => "unbalanced_brace",
+            Self::BadBrace => "invalid_repeat_count",
+            Self::Range => "invalid_range",
+            Self::Space => "out_of_memory",
+            Self::BadRepeat => "invalid_repeat",
+        }
+    }
 }
 
 /// Type of error: `Binding` (see [`BindingErrorCode`]), or `Tre`
@@ -35,14 +211,40 @@ pub enum ErrorKind {
     Tre(tre::reg_errcode_t),
 }
 
+/// Stage at which an error occurred: compiling the pattern, or matching with it.
+///
+/// This tells an invalid pattern apart from a failure at match time, such as running out of
+/// memory, which may have the same [`ErrorKind`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ErrorPhase {
+    /// The error occurred while compiling the pattern
+    Compile,
+
+    /// The error occurred while matching
+    Exec,
+}
+
 /// Error type returned in results
-#[derive(Debug, PartialEq, Eq)]
+///
+/// Besides its public fields, an error may carry the pattern and the underlying error it relates
+/// to, so it cannot be built with a struct literal; use [`RegexError::new`] instead.
+#[derive(Debug)]
+#[non_exhaustive]
 pub struct RegexError {
     /// Kind of error
     pub kind: ErrorKind,
 
     /// Error string
     pub error: String,
+
+    /// Pattern the error relates to, if known
+    pattern: Option<String>,
+
+    /// Stage the error occurred at, if known
+    phase: Option<ErrorPhase>,
+
+    /// Underlying error this error was caused by, if any
+    source: Option<Box<dyn Error + Send + Sync>>,
 }
 
 impl RegexError {
@@ -52,17 +254,227 @@ impl RegexError {
         Self {
             kind,
             error: error.to_string(),
+            pattern: None,
+            phase: None,
+            source: None,
+        }
+    }
+
+    /// Attaches the pattern this error relates to, so the error describes itself in logs.
+    ///
+    /// The pattern is included in the [`Display`](fmt::Display) output, truncated if it is
+    /// long. [`Regex::new`] and the other compiling functions attach the pattern automatically
+    /// when compilation fails.
+    ///
+    /// # Arguments
+    /// * `pattern`: the pattern to attach.
+    ///
+    /// # Returns
+    /// The error, with the pattern attached.
+    ///
+    /// # Examples
+    /// ```
+    /// use tre_regex::{RegcompFlags, Regex};
+    ///
+    /// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    /// let err = Regex::new("(a", regcomp_flags).unwrap_err();
+    ///
+    /// assert_eq!(err.pattern(), Some("(a"));
+    /// println!("{err}");
+    /// ```
+    #[must_use]
+    pub fn with_pattern(self, pattern: &str) -> Self {
+        Self {
+            pattern: Some(pattern.to_string()),
+            ..self
+        }
+    }
+
+    /// Gets the pattern this error relates to, if one was attached with
+    /// [`RegexError::with_pattern`].
+    #[must_use]
+    #[inline]
+    pub fn pattern(&self) -> Option<&str> {
+        self.pattern.as_deref()
+    }
+
+    /// Sets the stage this error occurred at.
+    ///
+    /// The compiling and matching functions of this crate set this automatically for errors
+    /// reported by TRE, and for patterns rejected by [`Regex::new_restricted`].
+    ///
+    /// # Arguments
+    /// * `phase`: the stage the error occurred at.
+    ///
+    /// # Returns
+    /// The error, with the phase set.
+    #[must_use]
+    pub fn with_phase(self, phase: ErrorPhase) -> Self {
+        Self {
+            phase: Some(phase),
+            ..self
+        }
+    }
+
+    /// Gets the stage this error occurred at, if known.
+    ///
+    /// # Returns
+    /// `None` for errors which are not tied to compiling or matching, such as a vacant
+    /// [`Regex`], or a match which is not valid UTF-8.
+    ///
+    /// # Examples
+    /// ```
+    /// use tre_regex::{ErrorPhase, RegcompFlags, Regex};
+    ///
+    /// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    /// let err = Regex::new("(a", regcomp_flags).unwrap_err();
+    ///
+    /// assert_eq!(err.phase(), Some(ErrorPhase::Compile));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn phase(&self) -> Option<ErrorPhase> {
+        self.phase
+    }
+
+    /// Attaches the underlying error this error was caused by, to be returned by
+    /// [`Error::source`].
+    ///
+    /// Errors from decoding text, such as a match which is not valid UTF-8, have their
+    /// underlying error attached automatically.
+    ///
+    /// # Arguments
+    /// * `source`: the underlying error.
+    ///
+    /// # Returns
+    /// The error, with the source attached.
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use std::error::Error;
+    /// use tre_regex::{RegcompFlags, RegexecFlags, Regex};
+    ///
+    /// let regcomp_flags = RegcompFlags::new()
+    ///     .add(RegcompFlags::EXTENDED)
+    ///     .add(RegcompFlags::USEBYTES);
+    /// let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    ///
+    /// // Only the first byte of "é" is matched
+    /// let compiled_reg = Regex::new("^.", regcomp_flags)?;
+    /// let matches = compiled_reg.regexec("é", 1, regexec_flags)?;
+    /// let err = matches[0].as_ref().unwrap().as_ref().unwrap_err();
+    ///
+    /// assert!(err.source().unwrap().is::<std::str::Utf8Error>());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn with_source<E: Error + Send + Sync + 'static>(self, source: E) -> Self {
+        Self {
+            source: Some(Box::new(source)),
+            ..self
         }
     }
 }
 
-impl std::error::Error for RegexError {}
+impl PartialEq for RegexError {
+    /// Compares the kind, message, and phase of two errors; any attached pattern or source is
+    /// ignored.
+    fn eq(&self, other: &Self) -> bool {
+        self.kind == other.kind && self.error == other.error && self.phase == other.phase
+    }
+}
 
-// Quick and dirty display impl
-impl fmt::Display for RegexError {
+impl Eq for RegexError {}
+
+impl RegexError {
+    /// Gets the TRE error code as a [`TreErrorCode`].
+    ///
+    /// # Returns
+    /// `None` if this is a binding error, or the TRE code is unknown.
+    #[must_use]
+    pub const fn tre_code(&self) -> Option<TreErrorCode> {
+        match self.kind {
+            ErrorKind::Tre(code) => TreErrorCode::from_raw(code),
+            ErrorKind::Binding(_) => None,
+        }
+    }
+
+    /// Gets a stable key identifying the category of this error, for use as a translation key.
+    ///
+    /// See [`TreErrorCode::category`] for the possible keys. The raw message is still available
+    /// in [`RegexError::error`].
+    ///
+    /// # Returns
+    /// `None` if this is a binding error, or the TRE code is unknown.
+    ///
+    /// # Examples
+    /// ```
+    /// use tre_regex::{RegcompFlags, Regex};
+    ///
+    /// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    /// let err = Regex::new("[a", regcomp_flags).unwrap_err();
+    ///
+    /// assert_eq!(err.category(), Some("unbalanced_bracket"));
+    /// ```
+    #[must_use]
+    pub fn category(&self) -> Option<&'static str> {
+        self.tre_code().map(TreErrorCode::category)
+    }
+}
+
+impl Error for RegexError {
+    /// Gets the underlying error attached with [`RegexError::with_source`], if any.
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+impl From<std::str::Utf8Error> for RegexError {
+    /// Converts a UTF-8 decoding error into a [`BindingErrorCode::ENCODING`] error.
+    fn from(e: std::str::Utf8Error) -> Self {
+        Self::new(
+            ErrorKind::Binding(BindingErrorCode::ENCODING),
+            &format!("UTF-8 encoding error: {e}"),
+        )
+        .with_source(e)
+    }
+}
+
+impl From<std::string::FromUtf8Error> for RegexError {
+    /// Converts a UTF-8 decoding error into a [`BindingErrorCode::ENCODING`] error.
     #[inline]
+    fn from(e: std::string::FromUtf8Error) -> Self {
+        e.utf8_error().into()
+    }
+}
+
+impl fmt::Display for RegexError {
+    /// Formats the error message, followed by a short description of the error code; for example,
+    /// `Missing ']' (bad bracket expression)`.
+    ///
+    /// If a pattern is attached, it follows in quotes, truncated to its first 32 characters; for
+    /// example, `Missing ']' (bad bracket expression) in pattern "[a"`.
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{} (code {:?})", self.error, self.kind)
+        const MAX_PATTERN_CHARS: usize = 32;
+
+        match self.kind {
+            ErrorKind::Binding(code) => write!(f, "{} ({})", self.error, code.description())?,
+            ErrorKind::Tre(code) => match TreErrorCode::from_raw(code) {
+                Some(code) => write!(f, "{} ({})", self.error, code.description())?,
+                None => write!(f, "{} (TRE error {})", self.error, code.0)?,
+            },
+        }
+
+        let Some(pattern) = &self.pattern else { return Ok(()); };
+        match pattern.char_indices().nth(MAX_PATTERN_CHARS) {
+            Some((end, _)) => write!(f, " in pattern {:?}...", &pattern[..end]),
+            None => write!(f, " in pattern {pattern:?}"),
+        }
     }
 }
 
@@ -100,6 +512,7 @@ impl Regex {
                 ErrorKind::Binding(BindingErrorCode::CSTRING),
                 &format!("Could not convert error buffer to C string: {e}"),
             )
+            .with_source(e)
         });
         let Ok(errstr) = errstr else { return errstr.unwrap_err(); };
         let errstr = errstr.to_str().map_err(|e| {
@@ -107,6 +520,7 @@ impl Regex {
                 ErrorKind::Binding(BindingErrorCode::ENCODING),
                 &format!("Could not encode error string to UTF-8: {e}"),
             )
+            .with_source(e)
         });
         let Ok(errstr) = errstr else { return errstr.unwrap_err(); };
 
@@ -114,6 +528,87 @@ impl Regex {
         #[allow(clippy::cast_sign_loss)]
         RegexError::new(ErrorKind::Tre(tre::reg_errcode_t(result as c_uint)), errstr)
     }
+
+    /// Builds a [`RegexError`] for a failed match, as [`Regex::regerror`] does, with its phase set
+    /// to [`ErrorPhase::Exec`].
+    pub(crate) fn exec_error(&self, result: ErrorInt) -> RegexError {
+        self.regerror(result).with_phase(ErrorPhase::Exec)
+    }
+
+    /// Writes the error message for the [`ErrorInt`] code into `buf`, reusing its allocation.
+    ///
+    /// `buf` is cleared first. This is like [`Regex::regerror`], but avoids allocating a new
+    /// buffer for every call once `buf` has grown large enough; this helps when reporting errors
+    /// in bulk.
+    ///
+    /// # Arguments
+    /// * `result`: the TRE result code, see [`reg_errcode_t`](tre_regex_sys::reg_errcode_t).
+    /// * `buf`: [`String`] to write the error message into.
+    ///
+    /// # Errors
+    /// Will return a [`RegexError`] if this object is vacant, or if the error message is not valid
+    /// UTF-8. In either case, `buf` is left empty.
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use tre_regex::{tre, ErrorInt, RegcompFlags, Regex};
+    ///
+    /// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    /// let compiled_reg = Regex::new("a", regcomp_flags)?;
+    ///
+    /// let mut buf = String::with_capacity(64);
+    /// let code = ErrorInt::try_from(tre::reg_errcode_t::REG_EBRACK.0).unwrap();
+    /// compiled_reg.regerror_into(code, &mut buf)?;
+    /// assert_eq!(buf, "Missing ']'");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn regerror_into(&self, result: ErrorInt, buf: &mut String) -> Result<()> {
+        buf.clear();
+        let Some(compiled_reg_obj) = self.get() else {
+            return Err(RegexError::new(
+                ErrorKind::Binding(BindingErrorCode::REGEX_VACANT),
+                "Attempted to unwrap a vacant Regex object"
+            ));
+        };
+
+        // SAFETY: compiled_reg should be valid; see safety concerns for Regex.
+        let bufsize = unsafe { tre::tre_regerror(result, compiled_reg_obj, null_mut(), 0) };
+        let mut errbuf = mem::take(buf).into_bytes();
+        errbuf.resize(bufsize, 0);
+        // SAFETY: compiled_reg should be valid; errbuf has enough room as validated above
+        unsafe {
+            tre::tre_regerror(
+                result,
+                compiled_reg_obj,
+                errbuf.as_mut_ptr().cast::<c_char>(),
+                bufsize,
+            );
+        }
+
+        // Strip the trailing NUL
+        let len = errbuf.iter().position(|&c| c == 0).unwrap_or(errbuf.len());
+        errbuf.truncate(len);
+        match String::from_utf8(errbuf) {
+            Ok(errstr) => {
+                *buf = errstr;
+                Ok(())
+            }
+            Err(e) => {
+                let error = RegexError::new(
+                    ErrorKind::Binding(BindingErrorCode::ENCODING),
+                    &format!("Could not encode error string to UTF-8: {}", e.utf8_error()),
+                )
+                .with_source(e.utf8_error());
+                let mut errbuf = e.into_bytes();
+                errbuf.clear();
+                *buf = String::from_utf8(errbuf).unwrap_or_default();
+                Err(error)
+            }
+        }
+    }
 }
 
 /// Given a [`Regex`] struct and [`ErrorInt`] code, build a [`RegexError`].
@@ -147,3 +642,21 @@ impl Regex {
 pub fn regerror(compiled_reg: &Regex, result: ErrorInt) -> RegexError {
     compiled_reg.regerror(result)
 }
+
+/// Writes the error message for a [`Regex`] struct and [`ErrorInt`] code into `buf`, reusing its
+/// allocation.
+///
+/// This is a thin wrapper around [`Regex::regerror_into`].
+///
+/// # Arguments
+/// * `compiled_reg`: the compiled `Regex` that triggered the error.
+/// * `result`: the TRE result code, see [`reg_errcode_t`](tre_regex_sys::reg_errcode_t).
+/// * `buf`: [`String`] to write the error message into.
+///
+/// # Errors
+/// Will return a [`RegexError`] if `compiled_reg` is vacant, or if the error message is not valid
+/// UTF-8. In either case, `buf` is left empty.
+#[inline]
+pub fn regerror_into(compiled_reg: &Regex, result: ErrorInt, buf: &mut String) -> Result<()> {
+    compiled_reg.regerror_into(result, buf)
+}
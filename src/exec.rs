@@ -1,10 +1,90 @@
 use std::borrow::Cow;
+use std::ffi::{c_char, c_uint};
 use std::hint::unreachable_unchecked;
+use std::ops::Range;
+use std::ptr::null_mut;
 
-use crate::{err::*, flags::*, tre, Regex};
+use crate::{
+    err::*,
+    flags::{RegcompFlags, RegexecFlags},
+    tre, Regex,
+};
 
 pub type RegMatchStr<'a> = Vec<Option<Result<Cow<'a, str>>>>;
 pub type RegMatchBytes<'a> = Vec<Option<Cow<'a, [u8]>>>;
+pub type RegMatchBytesChecked<'a> = Vec<Option<Result<Cow<'a, [u8]>>>>;
+pub type RegMatchMixed<'a> = Vec<Option<MatchText<'a>>>;
+
+/// Text of a match returned by [`regexec_mixed`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MatchText<'a> {
+    /// The match is valid UTF-8
+    Str(&'a str),
+
+    /// The match is not valid UTF-8
+    Bytes(&'a [u8]),
+}
+
+impl<'a> MatchText<'a> {
+    /// Gets the raw bytes of the match, whether or not it is valid UTF-8.
+    #[must_use]
+    #[inline]
+    pub const fn as_bytes(&self) -> &'a [u8] {
+        match *self {
+            Self::Str(s) => s.as_bytes(),
+            Self::Bytes(b) => b,
+        }
+    }
+
+    /// Gets the match as a string, or `None` if it is not valid UTF-8.
+    #[must_use]
+    #[inline]
+    pub const fn as_str(&self) -> Option<&'a str> {
+        match *self {
+            Self::Str(s) => Some(s),
+            Self::Bytes(_) => None,
+        }
+    }
+}
+
+/// Iterator over the results of a match, decoded on demand, returned by
+/// [`Regex::regexec_lazy`].
+#[derive(Clone, Debug)]
+pub struct LazyMatches<'a> {
+    data: &'a [u8],
+    pmatch: std::vec::IntoIter<tre::regmatch_t>,
+}
+
+impl<'a> LazyMatches<'a> {
+    /// Decodes a single result.
+    fn decode(&self, pmatch: tre::regmatch_t) -> Option<Result<&'a str>> {
+        let (start, end) = match_range(pmatch, 0)?;
+        Some(std::str::from_utf8(&self.data[start..end]).map_err(RegexError::from))
+    }
+}
+
+impl<'a> Iterator for LazyMatches<'a> {
+    type Item = Option<Result<&'a str>>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let pmatch = self.pmatch.next()?;
+        Some(self.decode(pmatch))
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let pmatch = self.pmatch.nth(n)?;
+        Some(self.decode(pmatch))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.pmatch.size_hint()
+    }
+}
+
+impl ExactSizeIterator for LazyMatches<'_> {}
 
 impl Regex {
     /// Performs a regex search on the passed string, returning `nmatches` results.
@@ -23,6 +103,10 @@ impl Regex {
     /// returned, containing either errors or substrings of the matches. Errors may be returned due to
     /// decoding problems, such as split codepoints.
     ///
+    /// A group which did not take part in the match is `None`, but a group which matched the empty
+    /// string is `Some("")`. For example, matching `b` against `(a)?b` gives `None` for group 1,
+    /// whereas matching it against `(a*)b` gives `Some("")`.
+    ///
     /// # Errors
     /// If an error is encountered during matching, it returns a [`RegexError`]. Match results may also
     /// return errors, if decoding into UTF-8 was unsuccessful for whatever reason.
@@ -77,13 +161,9 @@ impl Regex {
             let Some(pmatch) = pmatch else { result.push(None); continue; };
 
             result.push(Some(match pmatch {
-                Cow::Borrowed(pmatch) => match std::str::from_utf8(pmatch) {
-                    Ok(s) => Ok(s.into()),
-                    Err(e) => Err(RegexError::new(
-                        ErrorKind::Binding(BindingErrorCode::ENCODING),
-                        &format!("UTF-8 encoding error: {e}"),
-                    )),
-                },
+                Cow::Borrowed(pmatch) => std::str::from_utf8(pmatch)
+                    .map(Cow::Borrowed)
+                    .map_err(RegexError::from),
                 // SAFETY: cannot get here, we only have borrowed values.
                 _ => unsafe { unreachable_unchecked() },
             }));
@@ -106,10 +186,11 @@ impl Regex {
     /// If no error was found, a [`Vec`] of [`Option`]s will be returned.
     ///
     /// If a given match index is empty, The `Option` will be `None`. Otherwise, [`u8`] slices will be
-    /// returned.
+    /// returned. As with [`regexec`], an empty match is an empty slice, not `None`.
     ///
     /// # Errors
-    /// If an error is encountered during matching, it returns a [`RegexError`].
+    /// If an error is encountered during matching, it returns a [`RegexError`]. An error is also
+    /// returned if `data` is too long; see [Input length](crate::Regex#input-length).
     ///
     /// # Caveats
     /// Unless copied, the match results must live at least as long as `data`. This is because they are
@@ -153,6 +234,7 @@ impl Regex {
                 "Attempted to unwrap a vacant Regex object"
             ));
         };
+        check_len(data.len())?;
         let mut match_vec: Vec<tre::regmatch_t> =
             vec![tre::regmatch_t { rm_so: 0, rm_eo: 0 }; nmatches];
 
@@ -170,7 +252,7 @@ impl Regex {
             )
         };
         if result != 0 {
-            return Err(self.regerror(result));
+            return Err(self.exec_error(result));
         }
 
         let mut result: Vec<Option<Cow<'a, [u8]>>> = Vec::with_capacity(nmatches);
@@ -191,6 +273,749 @@ impl Regex {
 
         Ok(result)
     }
+
+    /// Performs a regex search on the passed bytes, returning `nmatches` results, each checked
+    /// against the bounds of `data`.
+    ///
+    /// This is the same as [`regexec_bytes`], but the results have the same shape as those of
+    /// [`regexec`], so that code handling the results can be generic over both.
+    ///
+    /// # Arguments
+    /// * `data`: [`u8`] slice to match against `compiled_reg`
+    /// * `nmatches`: number of matches to return
+    /// * `flags`: [`RegexecFlags`] to pass to [`tre_regnexec`](tre_regex_sys::tre_regnexec).
+    ///
+    /// # Returns
+    /// If no error was found, a [`Vec`] of [`Option`]s will be returned.
+    ///
+    /// If a given match index is empty, The `Option` will be `None`. Otherwise, [`Result`]s will be
+    /// returned, containing either errors or [`u8`] slices of the matches. A
+    /// [`BindingErrorCode::OUT_OF_BOUNDS`] error is returned if the offsets reported by TRE do not
+    /// form a valid range within `data`.
+    ///
+    /// # Errors
+    /// If an error is encountered during matching, it returns a [`RegexError`]. An error is also
+    /// returned if `data` is too long; see [Input length](crate::Regex#input-length).
+    ///
+    /// # Caveats
+    /// Unless copied, the match results must live at least as long as `data`. This is because they are
+    /// slices into `data` under the hood, for efficiency.
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use tre_regex::{RegcompFlags, RegexecFlags, Regex};
+    ///
+    /// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    /// let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    ///
+    /// let compiled_reg = Regex::new("^(hello)(x)?", regcomp_flags)?;
+    /// let matches = compiled_reg.regexec_bytes_checked(b"hello world", 3, regexec_flags)?;
+    ///
+    /// assert_eq!(&*matches[1].as_ref().unwrap().as_ref().unwrap()[..], b"hello");
+    /// assert!(matches[2].is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`BindingErrorCode::OUT_OF_BOUNDS`]: crate::BindingErrorCode::OUT_OF_BOUNDS
+    /// [`regexec`]: crate::Regex::regexec
+    /// [`regexec_bytes`]: crate::Regex::regexec_bytes
+    /// [`RegexError`]: crate::RegexError
+    pub fn regexec_bytes_checked<'a>(
+        &self,
+        data: &'a [u8],
+        nmatches: usize,
+        flags: RegexecFlags,
+    ) -> Result<RegMatchBytesChecked<'a>> {
+        let match_vec = self.regexec_raw(data, nmatches, flags)?;
+
+        let mut result: RegMatchBytesChecked<'a> = Vec::with_capacity(nmatches);
+        for pmatch in match_vec {
+            if pmatch.rm_so < 0 || pmatch.rm_eo < 0 {
+                result.push(None);
+                continue;
+            }
+
+            let range = usize::try_from(pmatch.rm_so)
+                .ok()
+                .zip(usize::try_from(pmatch.rm_eo).ok())
+                .filter(|&(so, eo)| so <= eo && eo <= data.len());
+            result.push(Some(range.map_or_else(
+                || {
+                    Err(RegexError::new(
+                        ErrorKind::Binding(BindingErrorCode::OUT_OF_BOUNDS),
+                        &format!(
+                            "Match offsets {}..{} are out of bounds for input of length {}",
+                            pmatch.rm_so,
+                            pmatch.rm_eo,
+                            data.len()
+                        ),
+                    ))
+                },
+                |(so, eo)| Ok(Cow::Borrowed(&data[so..eo])),
+            )));
+        }
+
+        Ok(result)
+    }
+
+    /// Performs a regex search on the passed bytes, returning `nmatches` results as strings where
+    /// possible.
+    ///
+    /// This is useful for data which is mostly, but not necessarily, UTF-8. Each match is checked
+    /// separately, so only the matches which are not valid UTF-8 are returned as bytes.
+    ///
+    /// # Arguments
+    /// * `data`: [`u8`] slice to match against `compiled_reg`
+    /// * `nmatches`: number of matches to return
+    /// * `flags`: [`RegexecFlags`] to pass to [`tre_regnexec`](tre_regex_sys::tre_regnexec).
+    ///
+    /// # Returns
+    /// If no error was found, a [`Vec`] of [`Option`]s will be returned.
+    ///
+    /// If a given match index is empty, The `Option` will be `None`. Otherwise, a [`MatchText`]
+    /// will be returned, containing a [`str`] if the match is valid UTF-8, and a [`u8`] slice if
+    /// not.
+    ///
+    /// # Errors
+    /// If an error is encountered during matching, it returns a [`RegexError`].
+    ///
+    /// # Caveats
+    /// Unless copied, the match results must live at least as long as `data`. This is because they are
+    /// slices into `data` under the hood, for efficiency.
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use tre_regex::{MatchText, RegcompFlags, RegexecFlags, Regex};
+    ///
+    /// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    /// let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    ///
+    /// let compiled_reg = Regex::new("^([a-z]+) (.*)$", regcomp_flags)?;
+    /// let matches = compiled_reg.regexec_mixed(b"name caf\xe9", 3, regexec_flags)?;
+    ///
+    /// assert_eq!(matches[1], Some(MatchText::Str("name")));
+    /// assert_eq!(matches[2], Some(MatchText::Bytes(b"caf\xe9")));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn regexec_mixed<'a>(
+        &self,
+        data: &'a [u8],
+        nmatches: usize,
+        flags: RegexecFlags,
+    ) -> Result<RegMatchMixed<'a>> {
+        let match_results = self.regexec_bytes(data, nmatches, flags)?;
+
+        let mut result: Vec<Option<MatchText<'a>>> = Vec::with_capacity(nmatches);
+        for pmatch in match_results {
+            let Some(pmatch) = pmatch else { result.push(None); continue; };
+
+            result.push(Some(match pmatch {
+                Cow::Borrowed(pmatch) => {
+                    std::str::from_utf8(pmatch).map_or(MatchText::Bytes(pmatch), MatchText::Str)
+                }
+                // SAFETY: cannot get here, we only have borrowed values.
+                Cow::Owned(_) => unsafe { unreachable_unchecked() },
+            }));
+        }
+
+        Ok(result)
+    }
+
+    /// Performs a regex search on the passed string, returning an iterator which decodes the
+    /// `nmatches` results on demand.
+    ///
+    /// This is like [`Regex::regexec`], but each group is only checked to be valid UTF-8 when the
+    /// iterator reaches it. A caller only interested in some groups, such as with
+    /// [`Iterator::nth`], does not pay for decoding the others.
+    ///
+    /// # Arguments
+    /// * `string`: string to match against `compiled_reg`
+    /// * `nmatches`: number of matches to return
+    /// * `flags`: [`RegexecFlags`] to pass to [`tre_regnexec`](tre_regex_sys::tre_regnexec).
+    ///
+    /// # Returns
+    /// If no error was found, a [`LazyMatches`] iterator yielding the same items as the [`Vec`]
+    /// returned by [`Regex::regexec`], but with plain `&str`s.
+    ///
+    /// # Errors
+    /// If an error is encountered during matching, it returns a [`RegexError`]. Items may also be
+    /// errors, if a match does not fall on character boundaries.
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use tre_regex::{RegcompFlags, RegexecFlags, Regex};
+    ///
+    /// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    /// let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    ///
+    /// let compiled_reg = Regex::new("^(a)(b)(c)(d)(e)$", regcomp_flags)?;
+    /// let mut matches = compiled_reg.regexec_lazy("abcde", 6, regexec_flags)?;
+    ///
+    /// // Only group 4 is decoded
+    /// assert_eq!(matches.nth(4).flatten().transpose()?, Some("d"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`RegexError`]: crate::RegexError
+    pub fn regexec_lazy<'a>(
+        &self,
+        string: &'a str,
+        nmatches: usize,
+        flags: RegexecFlags,
+    ) -> Result<LazyMatches<'a>> {
+        let mut match_vec = vec![tre::regmatch_t { rm_so: 0, rm_eo: 0 }; nmatches];
+        if !self.exec_at(string.as_bytes(), 0, &mut match_vec, flags)? {
+            // Value cannot ever wrap around.
+            #[allow(clippy::cast_possible_wrap)]
+            return Err(self.exec_error(tre::reg_errcode_t::REG_NOMATCH.0 as ErrorInt));
+        }
+
+        Ok(LazyMatches {
+            data: string.as_bytes(),
+            pmatch: match_vec.into_iter(),
+        })
+    }
+
+    /// Performs a regex search on the passed bytes, returning the raw match offsets from TRE.
+    ///
+    /// This is the lowest-level search function, for when the slicing done by
+    /// [`regexec_bytes`] is not wanted, for example to build a custom result type. The offsets
+    /// are returned exactly as TRE wrote them.
+    ///
+    /// # Arguments
+    /// * `data`: [`u8`] slice to match against `compiled_reg`
+    /// * `nmatches`: number of matches to return
+    /// * `flags`: [`RegexecFlags`] to pass to [`tre_regnexec`](tre_regex_sys::tre_regnexec).
+    ///
+    /// # Returns
+    /// If no error was found, a [`Vec`] of `nmatches` [`regmatch_t`](tre_regex_sys::regmatch_t)s
+    /// will be returned. Offsets are byte offsets into `data`. A group which did not take part in
+    /// the match has negative offsets.
+    ///
+    /// # Errors
+    /// If an error is encountered during matching, it returns a [`RegexError`]. An error is also
+    /// returned if `data` is too long; see [Input length](crate::Regex#input-length).
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use tre_regex::{RegcompFlags, RegexecFlags, Regex};
+    ///
+    /// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    /// let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    ///
+    /// let compiled_reg = Regex::new("(a)?(b)", regcomp_flags)?;
+    /// let matches = compiled_reg.regexec_raw(b"xb", 3, regexec_flags)?;
+    ///
+    /// assert_eq!((matches[0].rm_so, matches[0].rm_eo), (1, 2));
+    /// assert!(matches[1].rm_so < 0);
+    /// assert_eq!((matches[2].rm_so, matches[2].rm_eo), (1, 2));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`RegexError`]: crate::RegexError
+    pub fn regexec_raw(
+        &self,
+        data: &[u8],
+        nmatches: usize,
+        flags: RegexecFlags,
+    ) -> Result<Vec<tre::regmatch_t>> {
+        let mut match_vec = vec![tre::regmatch_t { rm_so: 0, rm_eo: 0 }; nmatches];
+        if !self.exec_at(data, 0, &mut match_vec, flags)? {
+            // Value cannot ever wrap around.
+            #[allow(clippy::cast_possible_wrap)]
+            return Err(self.exec_error(tre::reg_errcode_t::REG_NOMATCH.0 as ErrorInt));
+        }
+
+        Ok(match_vec)
+    }
+
+    /// Performs a regex search on the window `start..end` of the passed bytes, returning
+    /// `nmatches` results as offsets into the whole of `data`.
+    ///
+    /// This provides what the BSD `REG_STARTEND` flag does in other regex libraries. TRE has no
+    /// such flag, so the window is searched as its own subslice, without copying it. As with
+    /// `REG_STARTEND`, the window is treated as the whole input: `^` matches at `start` and `$`
+    /// at `end`, unless [`RegexecFlags::NOTBOL`] or [`RegexecFlags::NOTEOL`] are passed. To take
+    /// the text before `start` into account instead, see [`Regex::find_stream`].
+    ///
+    /// # Arguments
+    /// * `data`: [`u8`] slice holding the window
+    /// * `start`: byte offset in `data` of the start of the window
+    /// * `end`: byte offset in `data` of the end of the window
+    /// * `nmatches`: number of matches to return
+    /// * `flags`: [`RegexecFlags`] to pass to [`tre_regnexec`](tre_regex_sys::tre_regnexec).
+    ///
+    /// # Returns
+    /// If no error was found, a [`Vec`] of [`Option`]s will be returned.
+    ///
+    /// If a given match index is empty, The `Option` will be `None`. Otherwise, the range of the
+    /// match in `data` will be returned.
+    ///
+    /// # Errors
+    /// If an error is encountered during matching, it returns a [`RegexError`]. An error is also
+    /// returned if `start..end` is not a valid range in `data`, or if the window is too long; see
+    /// [Input length](crate::Regex#input-length).
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use tre_regex::{RegcompFlags, RegexecFlags, Regex};
+    ///
+    /// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    /// let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    ///
+    /// let compiled_reg = Regex::new("^[a-z]+([0-9])$", regcomp_flags)?;
+    /// let data = b"12\0abc3\0xyz";
+    /// let matches = compiled_reg.regexec_startend(data, 3, 7, 2, regexec_flags)?;
+    ///
+    /// assert_eq!(matches, vec![Some(3..7), Some(6..7)]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`RegexError`]: crate::RegexError
+    pub fn regexec_startend(
+        &self,
+        data: &[u8],
+        start: usize,
+        end: usize,
+        nmatches: usize,
+        flags: RegexecFlags,
+    ) -> Result<Vec<Option<Range<usize>>>> {
+        if start > end || end > data.len() {
+            return Err(RegexError::new(
+                ErrorKind::Binding(BindingErrorCode::OUT_OF_BOUNDS),
+                &format!(
+                    "Window {start}..{end} is out of bounds for input of length {}",
+                    data.len()
+                ),
+            ));
+        }
+
+        let mut match_vec = vec![tre::regmatch_t { rm_so: 0, rm_eo: 0 }; nmatches];
+        if !self.exec_at(&data[..end], start, &mut match_vec, flags)? {
+            // Value cannot ever wrap around.
+            #[allow(clippy::cast_possible_wrap)]
+            return Err(self.exec_error(tre::reg_errcode_t::REG_NOMATCH.0 as ErrorInt));
+        }
+
+        Ok(match_vec
+            .into_iter()
+            .map(|pmatch| match_range(pmatch, start).map(|(so, eo)| so..eo))
+            .collect())
+    }
+
+    /// Performs an ASCII case-insensitive regex search on the passed string, returning
+    /// `nmatches` results.
+    ///
+    /// `string` is lowercased (ASCII letters only) before matching, so this should be used with
+    /// a pattern compiled by [`Regex::new_ascii_ci`]. Lowercasing ASCII never changes the length
+    /// of a string, so the results are slices of the original `string`, with its original case.
+    ///
+    /// # Arguments
+    /// * `string`: string to match against `compiled_reg`
+    /// * `nmatches`: number of matches to return
+    /// * `flags`: [`RegexecFlags`] to pass to [`tre_regnexec`](tre_regex_sys::tre_regnexec).
+    ///
+    /// # Returns
+    /// If no error was found, a [`Vec`] of [`Option`]s will be returned, as with
+    /// [`Regex::regexec`].
+    ///
+    /// # Errors
+    /// If an error is encountered during matching, it returns a [`RegexError`]. Match results may also
+    /// return errors, if a match does not fall on UTF-8 character boundaries.
+    ///
+    /// # Caveats
+    /// A lowercased copy of `string` is made for every call.
+    ///
+    /// [`RegexError`]: crate::RegexError
+    pub fn regexec_ascii_ci<'a>(
+        &self,
+        string: &'a str,
+        nmatches: usize,
+        flags: RegexecFlags,
+    ) -> Result<RegMatchStr<'a>> {
+        let lowered = string.to_ascii_lowercase();
+        let mut match_vec = vec![tre::regmatch_t { rm_so: 0, rm_eo: 0 }; nmatches];
+        if !self.exec_at(lowered.as_bytes(), 0, &mut match_vec, flags)? {
+            // Value cannot ever wrap around.
+            #[allow(clippy::cast_possible_wrap)]
+            return Err(self.exec_error(tre::reg_errcode_t::REG_NOMATCH.0 as ErrorInt));
+        }
+
+        Ok(match_vec
+            .into_iter()
+            .map(|pmatch| {
+                let (start, end) = match_range(pmatch, 0)?;
+                Some(string.get(start..end).map(Cow::Borrowed).ok_or_else(|| {
+                    RegexError::new(
+                        ErrorKind::Binding(BindingErrorCode::ENCODING),
+                        &format!(
+                            "UTF-8 encoding error: match {start}..{end} is not on a character boundary"
+                        ),
+                    )
+                }))
+            })
+            .collect())
+    }
+
+    /// Checks whether the pattern matches anywhere in `string`.
+    ///
+    /// No match offsets are requested from TRE, so this does not allocate. This is the cheapest
+    /// way to test a string when the matched text itself is not needed. Unlike [`Regex::regexec`],
+    /// the lack of a match is not an error; only other errors from TRE are returned.
+    ///
+    /// If the pattern contains no special characters and was not compiled with
+    /// [`RegcompFlags::ICASE`], TRE is skipped entirely and a plain substring search is done
+    /// instead. The result is the same either way.
+    ///
+    /// # Arguments
+    /// * `string`: string to match against `compiled_reg`
+    /// * `flags`: [`RegexecFlags`] to pass to [`tre_regnexec`](tre_regex_sys::tre_regnexec).
+    ///
+    /// # Returns
+    /// `true` if the pattern matched, `false` otherwise.
+    ///
+    /// # Errors
+    /// If an error is encountered during matching, it returns a [`RegexError`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use tre_regex::{RegcompFlags, RegexecFlags, Regex};
+    ///
+    /// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    /// let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    ///
+    /// let compiled_reg = Regex::new("^[[:digit:]]+$", regcomp_flags)?;
+    /// assert!(compiled_reg.is_match("12345", regexec_flags)?);
+    /// assert!(!compiled_reg.is_match("12a45", regexec_flags)?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`RegexError`]: crate::RegexError
+    #[inline]
+    pub fn is_match(&self, string: &str, flags: RegexecFlags) -> Result<bool> {
+        if let Some(needle) = self.literal() {
+            check_len(string.len())?;
+            return Ok(string.contains(needle));
+        }
+
+        self.exec_at(string.as_bytes(), 0, &mut [], flags)
+    }
+
+    /// Checks whether the pattern matches anywhere in `data`.
+    ///
+    /// This is the byte counterpart of [`Regex::is_match`], for input which may not be UTF-8. No
+    /// match offsets are requested from TRE, so this does not allocate.
+    ///
+    /// # Arguments
+    /// * `data`: [`u8`] slice to match against `compiled_reg`
+    /// * `flags`: [`RegexecFlags`] to pass to [`tre_regnexec`](tre_regex_sys::tre_regnexec).
+    ///
+    /// # Returns
+    /// `true` if the pattern matched, `false` otherwise.
+    ///
+    /// # Errors
+    /// If an error is encountered during matching, it returns a [`RegexError`]. An error is also
+    /// returned if `data` is too long; see [Input length](crate::Regex#input-length).
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use tre_regex::{RegcompFlags, RegexecFlags, Regex};
+    ///
+    /// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    /// let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    ///
+    /// let compiled_reg = Regex::new("GET /admin", regcomp_flags)?;
+    /// assert!(compiled_reg.is_match_bytes(b"\x00\xffGET /admin HTTP/1.1", regexec_flags)?);
+    /// assert!(!compiled_reg.is_match_bytes(b"\x00\xffGET / HTTP/1.1", regexec_flags)?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`RegexError`]: crate::RegexError
+    #[inline]
+    pub fn is_match_bytes(&self, data: &[u8], flags: RegexecFlags) -> Result<bool> {
+        if let Some(needle) = self.literal() {
+            check_len(data.len())?;
+            return Ok(find_bytes(data, needle.as_bytes()).is_some());
+        }
+
+        self.exec_at(data, 0, &mut [], flags)
+    }
+
+    /// Checks whether the pattern matches the empty string.
+    ///
+    /// This runs the pattern against empty input, as with [`Regex::is_match`] on `""`. Patterns
+    /// such as `a*`, `x?`, or `^$` match the empty string, and so can produce zero-width matches
+    /// anywhere in a longer string; iterators such as [`Regex::find_iter`] have to step past
+    /// those by hand.
+    ///
+    /// # Arguments
+    /// * `flags`: [`RegexecFlags`] to pass to [`tre_regnexec`](tre_regex_sys::tre_regnexec).
+    ///
+    /// # Returns
+    /// `true` if the pattern matched the empty string, `false` otherwise.
+    ///
+    /// # Errors
+    /// If an error is encountered during matching, it returns a [`RegexError`].
+    ///
+    /// # Caveats
+    /// A pattern which does not match the empty string may still produce zero-width matches
+    /// within other text. For example, `\<` does not match `""`, as there is no word there to
+    /// start, but it matches before every word. [`RegexecFlags::NOTBOL`] and
+    /// [`RegexecFlags::NOTEOL`] also affect the result, since they stop `^` and `$` from matching.
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use tre_regex::{RegcompFlags, RegexecFlags, Regex};
+    ///
+    /// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    /// let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    ///
+    /// assert!(Regex::new("a*", regcomp_flags)?.matches_empty(regexec_flags)?);
+    /// assert!(!Regex::new("a+", regcomp_flags)?.matches_empty(regexec_flags)?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`RegexError`]: crate::RegexError
+    #[inline]
+    pub fn matches_empty(&self, flags: RegexecFlags) -> Result<bool> {
+        self.is_match("", flags)
+    }
+
+    /// Checks which of `inputs` the pattern matches.
+    ///
+    /// This calls [`Regex::is_match`] on each input in turn, stopping at the first error.
+    ///
+    /// # Arguments
+    /// * `inputs`: strings to match against `compiled_reg`
+    /// * `flags`: [`RegexecFlags`] to pass to [`tre_regnexec`](tre_regex_sys::tre_regnexec).
+    ///
+    /// # Returns
+    /// A [`Vec`] with one entry per input, `true` where the pattern matched.
+    ///
+    /// # Errors
+    /// If an error is encountered during matching, it returns a [`RegexError`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use tre_regex::{RegcompFlags, RegexecFlags, Regex};
+    ///
+    /// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    /// let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    ///
+    /// let compiled_reg = Regex::new("^beta-", regcomp_flags)?;
+    /// let mask = compiled_reg.match_mask(&["beta-ui", "stable", "beta-api"], regexec_flags)?;
+    ///
+    /// assert_eq!(mask, vec![true, false, true]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`RegexError`]: crate::RegexError
+    pub fn match_mask(&self, inputs: &[&str], flags: RegexecFlags) -> Result<Vec<bool>> {
+        inputs
+            .iter()
+            .map(|input| self.is_match(input, flags))
+            .collect()
+    }
+
+    /// Performs a regex search on `data[start..]`, filling in `pmatch`.
+    ///
+    /// Offsets written to `pmatch` are relative to `start`; use [`match_range`] to convert them
+    /// back into offsets into `data`. No flags are added. If `pmatch` is empty, no offsets are
+    /// requested at all.
+    ///
+    /// # Returns
+    /// `false` if there was no match, `true` otherwise.
+    pub(crate) fn exec_at(
+        &self,
+        data: &[u8],
+        start: usize,
+        pmatch: &mut [tre::regmatch_t],
+        flags: RegexecFlags,
+    ) -> Result<bool> {
+        let Some(compiled_reg_obj) = self.get() else {
+            return Err(RegexError::new(
+                ErrorKind::Binding(BindingErrorCode::REGEX_VACANT),
+                "Attempted to unwrap a vacant Regex object"
+            ));
+        };
+        let data = &data[start..];
+        check_len(data.len())?;
+        let pmatch_ptr = if pmatch.is_empty() {
+            null_mut()
+        } else {
+            pmatch.as_mut_ptr()
+        };
+
+        // SAFETY: compiled_reg is a wrapped type (see safety concerns for Regex). data is read-only.
+        // pmatch has room for pmatch.len() matches. flags also cannot wrap around.
+        let result = unsafe {
+            tre::tre_regnexec(
+                compiled_reg_obj,
+                data.as_ptr().cast::<c_char>(),
+                data.len(),
+                pmatch.len(),
+                pmatch_ptr,
+                flags.get(),
+            )
+        };
+
+        // Value cannot ever be negative.
+        #[allow(clippy::cast_sign_loss)]
+        let errcode = tre::reg_errcode_t(result as c_uint);
+        if errcode == tre::reg_errcode_t::REG_NOMATCH {
+            return Ok(false);
+        } else if result != 0 {
+            return Err(self.exec_error(result));
+        }
+
+        Ok(true)
+    }
+
+    /// Performs a regex search on `data[start..]` as part of a search over all of `data`, filling
+    /// in `pmatch`.
+    ///
+    /// Unlike [`Regex::exec_at`], the character before `start` is taken into account, so that `^`
+    /// (with [`RegcompFlags::NEWLINE`]) and word boundary assertions such as `\<` behave at `start`
+    /// as they would in a search over all of `data`. TRE cannot be told about preceding input, so
+    /// the search is run from the character before `start`, and [`RegexecFlags::NOTBOL`] is added
+    /// unless that is the start of `data`.
+    ///
+    /// If a match starts before `start`, the search is run again on `data[start..]` alone, with
+    /// [`RegexecFlags::NOTBOL`] added unless the previous character ends a line. In that case
+    /// only, word boundary assertions at `start` do not see the previous character.
+    ///
+    /// Offsets written to `pmatch` are relative to `start`, as with [`Regex::exec_at`].
+    ///
+    /// # Returns
+    /// `false` if there was no match, `true` otherwise.
+    pub(crate) fn exec_from(
+        &self,
+        data: &[u8],
+        start: usize,
+        pmatch: &mut [tre::regmatch_t],
+        flags: RegexecFlags,
+    ) -> Result<bool> {
+        if start == 0 {
+            return self.exec_at(data, 0, pmatch, flags);
+        }
+
+        let nosub = self.flags.get() & RegcompFlags::NOSUB != 0;
+        if !pmatch.is_empty() && !nosub {
+            let mut ctx = start - 1;
+            if self.flags.get() & RegcompFlags::USEBYTES == 0 {
+                while ctx > 0 && start - ctx < 4 && (data[ctx] & 0xC0) == 0x80 {
+                    ctx -= 1;
+                }
+            }
+            let ctx_flags = if ctx > 0 {
+                flags.add(RegexecFlags::NOTBOL)
+            } else {
+                flags
+            };
+            if !self.exec_at(data, ctx, pmatch, ctx_flags)? {
+                return Ok(false);
+            }
+
+            // Offsets cannot wrap around, as data is no longer than a regoff_t can index.
+            #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+            let delta = (start - ctx) as tre::regoff_t;
+            if pmatch[0].rm_so >= delta {
+                for m in pmatch.iter_mut().filter(|m| m.rm_so >= 0 && m.rm_eo >= 0) {
+                    m.rm_so -= delta;
+                    m.rm_eo -= delta;
+                }
+                return Ok(true);
+            }
+        }
+
+        let newline = self.flags.get() & RegcompFlags::NEWLINE != 0;
+        let flags = if newline && data[start - 1] == b'\n' {
+            flags.remove(RegexecFlags::NOTBOL)
+        } else {
+            flags.add(RegexecFlags::NOTBOL)
+        };
+        self.exec_at(data, start, pmatch, flags)
+    }
+}
+
+/// Finds the first occurrence of `needle` in `haystack`, returning its offset.
+#[allow(clippy::redundant_pub_crate)]
+pub(crate) fn find_bytes(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    let Some((&first, rest)) = needle.split_first() else {
+        return Some(0);
+    };
+
+    let mut pos = 0;
+    while let Some(i) = haystack[pos..].iter().position(|&c| c == first) {
+        let found = pos + i;
+        if haystack[found + 1..].starts_with(rest) {
+            return Some(found);
+        }
+        pos = found + 1;
+    }
+    None
+}
+
+/// Checks that offsets into an input of `len` units fit in a [`regoff_t`](tre_regex_sys::regoff_t).
+///
+/// Offsets past the maximum would be truncated by TRE, giving wrong match positions.
+#[allow(clippy::redundant_pub_crate)]
+pub(crate) fn check_len(len: usize) -> Result<()> {
+    if tre::regoff_t::try_from(len).is_err() {
+        return Err(RegexError::new(
+            ErrorKind::Binding(BindingErrorCode::INPUT_TOO_LONG),
+            &format!(
+                "Input of length {len} is longer than the maximum of {}",
+                tre::regoff_t::MAX
+            ),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Converts a [`regmatch_t`](tre_regex_sys::regmatch_t) filled in by a search starting at `start`
+/// into absolute offsets, or `None` if the group did not match.
+#[allow(clippy::redundant_pub_crate)]
+pub(crate) const fn match_range(pmatch: tre::regmatch_t, start: usize) -> Option<(usize, usize)> {
+    if pmatch.rm_so < 0 || pmatch.rm_eo < 0 {
+        return None;
+    }
+
+    // Wraparound is impossible.
+    #[allow(clippy::cast_sign_loss)]
+    let start_offset = start + pmatch.rm_so as usize;
+    #[allow(clippy::cast_sign_loss)]
+    let end_offset = start + pmatch.rm_eo as usize;
+
+    Some((start_offset, end_offset))
 }
 
 /// Performs a regex search on the passed string, returning `nmatches` results.
@@ -330,3 +1155,97 @@ pub fn regexec_bytes<'a>(
 ) -> Result<RegMatchBytes<'a>> {
     compiled_reg.regexec_bytes(data, nmatches, flags)
 }
+
+/// Performs a regex search on the passed bytes, returning `nmatches` results as strings where
+/// possible.
+///
+/// This is a thin wrapper around [`Regex::regexec_mixed`].
+///
+/// # Arguments
+/// * `compiled_reg`: the compiled [`Regex`] object.
+/// * `data`: [`u8`] slice to match against `compiled_reg`
+/// * `nmatches`: number of matches to return
+/// * `flags`: [`RegexecFlags`] to pass to [`tre_regnexec`](tre_regex_sys::tre_regnexec).
+///
+/// # Returns
+/// If no error was found, a [`Vec`] of [`Option`]s will be returned.
+///
+/// If a given match index is empty, The `Option` will be `None`. Otherwise, a [`MatchText`] will
+/// be returned, containing a [`str`] if the match is valid UTF-8, and a [`u8`] slice if not.
+///
+/// # Errors
+/// If an error is encountered during matching, it returns a [`RegexError`].
+///
+/// # Caveats
+/// Unless copied, the match results must live at least as long as `data`. This is because they are
+/// slices into `data` under the hood, for efficiency.
+#[inline]
+pub fn regexec_mixed<'a>(
+    compiled_reg: &Regex,
+    data: &'a [u8],
+    nmatches: usize,
+    flags: RegexecFlags,
+) -> Result<RegMatchMixed<'a>> {
+    compiled_reg.regexec_mixed(data, nmatches, flags)
+}
+
+/// Compiles `pattern`, searches `string` with it, and returns `nmatches` owned results.
+///
+/// This is a convenience function for one-off searches, such as in build scripts or small tools.
+/// The results are copied into [`String`]s and the compiled regex is dropped before returning, so
+/// nothing borrows from `string` or a [`Regex`]. When searching more than once with the same
+/// pattern, compile it with [`Regex::new`] and use [`Regex::regexec`] instead.
+///
+/// # Arguments
+/// * `pattern`: regular expression to compile
+/// * `regcomp_flags`: [`RegcompFlags`] to pass to [`tre_regcomp`](tre_regex_sys::tre_regcomp).
+/// * `string`: string to match against `pattern`
+/// * `nmatches`: number of matches to return
+/// * `regexec_flags`: [`RegexecFlags`] to pass to [`tre_regnexec`](tre_regex_sys::tre_regnexec).
+///
+/// # Returns
+/// If no error was found, a [`Vec`] of [`Option`]s will be returned, with `None` for each group
+/// which did not take part in the match.
+///
+/// # Errors
+/// If an error is encountered during compilation or matching, it returns a [`RegexError`]. As with
+/// [`regexec`], an error is returned if there is no match, or if a match is not valid UTF-8.
+///
+/// # Examples
+/// ```
+/// # use tre_regex::Result;
+/// # fn main() -> Result<()> {
+/// use tre_regex::{scan, RegcompFlags, RegexecFlags};
+///
+/// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+/// let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+///
+/// let version = scan(
+///     "^([0-9]+)\\.([0-9]+)",
+///     regcomp_flags,
+///     "1.65.0",
+///     3,
+///     regexec_flags,
+/// )?;
+///
+/// assert_eq!(version[0].as_deref(), Some("1.65"));
+/// assert_eq!(version[2].as_deref(), Some("65"));
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`RegexError`]: crate::RegexError
+pub fn scan(
+    pattern: &str,
+    regcomp_flags: RegcompFlags,
+    string: &str,
+    nmatches: usize,
+    regexec_flags: RegexecFlags,
+) -> Result<Vec<Option<String>>> {
+    let compiled_reg = Regex::new(pattern, regcomp_flags)?;
+    compiled_reg
+        .regexec(string, nmatches, regexec_flags)?
+        .into_iter()
+        .map(|matched| matched.map(|res| res.map(Cow::into_owned)).transpose())
+        .collect()
+}
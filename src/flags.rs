@@ -1,4 +1,5 @@
 use std::ffi::c_int;
+use std::fmt;
 
 use crate::tre;
 
@@ -44,6 +45,20 @@ impl RegcompFlags {
     /// Use raw bytes
     pub const USEBYTES: RegFlags = tre::REG_USEBYTES;
 
+    /// Names of all flags with their own bits, in the order [`RegcompFlags::iter_names`] yields
+    /// them. Zero-valued flags and aliases are left out.
+    #[allow(clippy::redundant_pub_crate)]
+    pub(crate) const NAMES: &'static [(&'static str, RegFlags)] = &[
+        ("EXTENDED", Self::EXTENDED),
+        ("ICASE", Self::ICASE),
+        ("LITERAL", Self::LITERAL),
+        ("NEWLINE", Self::NEWLINE),
+        ("NOSUB", Self::NOSUB),
+        ("RIGHT_ASSOC", Self::RIGHT_ASSOC),
+        ("UNGREEDY", Self::UNGREEDY),
+        ("USEBYTES", Self::USEBYTES),
+    ];
+
     /// Construct a new set of empty flags
     #[must_use]
     pub const fn new() -> Self {
@@ -70,6 +85,76 @@ impl RegcompFlags {
     pub const fn get(&self) -> RegFlags {
         self.0
     }
+
+    /// Checks whether all the bits of `flag` are set.
+    ///
+    /// A flag which is zero (such as [`RegcompFlags::NONE`]) is always contained.
+    ///
+    /// # Examples
+    /// ```
+    /// use tre_regex::RegcompFlags;
+    ///
+    /// let flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    ///
+    /// assert!(flags.contains(RegcompFlags::EXTENDED));
+    /// assert!(!flags.contains(RegcompFlags::ICASE));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn contains(&self, flag: RegFlags) -> bool {
+        self.0 & flag == flag
+    }
+
+    /// Checks whether no flags are set.
+    #[must_use]
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns an iterator over the names of the set flags.
+    ///
+    /// This is the same as [`RegcompFlags::iter_names`].
+    pub fn iter_set(&self) -> impl Iterator<Item = &'static str> {
+        self.iter_names()
+    }
+
+    /// Returns an iterator over the names of the set flags.
+    ///
+    /// Flags which are zero (such as [`RegcompFlags::NONE`]) are never yielded, and neither are set bits
+    /// which do not correspond to a known flag; see [`RegcompFlags::unknown_bits`].
+    ///
+    /// # Examples
+    /// ```
+    /// use tre_regex::RegcompFlags;
+    ///
+    /// let flags = RegcompFlags::new()
+    ///     .add(RegcompFlags::EXTENDED)
+    ///     .add(RegcompFlags::ICASE);
+    /// let names: Vec<_> = flags.iter_names().collect();
+    ///
+    /// assert_eq!(names, vec!["EXTENDED", "ICASE"]);
+    /// ```
+    pub fn iter_names(&self) -> impl Iterator<Item = &'static str> {
+        flag_names(self.0, Self::NAMES)
+    }
+
+    /// Gets the set bits which do not correspond to a known flag.
+    ///
+    /// These are left out by [`Self::iter_names`].
+    #[must_use]
+    #[inline]
+    pub fn unknown_bits(&self) -> RegFlags {
+        unknown_bits(self.0, Self::NAMES)
+    }
+}
+
+impl fmt::Display for RegcompFlags {
+    /// Formats the names of the set flags, separated by `|`, or `NONE` if no flags are set. Any
+    /// unknown bits are formatted last, together, as a hexadecimal number.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_flag_names(f, self.iter_names(), self.unknown_bits())
+    }
 }
 
 /// Flags to pass to [`regexec`](crate::regexec).
@@ -93,6 +178,16 @@ impl RegexecFlags {
     /// Last character of the string is not the end of the line
     pub const NOTEOL: RegFlags = tre::REG_NOTEOL;
 
+    /// Names of all flags with their own bits, in the order [`RegexecFlags::iter_names`] yields
+    /// them.
+    #[allow(clippy::redundant_pub_crate)]
+    pub(crate) const NAMES: &'static [(&'static str, RegFlags)] = &[
+        ("APPROX_MATCHER", Self::APPROX_MATCHER),
+        ("BACKTRACKING_MATCHER", Self::BACKTRACKING_MATCHER),
+        ("NOTBOL", Self::NOTBOL),
+        ("NOTEOL", Self::NOTEOL),
+    ];
+
     /// Construct a new set of empty flags
     #[must_use]
     #[inline]
@@ -120,4 +215,116 @@ impl RegexecFlags {
     pub const fn get(&self) -> RegFlags {
         self.0
     }
+
+    /// Checks whether all the bits of `flag` are set.
+    ///
+    /// A flag which is zero (such as [`RegexecFlags::NONE`]) is always contained.
+    ///
+    /// # Examples
+    /// ```
+    /// use tre_regex::RegexecFlags;
+    ///
+    /// let flags = RegexecFlags::new().add(RegexecFlags::NOTBOL);
+    ///
+    /// assert!(flags.contains(RegexecFlags::NOTBOL));
+    /// assert!(!flags.contains(RegexecFlags::NOTEOL));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn contains(&self, flag: RegFlags) -> bool {
+        self.0 & flag == flag
+    }
+
+    /// Checks whether no flags are set.
+    #[must_use]
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns an iterator over the names of the set flags.
+    ///
+    /// This is the same as [`RegexecFlags::iter_names`].
+    pub fn iter_set(&self) -> impl Iterator<Item = &'static str> {
+        self.iter_names()
+    }
+
+    /// Returns an iterator over the names of the set flags.
+    ///
+    /// Flags which are zero (such as [`RegexecFlags::NONE`]) are never yielded, and neither are set bits
+    /// which do not correspond to a known flag; see [`RegexecFlags::unknown_bits`].
+    ///
+    /// # Examples
+    /// ```
+    /// use tre_regex::RegexecFlags;
+    ///
+    /// let flags = RegexecFlags::new()
+    ///     .add(RegexecFlags::NOTBOL)
+    ///     .add(RegexecFlags::NOTEOL);
+    /// let names: Vec<_> = flags.iter_names().collect();
+    ///
+    /// assert_eq!(names, vec!["NOTBOL", "NOTEOL"]);
+    /// ```
+    pub fn iter_names(&self) -> impl Iterator<Item = &'static str> {
+        flag_names(self.0, Self::NAMES)
+    }
+
+    /// Gets the set bits which do not correspond to a known flag.
+    ///
+    /// These are left out by [`Self::iter_names`].
+    #[must_use]
+    #[inline]
+    pub fn unknown_bits(&self) -> RegFlags {
+        unknown_bits(self.0, Self::NAMES)
+    }
+}
+
+impl fmt::Display for RegexecFlags {
+    /// Formats the names of the set flags, separated by `|`, or `NONE` if no flags are set. Any
+    /// unknown bits are formatted last, together, as a hexadecimal number.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_flag_names(f, self.iter_names(), self.unknown_bits())
+    }
+}
+
+/// Yields the names of the flags in `table` which are set in `bits`.
+fn flag_names(
+    bits: RegFlags,
+    table: &'static [(&'static str, RegFlags)],
+) -> impl Iterator<Item = &'static str> {
+    table
+        .iter()
+        .filter(move |&&(_, flag)| bits & flag == flag)
+        .map(|&(name, _)| name)
+}
+
+/// Gets the bits set in `bits` which are not a flag in `table`.
+fn unknown_bits(bits: RegFlags, table: &[(&str, RegFlags)]) -> RegFlags {
+    table.iter().fold(bits, |acc, &(_, flag)| acc & !flag)
+}
+
+/// Writes flag names separated by `|`, followed by any unknown bits, or `NONE` if there are none.
+fn write_flag_names(
+    f: &mut fmt::Formatter<'_>,
+    names: impl Iterator<Item = &'static str>,
+    unknown: RegFlags,
+) -> fmt::Result {
+    let mut first = true;
+    for name in names {
+        if !first {
+            f.write_str(" | ")?;
+        }
+        f.write_str(name)?;
+        first = false;
+    }
+
+    if unknown != 0 {
+        if !first {
+            f.write_str(" | ")?;
+        }
+        write!(f, "{unknown:#x}")?;
+    } else if first {
+        f.write_str("NONE")?;
+    }
+    Ok(())
 }
@@ -0,0 +1,175 @@
+use std::fmt;
+
+use crate::{err::Result, flags::RegexecFlags, iter::Piece, Regex};
+
+/// Wraps every match of a pattern in a pair of markers, such as ANSI colour codes.
+///
+/// Matches are found as with [`Regex::find_iter`], so they never overlap. Empty matches are left
+/// unmarked. To highlight text as it is written, wrap a [`fmt::Write`] with
+/// [`Highlighter::writer`].
+#[derive(Debug)]
+pub struct Highlighter<'r> {
+    regex: &'r Regex,
+    pre: String,
+    post: String,
+    flags: RegexecFlags,
+}
+
+impl<'r> Highlighter<'r> {
+    /// Creates a new [`Highlighter`].
+    ///
+    /// # Arguments
+    /// * `regex`: the compiled [`Regex`] whose matches should be highlighted.
+    /// * `pre`: marker to insert before each match
+    /// * `post`: marker to insert after each match
+    /// * `flags`: [`RegexecFlags`] to pass to [`tre_regnexec`](tre_regex_sys::tre_regnexec).
+    #[must_use]
+    pub fn new(regex: &'r Regex, pre: &str, post: &str, flags: RegexecFlags) -> Self {
+        Self {
+            regex,
+            pre: pre.to_string(),
+            post: post.to_string(),
+            flags,
+        }
+    }
+
+    /// Returns a copy of `string` with every match wrapped in the markers.
+    ///
+    /// # Arguments
+    /// * `string`: string to highlight
+    ///
+    /// # Returns
+    /// The highlighted string.
+    ///
+    /// # Errors
+    /// If an error is encountered during matching, it returns a [`RegexError`]. An error is also
+    /// returned if a match does not fall on UTF-8 character boundaries.
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use tre_regex::{Highlighter, RegcompFlags, RegexecFlags, Regex};
+    ///
+    /// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    /// let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    ///
+    /// let compiled_reg = Regex::new("ERROR|WARN", regcomp_flags)?;
+    /// let highlighter = Highlighter::new(&compiled_reg, "\x1b[31m", "\x1b[0m", regexec_flags);
+    ///
+    /// assert_eq!(
+    ///     highlighter.highlight("WARN: low disk")?,
+    ///     "\x1b[31mWARN\x1b[0m: low disk"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`RegexError`]: crate::RegexError
+    pub fn highlight(&self, string: &str) -> Result<String> {
+        let mut highlighted = String::with_capacity(string.len());
+        for piece in self.regex.split_at_matches(string, self.flags) {
+            match piece? {
+                Piece::Field(field) => highlighted.push_str(field),
+                Piece::Separator("") => {}
+                Piece::Separator(matched) => {
+                    highlighted.push_str(&self.pre);
+                    highlighted.push_str(matched);
+                    highlighted.push_str(&self.post);
+                }
+            }
+        }
+
+        Ok(highlighted)
+    }
+
+    /// Wraps `inner` in a [`HighlightWriter`], which highlights text written to it.
+    ///
+    /// # Arguments
+    /// * `inner`: writer to pass the highlighted text on to
+    #[must_use]
+    pub const fn writer<W: fmt::Write>(self, inner: W) -> HighlightWriter<'r, W> {
+        HighlightWriter {
+            highlighter: self,
+            inner,
+            buf: String::new(),
+        }
+    }
+}
+
+/// A [`fmt::Write`] adapter highlighting matches in the text written to it, returned by
+/// [`Highlighter::writer`].
+///
+/// Text is buffered until a line break is written, and then highlighted and passed on a line at a
+/// time, so matches split across several [`write_str`](fmt::Write::write_str) calls are still
+/// found. Matches spanning a line break may be split in two. Call [`HighlightWriter::finish`] to
+/// pass on the last line, if it was not terminated.
+///
+/// As [`fmt::Write`] cannot report other errors, a [`RegexError`] encountered while matching is
+/// reported as a [`fmt::Error`].
+///
+/// # Examples
+/// ```
+/// # fn main() -> std::fmt::Result {
+/// use std::fmt::Write;
+/// use tre_regex::{Highlighter, RegcompFlags, RegexecFlags, Regex};
+///
+/// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+/// let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+///
+/// let compiled_reg = Regex::new("ERROR|WARN", regcomp_flags).expect("Regex::new");
+/// let highlighter = Highlighter::new(&compiled_reg, "<", ">", regexec_flags);
+///
+/// let mut writer = highlighter.writer(String::new());
+/// write!(writer, "WA")?;
+/// write!(writer, "RN: low disk\nERR")?;
+/// write!(writer, "OR: disk full")?;
+///
+/// assert_eq!(writer.finish()?, "<WARN>: low disk\n<ERROR>: disk full");
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`RegexError`]: crate::RegexError
+#[derive(Debug)]
+pub struct HighlightWriter<'r, W> {
+    highlighter: Highlighter<'r>,
+    inner: W,
+    buf: String,
+}
+
+impl<W: fmt::Write> HighlightWriter<'_, W> {
+    /// Highlights and passes on any buffered text, returning the inner writer.
+    ///
+    /// # Errors
+    /// Returns a [`fmt::Error`] if the inner writer fails, or if an error is encountered during
+    /// matching.
+    pub fn finish(mut self) -> std::result::Result<W, fmt::Error> {
+        let rest = std::mem::take(&mut self.buf);
+        self.pass_on(&rest)?;
+        Ok(self.inner)
+    }
+
+    /// Gets a reference to the inner writer.
+    pub const fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    fn pass_on(&mut self, text: &str) -> fmt::Result {
+        let highlighted = self.highlighter.highlight(text).map_err(|_| fmt::Error)?;
+        self.inner.write_str(&highlighted)
+    }
+}
+
+impl<W: fmt::Write> fmt::Write for HighlightWriter<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.buf.push_str(s);
+        let Some(i) = self.buf.rfind('\n') else {
+            return Ok(());
+        };
+
+        let rest = self.buf.split_off(i + 1);
+        let lines = std::mem::replace(&mut self.buf, rest);
+        self.pass_on(&lines)
+    }
+}
@@ -0,0 +1,147 @@
+use std::ffi::{c_char, c_int, CStr};
+use std::ptr::{addr_of_mut, null};
+
+use crate::tre;
+
+/// Gets the version string of the TRE library this crate is linked against.
+///
+/// This is the string returned by [`tre_version`](tre_regex_sys::tre_version), such as
+/// `TRE 0.8.0 (BSD)`. It is mostly useful for diagnostics, such as bug reports.
+///
+/// # Returns
+/// The version string, or an empty string if TRE returned something that is not valid UTF-8.
+///
+/// # Examples
+/// ```
+/// use tre_regex::tre_version;
+///
+/// assert!(tre_version().starts_with("TRE"));
+/// ```
+#[must_use]
+pub fn tre_version() -> &'static str {
+    // SAFETY: tre_version returns a pointer to a static, NUL-terminated buffer, which lives for
+    // the rest of the program.
+    let version = unsafe { CStr::from_ptr(tre::tre_version()) };
+    version.to_str().unwrap_or_default()
+}
+
+/// Features the linked TRE library was built with, as reported by
+/// [`tre_config`](tre_regex_sys::tre_config).
+///
+/// The `approx` and `wchar` features of this crate only enable the bindings; whether the
+/// corresponding functionality works depends on how TRE itself was built. This can be checked at
+/// runtime before relying on it.
+///
+/// # Examples
+/// ```
+/// use tre_regex::TreConfig;
+///
+/// let config = TreConfig::query();
+/// if !config.approx() {
+///     eprintln!("approximate matching is not available");
+/// }
+/// assert!(!config.version().is_empty());
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct TreConfig {
+    approx: bool,
+    wchar: bool,
+    multibyte: bool,
+    system_abi: bool,
+    version: &'static str,
+}
+
+impl TreConfig {
+    /// Queries the configuration of the linked TRE library.
+    ///
+    /// Any query TRE does not recognise is reported as the feature being absent.
+    #[must_use]
+    pub fn query() -> Self {
+        Self {
+            approx: query_flag(tre::TRE_CONFIG_APPROX),
+            wchar: query_flag(tre::TRE_CONFIG_WCHAR),
+            multibyte: query_flag(tre::TRE_CONFIG_MULTIBYTE),
+            system_abi: query_flag(tre::TRE_CONFIG_SYSTEM_ABI),
+            version: query_version(),
+        }
+    }
+
+    /// Checks whether TRE was built with approximate matching support.
+    #[must_use]
+    #[inline]
+    pub const fn approx(&self) -> bool {
+        self.approx
+    }
+
+    /// Checks whether TRE was built with wide character support.
+    #[must_use]
+    #[inline]
+    pub const fn wchar(&self) -> bool {
+        self.wchar
+    }
+
+    /// Checks whether TRE was built with multibyte character set support.
+    #[must_use]
+    #[inline]
+    pub const fn multibyte(&self) -> bool {
+        self.multibyte
+    }
+
+    /// Checks whether TRE was built to use the system regex ABI, so that its functions replace
+    /// the system `regcomp` and friends.
+    #[must_use]
+    #[inline]
+    pub const fn system_abi(&self) -> bool {
+        self.system_abi
+    }
+
+    /// Gets the bare version number of TRE, such as `0.8.0`.
+    ///
+    /// See [`tre_version`] for the full version string.
+    #[must_use]
+    #[inline]
+    pub const fn version(&self) -> &'static str {
+        self.version
+    }
+}
+
+/// Converts a `TRE_CONFIG_*` constant to the `int` [`tre_config`](tre_regex_sys::tre_config)
+/// takes.
+///
+/// The type of the constants is generated by bindgen and differs between platforms. A constant
+/// that does not fit becomes an unknown query, which TRE rejects.
+fn query_id<T: TryInto<c_int>>(query: T) -> c_int {
+    query.try_into().unwrap_or(-1)
+}
+
+/// Runs a [`tre_config`](tre_regex_sys::tre_config) query with an integer result.
+#[allow(clippy::redundant_pub_crate)]
+pub(crate) fn query_flag<T: TryInto<c_int>>(query: T) -> bool {
+    let mut result: c_int = 0;
+
+    // SAFETY: integer queries write a single int to result, which is valid for writes.
+    let ret = unsafe { tre::tre_config(query_id(query), addr_of_mut!(result).cast()) };
+    ret == 0 && result != 0
+}
+
+/// Runs the [`tre_config`](tre_regex_sys::tre_config) query for the version number.
+fn query_version() -> &'static str {
+    let mut result: *const c_char = null();
+
+    // SAFETY: the version query writes a pointer to a static, NUL-terminated string to result,
+    // which is valid for writes.
+    let ret = unsafe {
+        tre::tre_config(
+            query_id(tre::TRE_CONFIG_VERSION),
+            addr_of_mut!(result).cast(),
+        )
+    };
+    if ret != 0 || result.is_null() {
+        return "";
+    }
+
+    // SAFETY: see above.
+    let version = unsafe { CStr::from_ptr(result) };
+    version.to_str().unwrap_or_default()
+}
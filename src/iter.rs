@@ -0,0 +1,1191 @@
+use std::ops::{ControlFlow, Range};
+
+use crate::{
+    err::{BindingErrorCode, ErrorKind, RegexError, Result},
+    exec::{check_len, find_bytes, match_range},
+    flags::RegexecFlags,
+    tre, Regex,
+};
+
+impl Regex {
+    /// Finds the first match of the whole pattern in `data`, starting the search at `start`.
+    ///
+    /// The character before `start` is taken into account (see [`Regex::exec_from`]), so that `^`
+    /// and `\<` only match at `start` if they would in a search over all of `data`. Literal
+    /// patterns are searched for without TRE.
+    ///
+    /// # Returns
+    /// `None` if there was no match, otherwise the absolute start and end offsets of the match in
+    /// `data`.
+    pub(crate) fn find_at_bytes(
+        &self,
+        data: &[u8],
+        start: usize,
+        flags: RegexecFlags,
+    ) -> Result<Option<(usize, usize)>> {
+        if let Some(needle) = self.literal() {
+            check_len(data.len() - start)?;
+            let found = find_bytes(&data[start..], needle.as_bytes());
+            return Ok(found.map(|i| (start + i, start + i + needle.len())));
+        }
+
+        let mut pmatch = [tre::regmatch_t { rm_so: 0, rm_eo: 0 }];
+        if !self.exec_from(data, start, &mut pmatch, flags)? {
+            return Ok(None);
+        }
+
+        Ok(match_range(pmatch[0], start))
+    }
+
+    /// Returns an iterator over all successive non-overlapping matches in `string`.
+    ///
+    /// Each item is the absolute byte offsets of the start and end of the match in `string`. After
+    /// each match, the search resumes at the end of it; after an empty match, it resumes one
+    /// character further along, so that the iterator always terminates. An empty match directly
+    /// following the previous match is not reported.
+    ///
+    /// # Arguments
+    /// * `string`: string to match against `compiled_reg`
+    /// * `flags`: [`RegexecFlags`] to pass to [`tre_regnexec`](tre_regex_sys::tre_regnexec).
+    ///
+    /// # Returns
+    /// A [`FindIter`] yielding [`Result`]s of `(start, end)` byte offsets.
+    ///
+    /// # Errors
+    /// If an error is encountered during matching, the iterator yields a [`RegexError`] and then
+    /// stops.
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use tre_regex::{RegcompFlags, RegexecFlags, Regex};
+    ///
+    /// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    /// let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    ///
+    /// let compiled_reg = Regex::new("[[:digit:]]+", regcomp_flags)?;
+    /// for matched in compiled_reg.find_iter("1 22 333", regexec_flags) {
+    ///     let (start, end) = matched?;
+    ///     println!("Match at {start}..{end}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn find_iter<'r, 'h>(
+        &'r self,
+        string: &'h str,
+        flags: RegexecFlags,
+    ) -> FindIter<'r, 'h> {
+        FindIter::new(self, string.as_bytes(), flags, false)
+    }
+
+    /// Returns an iterator over all successive non-overlapping matches in `data`.
+    ///
+    /// This is the byte counterpart of [`Regex::find_iter`], for input which may not be UTF-8.
+    /// Matches are found in the same way, except that after an empty match the search resumes one
+    /// byte further along rather than one character.
+    ///
+    /// As with [`Regex::find_iter`], each search after the first takes the character before it
+    /// into account, so that `^` and word boundary assertions such as `\<` behave as they would in
+    /// a single search over all of `data`.
+    ///
+    /// # Arguments
+    /// * `data`: [`u8`] slice to match against `compiled_reg`
+    /// * `flags`: [`RegexecFlags`] to pass to [`tre_regnexec`](tre_regex_sys::tre_regnexec).
+    ///
+    /// # Returns
+    /// A [`FindIter`] yielding [`Result`]s of `(start, end)` byte offsets.
+    ///
+    /// # Errors
+    /// If an error is encountered during matching, the iterator yields a [`RegexError`] and then
+    /// stops.
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use tre_regex::{RegcompFlags, RegexecFlags, Regex};
+    ///
+    /// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    /// let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    ///
+    /// let compiled_reg = Regex::new("[[:digit:]]+", regcomp_flags)?;
+    /// let matches = compiled_reg
+    ///     .find_iter_bytes(b"1\xff22\xfe333", regexec_flags)
+    ///     .collect::<Result<Vec<_>>>()?;
+    ///
+    /// assert_eq!(matches, vec![(0, 1), (2, 4), (5, 8)]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`RegexError`]: crate::RegexError
+    #[must_use]
+    #[inline]
+    pub const fn find_iter_bytes<'r, 'h>(
+        &'r self,
+        data: &'h [u8],
+        flags: RegexecFlags,
+    ) -> FindIter<'r, 'h> {
+        FindIter::new(self, data, flags, false).bytes()
+    }
+
+    /// Returns an iterator over all matches in `string`, including overlapping ones.
+    ///
+    /// Unlike [`find_iter`], after each match the search resumes one character past the *start*
+    /// of the match rather than at its end. This means a match is reported at every position
+    /// where the pattern matches, so the number of matches will generally differ from
+    /// [`find_iter`]: for example, `aa` matches `aaaa` three times here, but only twice with
+    /// [`find_iter`].
+    ///
+    /// **WARNING**: every character of `string` may be searched from, so this can take
+    /// O(n·m) time, where n is the length of `string` and m is the cost of a single match.
+    ///
+    /// # Arguments
+    /// * `string`: string to match against `compiled_reg`
+    /// * `flags`: [`RegexecFlags`] to pass to [`tre_regnexec`](tre_regex_sys::tre_regnexec).
+    ///
+    /// # Returns
+    /// A [`FindIter`] yielding [`Result`]s of `(start, end)` byte offsets.
+    ///
+    /// # Errors
+    /// If an error is encountered during matching, the iterator yields a [`RegexError`] and then
+    /// stops.
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use tre_regex::{RegcompFlags, RegexecFlags, Regex};
+    ///
+    /// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    /// let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    ///
+    /// let compiled_reg = Regex::new("aa", regcomp_flags)?;
+    /// let matches = compiled_reg
+    ///     .find_iter_overlapping("aaaa", regexec_flags)
+    ///     .collect::<Result<Vec<_>>>()?;
+    ///
+    /// assert_eq!(matches, vec![(0, 2), (1, 3), (2, 4)]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`find_iter`]: crate::Regex::find_iter
+    #[must_use]
+    #[inline]
+    pub const fn find_iter_overlapping<'r, 'h>(
+        &'r self,
+        string: &'h str,
+        flags: RegexecFlags,
+    ) -> FindIter<'r, 'h> {
+        FindIter::new(self, string.as_bytes(), flags, true)
+    }
+
+    /// Returns an iterator over all matches in `string`, including overlapping ones, resuming
+    /// `step` bytes past the start of each match.
+    ///
+    /// This is the same as [`find_iter_overlapping`], except that the search resumes `step` bytes
+    /// past the start of each match rather than one character past it, which is useful for
+    /// sliding-window scanning. The new start is rounded up to the next character boundary. A
+    /// `step` of zero is treated as one.
+    ///
+    /// **WARNING**: as with [`find_iter_overlapping`], this can take O(n·m) time, where n is the
+    /// length of `string` divided by `step` and m is the cost of a single match. A small `step`
+    /// with a pattern which matches long stretches of `string` is quadratic.
+    ///
+    /// # Arguments
+    /// * `string`: string to match against `compiled_reg`
+    /// * `step`: number of bytes to advance past the start of each match
+    /// * `flags`: [`RegexecFlags`] to pass to [`tre_regnexec`](tre_regex_sys::tre_regnexec).
+    ///
+    /// # Returns
+    /// A [`FindIter`] yielding [`Result`]s of `(start, end)` byte offsets.
+    ///
+    /// # Errors
+    /// If an error is encountered during matching, the iterator yields a [`RegexError`] and then
+    /// stops.
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use tre_regex::{RegcompFlags, RegexecFlags, Regex};
+    ///
+    /// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    /// let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    ///
+    /// let compiled_reg = Regex::new("aa", regcomp_flags)?;
+    /// let matches = compiled_reg
+    ///     .find_iter_overlapping_step("aaaaaa", 2, regexec_flags)
+    ///     .collect::<Result<Vec<_>>>()?;
+    ///
+    /// assert_eq!(matches, vec![(0, 2), (2, 4), (4, 6)]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`find_iter_overlapping`]: crate::Regex::find_iter_overlapping
+    /// [`RegexError`]: crate::RegexError
+    #[must_use]
+    #[inline]
+    pub const fn find_iter_overlapping_step<'r, 'h>(
+        &'r self,
+        string: &'h str,
+        step: usize,
+        flags: RegexecFlags,
+    ) -> FindIter<'r, 'h> {
+        FindIter::new(self, string.as_bytes(), flags, true).stride(step)
+    }
+
+    /// Returns an iterator over all matches in `data`, including overlapping ones, resuming
+    /// `step` bytes past the start of each match.
+    ///
+    /// This is the byte counterpart of [`Regex::find_iter_overlapping_step`]. `data` need not be
+    /// UTF-8, and the new start is not rounded to a character boundary.
+    ///
+    /// **WARNING**: see [`Regex::find_iter_overlapping_step`] for the worst-case running time.
+    ///
+    /// # Arguments
+    /// * `data`: [`u8`] slice to match against `compiled_reg`
+    /// * `step`: number of bytes to advance past the start of each match
+    /// * `flags`: [`RegexecFlags`] to pass to [`tre_regnexec`](tre_regex_sys::tre_regnexec).
+    ///
+    /// # Returns
+    /// A [`FindIter`] yielding [`Result`]s of `(start, end)` byte offsets.
+    ///
+    /// # Errors
+    /// If an error is encountered during matching, the iterator yields a [`RegexError`] and then
+    /// stops.
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use tre_regex::{RegcompFlags, RegexecFlags, Regex};
+    ///
+    /// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    /// let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    ///
+    /// let compiled_reg = Regex::new("a.", regcomp_flags.add(RegcompFlags::USEBYTES))?;
+    /// let matches = compiled_reg
+    ///     .find_iter_overlapping_step_bytes(b"aaa\xff", 1, regexec_flags)
+    ///     .collect::<Result<Vec<_>>>()?;
+    ///
+    /// assert_eq!(matches, vec![(0, 2), (1, 3), (2, 4)]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`RegexError`]: crate::RegexError
+    #[must_use]
+    #[inline]
+    pub const fn find_iter_overlapping_step_bytes<'r, 'h>(
+        &'r self,
+        data: &'h [u8],
+        step: usize,
+        flags: RegexecFlags,
+    ) -> FindIter<'r, 'h> {
+        FindIter::new(self, data, flags, true).stride(step).bytes()
+    }
+
+    /// Returns an iterator over all successive non-overlapping, non-empty matches in `string`.
+    ///
+    /// This is like [`find_iter`], except that empty matches are never reported. Where the
+    /// pattern matches the empty string at some position, the search simply moves on by one
+    /// character. This changes which positions are reported, not just how many: for example,
+    /// `x*` matches `axxb` at `0..0`, `1..3`, and `4..4` with [`find_iter`], but only at `1..3`
+    /// here.
+    ///
+    /// # Arguments
+    /// * `string`: string to match against `compiled_reg`
+    /// * `flags`: [`RegexecFlags`] to pass to [`tre_regnexec`](tre_regex_sys::tre_regnexec).
+    ///
+    /// # Returns
+    /// A [`FindIter`] yielding [`Result`]s of `(start, end)` byte offsets.
+    ///
+    /// # Errors
+    /// If an error is encountered during matching, the iterator yields a [`RegexError`] and then
+    /// stops.
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use tre_regex::{RegcompFlags, RegexecFlags, Regex};
+    ///
+    /// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    /// let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    ///
+    /// let compiled_reg = Regex::new("[[:digit:]]*", regcomp_flags)?;
+    /// let matches = compiled_reg
+    ///     .find_iter_nonempty("a12b3", regexec_flags)
+    ///     .collect::<Result<Vec<_>>>()?;
+    ///
+    /// assert_eq!(matches, vec![(1, 3), (4, 5)]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`find_iter`]: crate::Regex::find_iter
+    #[must_use]
+    #[inline]
+    pub const fn find_iter_nonempty<'r, 'h>(
+        &'r self,
+        string: &'h str,
+        flags: RegexecFlags,
+    ) -> FindIter<'r, 'h> {
+        FindIter::new(self, string.as_bytes(), flags, false).nonempty()
+    }
+
+    /// Returns an iterator over at most `max` successive non-overlapping matches in `string`.
+    ///
+    /// This is like [`find_iter`] followed by [`Iterator::take`], except that the iterator stops
+    /// calling into TRE as soon as the limit is reached. This is useful when only the first few
+    /// matches in a large haystack are needed.
+    ///
+    /// # Arguments
+    /// * `string`: string to match against `compiled_reg`
+    /// * `flags`: [`RegexecFlags`] to pass to [`tre_regnexec`](tre_regex_sys::tre_regnexec).
+    /// * `max`: maximum number of matches to return
+    ///
+    /// # Returns
+    /// A [`FindIter`] yielding [`Result`]s of `(start, end)` byte offsets.
+    ///
+    /// # Errors
+    /// If an error is encountered during matching, the iterator yields a [`RegexError`] and then
+    /// stops.
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use tre_regex::{RegcompFlags, RegexecFlags, Regex};
+    ///
+    /// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    /// let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    ///
+    /// let compiled_reg = Regex::new("[[:digit:]]+", regcomp_flags)?;
+    /// let matches = compiled_reg
+    ///     .find_iter_limited("1 22 333", regexec_flags, 2)
+    ///     .collect::<Result<Vec<_>>>()?;
+    ///
+    /// assert_eq!(matches, vec![(0, 1), (2, 4)]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`find_iter`]: crate::Regex::find_iter
+    #[must_use]
+    #[inline]
+    pub const fn find_iter_limited<'r, 'h>(
+        &'r self,
+        string: &'h str,
+        flags: RegexecFlags,
+        max: usize,
+    ) -> FindIter<'r, 'h> {
+        FindIter::new(self, string.as_bytes(), flags, false).limit(max)
+    }
+
+    /// Counts all matches in `string`, including overlapping ones.
+    ///
+    /// See [`Regex::find_iter_overlapping`] for the semantics and performance caveats.
+    ///
+    /// # Arguments
+    /// * `string`: string to match against `compiled_reg`
+    /// * `flags`: [`RegexecFlags`] to pass to [`tre_regnexec`](tre_regex_sys::tre_regnexec).
+    ///
+    /// # Returns
+    /// The number of overlapping matches found.
+    ///
+    /// # Errors
+    /// If an error is encountered during matching, it returns a [`RegexError`].
+    pub fn matches_count_overlapping(&self, string: &str, flags: RegexecFlags) -> Result<usize> {
+        let mut count = 0;
+        for matched in self.find_iter_overlapping(string, flags) {
+            matched?;
+            count += 1;
+        }
+        Ok(count)
+    }
+
+    /// Collects the byte ranges of all successive non-overlapping matches in `string`.
+    ///
+    /// This is [`find_iter`] collected into a [`Vec`] of ranges, which is convenient for
+    /// highlighting every match at once.
+    ///
+    /// # Arguments
+    /// * `string`: string to match against `compiled_reg`
+    /// * `flags`: [`RegexecFlags`] to pass to [`tre_regnexec`](tre_regex_sys::tre_regnexec).
+    ///
+    /// # Returns
+    /// The byte range of every match, in order.
+    ///
+    /// # Errors
+    /// If an error is encountered during matching, it returns a [`RegexError`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use tre_regex::{RegcompFlags, RegexecFlags, Regex};
+    ///
+    /// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    /// let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    ///
+    /// let compiled_reg = Regex::new("[[:digit:]]+", regcomp_flags)?;
+    /// let ranges = compiled_reg.all_match_ranges("a 1 22 333", regexec_flags)?;
+    ///
+    /// assert_eq!(ranges, vec![2..3, 4..6, 7..10]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`find_iter`]: crate::Regex::find_iter
+    pub fn all_match_ranges(&self, string: &str, flags: RegexecFlags) -> Result<Vec<Range<usize>>> {
+        self.find_iter(string, flags)
+            .map(|matched| matched.map(|(start, end)| start..end))
+            .collect()
+    }
+
+    /// Calls `f` with the byte range of each successive non-overlapping match in `string`.
+    ///
+    /// This is a push-based alternative to [`find_iter`], finding the same matches without
+    /// collecting them. Returning [`ControlFlow::Break`] from `f` stops the scan early.
+    ///
+    /// # Arguments
+    /// * `string`: string to match against `compiled_reg`
+    /// * `flags`: [`RegexecFlags`] to pass to [`tre_regnexec`](tre_regex_sys::tre_regnexec).
+    /// * `f`: function to call with the byte range of each match
+    ///
+    /// # Returns
+    /// [`ControlFlow::Break`] if `f` stopped the scan, [`ControlFlow::Continue`] otherwise.
+    ///
+    /// # Errors
+    /// If an error is encountered during matching, it returns a [`RegexError`]. `f` has been called
+    /// for every match before the error.
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use std::ops::ControlFlow;
+    /// use tre_regex::{RegcompFlags, RegexecFlags, Regex};
+    ///
+    /// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    /// let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    ///
+    /// let compiled_reg = Regex::new("[[:digit:]]+", regcomp_flags)?;
+    /// let mut total = 0;
+    /// compiled_reg.for_each_match("a 1 22 333 4444", regexec_flags, |range| {
+    ///     total += range.len();
+    ///     if total > 3 {
+    ///         ControlFlow::Break(())
+    ///     } else {
+    ///         ControlFlow::Continue(())
+    ///     }
+    /// })?;
+    ///
+    /// assert_eq!(total, 6);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`find_iter`]: crate::Regex::find_iter
+    pub fn for_each_match<F>(
+        &self,
+        string: &str,
+        flags: RegexecFlags,
+        mut f: F,
+    ) -> Result<ControlFlow<()>>
+    where
+        F: FnMut(Range<usize>) -> ControlFlow<()>,
+    {
+        for matched in self.find_iter(string, flags) {
+            let (start, end) = matched?;
+            if f(start..end).is_break() {
+                return Ok(ControlFlow::Break(()));
+            }
+        }
+
+        Ok(ControlFlow::Continue(()))
+    }
+
+    /// Scans `string` once, returning whether the pattern matched, how many times, and where it
+    /// first matched.
+    ///
+    /// Matches are counted as with [`find_iter`], so they do not overlap.
+    ///
+    /// # Arguments
+    /// * `string`: string to match against `compiled_reg`
+    /// * `flags`: [`RegexecFlags`] to pass to [`tre_regnexec`](tre_regex_sys::tre_regnexec).
+    ///
+    /// # Returns
+    /// A tuple of whether there was any match, the number of matches, and the byte range of the
+    /// first match.
+    ///
+    /// # Errors
+    /// If an error is encountered during matching, it returns a [`RegexError`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use tre_regex::{RegcompFlags, RegexecFlags, Regex};
+    ///
+    /// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    /// let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    ///
+    /// let compiled_reg = Regex::new("[[:digit:]]+", regcomp_flags)?;
+    /// let (matched, count, first) = compiled_reg.find_summary("a 1 22 333", regexec_flags)?;
+    ///
+    /// assert!(matched);
+    /// assert_eq!(count, 3);
+    /// assert_eq!(first, Some(2..3));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`find_iter`]: crate::Regex::find_iter
+    pub fn find_summary(
+        &self,
+        string: &str,
+        flags: RegexecFlags,
+    ) -> Result<(bool, usize, Option<Range<usize>>)> {
+        let mut count = 0;
+        let mut first = None;
+        for matched in self.find_iter(string, flags) {
+            let (start, end) = matched?;
+            if first.is_none() {
+                first = Some(start..end);
+            }
+            count += 1;
+        }
+
+        Ok((count > 0, count, first))
+    }
+
+    /// Splits `string` into fields and the separators between them.
+    ///
+    /// Separators are the matches of the whole pattern, as found by [`find_iter`]. The pieces
+    /// always start and end with a [`Piece::Field`] and alternate between fields and separators,
+    /// so concatenating them all reconstructs `string` exactly. Consecutive separators and
+    /// separators at either end of the string are delimited by empty fields; empty matches
+    /// produce empty separators.
+    ///
+    /// # Arguments
+    /// * `string`: string to split
+    /// * `flags`: [`RegexecFlags`] to pass to [`tre_regnexec`](tre_regex_sys::tre_regnexec).
+    ///
+    /// # Returns
+    /// A [`SplitAtMatches`] iterator yielding [`Result`]s of [`Piece`]s.
+    ///
+    /// # Errors
+    /// If an error is encountered during matching, the iterator yields a [`RegexError`] and then
+    /// stops. Pieces may also be errors if a match does not fall on UTF-8 character boundaries.
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use tre_regex::{Piece, RegcompFlags, RegexecFlags, Regex};
+    ///
+    /// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    /// let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    ///
+    /// let compiled_reg = Regex::new("[,;]", regcomp_flags)?;
+    /// let pieces = compiled_reg
+    ///     .split_at_matches("a,b;c", regexec_flags)
+    ///     .collect::<Result<Vec<_>>>()?;
+    ///
+    /// assert_eq!(
+    ///     pieces,
+    ///     vec![
+    ///         Piece::Field("a"),
+    ///         Piece::Separator(","),
+    ///         Piece::Field("b"),
+    ///         Piece::Separator(";"),
+    ///         Piece::Field("c"),
+    ///     ]
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`find_iter`]: crate::Regex::find_iter
+    #[must_use]
+    #[inline]
+    pub const fn split_at_matches<'r, 'h>(
+        &'r self,
+        string: &'h str,
+        flags: RegexecFlags,
+    ) -> SplitAtMatches<'r, 'h> {
+        SplitAtMatches {
+            finder: self.find_iter(string, flags),
+            string,
+            last: 0,
+            pending: None,
+            finished: false,
+        }
+    }
+
+    /// Splits `string` into segments, each ending with the match that terminates it.
+    ///
+    /// This is like [`str::split_inclusive`]: each segment runs up to and including a match (as
+    /// found by [`find_iter`]), so concatenating all the segments reconstructs `string` exactly.
+    /// The text after the last match, if any, is the final segment. A trailing empty segment is
+    /// never produced. Empty matches end a segment without adding a delimiter; an empty match
+    /// directly after the previous segment is ignored, so no empty segments are produced at all.
+    ///
+    /// # Arguments
+    /// * `string`: string to split
+    /// * `flags`: [`RegexecFlags`] to pass to [`tre_regnexec`](tre_regex_sys::tre_regnexec).
+    ///
+    /// # Returns
+    /// A [`SplitInclusive`] iterator yielding [`Result`]s of string slices.
+    ///
+    /// # Errors
+    /// If an error is encountered during matching, the iterator yields a [`RegexError`] and then
+    /// stops. Segments may also be errors if a match does not fall on UTF-8 character boundaries.
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use tre_regex::{RegcompFlags, RegexecFlags, Regex};
+    ///
+    /// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    /// let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    ///
+    /// let compiled_reg = Regex::new("\r?\n", regcomp_flags)?;
+    /// let records = compiled_reg
+    ///     .split_inclusive("a\r\nb\nc", regexec_flags)
+    ///     .collect::<Result<Vec<_>>>()?;
+    ///
+    /// assert_eq!(records, vec!["a\r\n", "b\n", "c"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`find_iter`]: crate::Regex::find_iter
+    #[must_use]
+    #[inline]
+    pub const fn split_inclusive<'r, 'h>(
+        &'r self,
+        string: &'h str,
+        flags: RegexecFlags,
+    ) -> SplitInclusive<'r, 'h> {
+        SplitInclusive {
+            finder: self.find_iter(string, flags),
+            string,
+            last: 0,
+            finished: false,
+        }
+    }
+
+    /// Returns an iterator over the parts of `string` not covered by any match.
+    ///
+    /// Matches are found as with [`find_iter`]. The gaps are the text before the first match,
+    /// between each pair of consecutive matches, and after the last match, so there is always one
+    /// more gap than there are matches. Gaps may be empty.
+    ///
+    /// # Arguments
+    /// * `string`: string to match against `compiled_reg`
+    /// * `flags`: [`RegexecFlags`] to pass to [`tre_regnexec`](tre_regex_sys::tre_regnexec).
+    ///
+    /// # Returns
+    /// A [`Gaps`] iterator yielding [`Result`]s of string slices.
+    ///
+    /// # Errors
+    /// If an error is encountered during matching, the iterator yields a [`RegexError`] and then
+    /// stops. Gaps may also be errors if a match does not fall on UTF-8 character boundaries.
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use tre_regex::{RegcompFlags, RegexecFlags, Regex};
+    ///
+    /// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    /// let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    ///
+    /// let compiled_reg = Regex::new("[[:digit:]]+", regcomp_flags)?;
+    /// let gaps = compiled_reg
+    ///     .gaps("a1b22c", regexec_flags)
+    ///     .collect::<Result<Vec<_>>>()?;
+    ///
+    /// assert_eq!(gaps, vec!["a", "b", "c"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`find_iter`]: crate::Regex::find_iter
+    #[must_use]
+    #[inline]
+    pub const fn gaps<'r, 'h>(&'r self, string: &'h str, flags: RegexecFlags) -> Gaps<'r, 'h> {
+        Gaps {
+            ranges: self.gap_ranges(string, flags),
+            string,
+        }
+    }
+
+    /// Splits `string` on the matches of the pattern, returning the text between them.
+    ///
+    /// This is the same as [`Regex::gaps`], under the name used by [`str::split`]. Delimiters at
+    /// either end of `string` produce empty first or last segments, and consecutive delimiters
+    /// produce empty segments between them. Empty matches split the string without removing any
+    /// text, and are found as with [`find_iter`], so they never produce an endless stream of
+    /// empty segments.
+    ///
+    /// # Arguments
+    /// * `string`: string to split
+    /// * `flags`: [`RegexecFlags`] to pass to [`tre_regnexec`](tre_regex_sys::tre_regnexec).
+    ///
+    /// # Returns
+    /// A [`Gaps`] iterator yielding [`Result`]s of string slices.
+    ///
+    /// # Errors
+    /// If an error is encountered during matching, the iterator yields a [`RegexError`] and then
+    /// stops. Segments may also be errors if a match does not fall on UTF-8 character boundaries.
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use tre_regex::{RegcompFlags, RegexecFlags, Regex};
+    ///
+    /// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    /// let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    ///
+    /// let compiled_reg = Regex::new(", *", regcomp_flags)?;
+    /// let fields = compiled_reg
+    ///     .split("a, b,c,", regexec_flags)
+    ///     .collect::<Result<Vec<_>>>()?;
+    ///
+    /// assert_eq!(fields, vec!["a", "b", "c", ""]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`find_iter`]: crate::Regex::find_iter
+    /// [`RegexError`]: crate::RegexError
+    #[must_use]
+    #[inline]
+    pub const fn split<'r, 'h>(&'r self, string: &'h str, flags: RegexecFlags) -> Gaps<'r, 'h> {
+        self.gaps(string, flags)
+    }
+
+    /// Returns an iterator over the byte ranges of `string` not covered by any match.
+    ///
+    /// This is the same as [`Regex::gaps`], but yields `(start, end)` byte offsets rather than
+    /// string slices.
+    ///
+    /// # Arguments
+    /// * `string`: string to match against `compiled_reg`
+    /// * `flags`: [`RegexecFlags`] to pass to [`tre_regnexec`](tre_regex_sys::tre_regnexec).
+    ///
+    /// # Returns
+    /// A [`GapRanges`] iterator yielding [`Result`]s of `(start, end)` byte offsets.
+    ///
+    /// # Errors
+    /// If an error is encountered during matching, the iterator yields a [`RegexError`] and then
+    /// stops.
+    #[must_use]
+    #[inline]
+    pub const fn gap_ranges<'r, 'h>(
+        &'r self,
+        string: &'h str,
+        flags: RegexecFlags,
+    ) -> GapRanges<'r, 'h> {
+        GapRanges {
+            finder: self.find_iter(string, flags),
+            last: 0,
+            len: string.len(),
+            finished: false,
+        }
+    }
+
+    /// Returns an iterator over all successive non-overlapping matches in `string`, along with
+    /// whether each match touches either end of `string`.
+    ///
+    /// Matches are found exactly as with [`find_iter`]. Each one is reported as a
+    /// [`DetailedMatch`], recording whether it starts at the beginning of `string` and whether it
+    /// ends at the end of it. This is useful when `string` is a buffer which may still grow: a
+    /// match touching the end may change once more data arrives.
+    ///
+    /// # Arguments
+    /// * `string`: string to match against `compiled_reg`
+    /// * `flags`: [`RegexecFlags`] to pass to [`tre_regnexec`](tre_regex_sys::tre_regnexec).
+    ///
+    /// # Returns
+    /// A [`FindIterDetailed`] yielding [`Result`]s of [`DetailedMatch`]es.
+    ///
+    /// # Errors
+    /// If an error is encountered during matching, the iterator yields a [`RegexError`] and then
+    /// stops.
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use tre_regex::{RegcompFlags, RegexecFlags, Regex};
+    ///
+    /// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    /// let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    ///
+    /// let compiled_reg = Regex::new("[[:digit:]]+", regcomp_flags)?;
+    /// for matched in compiled_reg.find_iter_detailed("1 22 333", regexec_flags) {
+    ///     let matched = matched?;
+    ///     if matched.at_end {
+    ///         println!("Match at {:?} may be incomplete", matched.range);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`find_iter`]: crate::Regex::find_iter
+    #[must_use]
+    #[inline]
+    pub const fn find_iter_detailed<'r, 'h>(
+        &'r self,
+        string: &'h str,
+        flags: RegexecFlags,
+    ) -> FindIterDetailed<'r, 'h> {
+        FindIterDetailed {
+            finder: self.find_iter(string, flags),
+            len: string.len(),
+        }
+    }
+}
+
+/// Slices `string`, returning an encoding error if the bounds are not on character boundaries.
+fn slice_str(string: &str, start: usize, end: usize) -> Result<&str> {
+    string.get(start..end).ok_or_else(|| {
+        RegexError::new(
+            ErrorKind::Binding(BindingErrorCode::ENCODING),
+            &format!("UTF-8 encoding error: match {start}..{end} is not on a character boundary"),
+        )
+    })
+}
+
+/// Iterator over successive matches, returned by [`Regex::find_iter`] and its variants, such as
+/// [`Regex::find_iter_bytes`] and [`Regex::find_iter_overlapping`].
+#[derive(Debug)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct FindIter<'r, 'h> {
+    regex: &'r Regex,
+    data: &'h [u8],
+    flags: RegexecFlags,
+    overlapping: bool,
+    stride: usize,
+    utf8: bool,
+    nonempty: bool,
+    remaining: usize,
+    pos: usize,
+    last_end: Option<usize>,
+    done: bool,
+}
+
+impl<'r, 'h> FindIter<'r, 'h> {
+    pub(crate) const fn new(
+        regex: &'r Regex,
+        data: &'h [u8],
+        flags: RegexecFlags,
+        overlapping: bool,
+    ) -> Self {
+        Self {
+            regex,
+            data,
+            flags,
+            overlapping,
+            stride: 1,
+            utf8: true,
+            nonempty: false,
+            remaining: usize::MAX,
+            pos: 0,
+            last_end: None,
+            done: false,
+        }
+    }
+
+    /// Skip empty matches.
+    pub(crate) const fn nonempty(self) -> Self {
+        Self {
+            nonempty: true,
+            ..self
+        }
+    }
+
+    /// Stop after `max` matches.
+    pub(crate) const fn limit(self, max: usize) -> Self {
+        Self {
+            remaining: max,
+            ..self
+        }
+    }
+
+    /// After an overlapping match, resume `stride` bytes past its start.
+    pub(crate) const fn stride(self, stride: usize) -> Self {
+        Self {
+            stride: if stride == 0 { 1 } else { stride },
+            ..self
+        }
+    }
+
+    /// Treat the data as raw bytes rather than UTF-8, so that offsets are not rounded up to
+    /// character boundaries.
+    pub(crate) const fn bytes(self) -> Self {
+        Self {
+            utf8: false,
+            ..self
+        }
+    }
+
+    /// Get the offset `n` bytes past `offset`, rounded up to the next character boundary.
+    fn advance(&self, offset: usize, n: usize) -> usize {
+        let mut next = offset.saturating_add(n);
+        while self.utf8 && next < self.data.len() && (self.data[next] & 0xC0) == 0x80 {
+            next += 1;
+        }
+        next
+    }
+
+    /// Get the offset one character past `offset`.
+    fn step(&self, offset: usize) -> usize {
+        self.advance(offset, 1)
+    }
+}
+
+impl Iterator for FindIter<'_, '_> {
+    type Item = Result<(usize, usize)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            self.done = true;
+        }
+
+        while !self.done && self.pos <= self.data.len() {
+            let (start, end) = match self.regex.find_at_bytes(self.data, self.pos, self.flags) {
+                Ok(Some(found)) => found,
+                Ok(None) => break,
+                Err(e) => {
+                    self.done = true;
+                    return Some(Err(e));
+                }
+            };
+
+            if self.nonempty && start == end {
+                self.pos = self.step(end);
+                continue;
+            }
+
+            if self.overlapping {
+                self.pos = self.advance(start, self.stride);
+                self.remaining -= 1;
+                return Some(Ok((start, end)));
+            }
+
+            if start == end {
+                self.pos = self.step(end);
+                if self.last_end == Some(end) {
+                    // Empty match right after the previous match; skip it.
+                    continue;
+                }
+            } else {
+                self.pos = end;
+            }
+
+            self.last_end = Some(end);
+            self.remaining -= 1;
+            return Some(Ok((start, end)));
+        }
+
+        self.done = true;
+        None
+    }
+}
+
+/// A piece of a string split by [`Regex::split_at_matches`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Piece<'h> {
+    /// Text between separators
+    Field(&'h str),
+
+    /// Text matched by the pattern
+    Separator(&'h str),
+}
+
+/// Iterator over fields and separators, returned by [`Regex::split_at_matches`].
+#[derive(Debug)]
+pub struct SplitAtMatches<'r, 'h> {
+    finder: FindIter<'r, 'h>,
+    string: &'h str,
+    last: usize,
+    pending: Option<(usize, usize)>,
+    finished: bool,
+}
+
+impl<'h> Iterator for SplitAtMatches<'_, 'h> {
+    type Item = Result<Piece<'h>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some((start, end)) = self.pending.take() {
+            self.last = end;
+            return Some(slice_str(self.string, start, end).map(Piece::Separator));
+        }
+
+        if self.finished {
+            return None;
+        }
+
+        match self.finder.next() {
+            Some(Ok((start, end))) => {
+                self.pending = Some((start, end));
+                Some(slice_str(self.string, self.last, start).map(Piece::Field))
+            }
+            Some(Err(e)) => {
+                self.finished = true;
+                Some(Err(e))
+            }
+            None => {
+                self.finished = true;
+                Some(slice_str(self.string, self.last, self.string.len()).map(Piece::Field))
+            }
+        }
+    }
+}
+
+/// A match found by [`Regex::find_iter_detailed`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DetailedMatch {
+    /// Byte offsets of the match
+    pub range: Range<usize>,
+
+    /// Whether the match starts at the beginning of the string
+    pub at_start: bool,
+
+    /// Whether the match ends at the end of the string
+    pub at_end: bool,
+}
+
+/// Iterator over successive matches with their details, returned by
+/// [`Regex::find_iter_detailed`].
+#[derive(Debug)]
+pub struct FindIterDetailed<'r, 'h> {
+    finder: FindIter<'r, 'h>,
+    len: usize,
+}
+
+impl Iterator for FindIterDetailed<'_, '_> {
+    type Item = Result<DetailedMatch>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let len = self.len;
+        self.finder.next().map(|matched| {
+            matched.map(|(start, end)| DetailedMatch {
+                range: start..end,
+                at_start: start == 0,
+                at_end: end == len,
+            })
+        })
+    }
+}
+
+/// Iterator over the byte ranges between matches, returned by [`Regex::gap_ranges`].
+#[derive(Debug)]
+pub struct GapRanges<'r, 'h> {
+    finder: FindIter<'r, 'h>,
+    last: usize,
+    len: usize,
+    finished: bool,
+}
+
+impl Iterator for GapRanges<'_, '_> {
+    type Item = Result<(usize, usize)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        match self.finder.next() {
+            Some(Ok((start, end))) => {
+                let gap = (self.last, start);
+                self.last = end;
+                Some(Ok(gap))
+            }
+            Some(Err(e)) => {
+                self.finished = true;
+                Some(Err(e))
+            }
+            None => {
+                self.finished = true;
+                Some(Ok((self.last, self.len)))
+            }
+        }
+    }
+}
+
+/// Iterator over the text between matches, returned by [`Regex::gaps`] and [`Regex::split`].
+#[derive(Debug)]
+pub struct Gaps<'r, 'h> {
+    ranges: GapRanges<'r, 'h>,
+    string: &'h str,
+}
+
+impl<'h> Iterator for Gaps<'_, 'h> {
+    type Item = Result<&'h str>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let string = self.string;
+        self.ranges
+            .next()
+            .map(|gap| gap.and_then(|(start, end)| slice_str(string, start, end)))
+    }
+}
+
+/// Iterator over segments ending with their delimiters, returned by [`Regex::split_inclusive`].
+#[derive(Debug)]
+pub struct SplitInclusive<'r, 'h> {
+    finder: FindIter<'r, 'h>,
+    string: &'h str,
+    last: usize,
+    finished: bool,
+}
+
+impl<'h> Iterator for SplitInclusive<'_, 'h> {
+    type Item = Result<&'h str>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.finished {
+            match self.finder.next() {
+                Some(Ok((_, end))) => {
+                    if end == self.last {
+                        // Empty match at the start of the segment; skip it.
+                        continue;
+                    }
+
+                    let start = self.last;
+                    self.last = end;
+                    return Some(slice_str(self.string, start, end));
+                }
+                Some(Err(e)) => {
+                    self.finished = true;
+                    return Some(Err(e));
+                }
+                None => {
+                    self.finished = true;
+                    if self.last < self.string.len() {
+                        return Some(slice_str(self.string, self.last, self.string.len()));
+                    }
+                }
+            }
+        }
+
+        None
+    }
+}
@@ -0,0 +1,334 @@
+use std::borrow::Cow;
+use std::ffi::{c_int, c_uint, c_void};
+use std::ops::Range;
+use std::ptr::{addr_of_mut, null_mut};
+
+use crate::{
+    err::{BindingErrorCode, ErrorKind, RegexError, Result},
+    exec::{check_len, match_range},
+    flags::RegexecFlags,
+    tre, Regex,
+};
+
+/// A haystack made of several string segments, matched as if they were concatenated.
+///
+/// This is useful for rope-like data, or for many small strings, where building one joined
+/// [`String`] would be wasteful. Matches may span several segments. Offsets into the haystack are
+/// byte offsets into the logical concatenation of the segments.
+///
+/// # Examples
+/// ```
+/// use tre_regex::JoinedHaystack;
+///
+/// let haystack = JoinedHaystack(&["hello ", "", "world"]);
+/// assert_eq!(haystack.len(), 11);
+/// assert_eq!(haystack.locate(8), Some((2, 2)));
+/// assert_eq!(haystack.text(4..8), "o wo");
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct JoinedHaystack<'a>(pub &'a [&'a str]);
+
+impl<'a> JoinedHaystack<'a> {
+    /// Gets the total length of the haystack in bytes.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.iter().map(|segment| segment.len()).sum()
+    }
+
+    /// Checks whether the haystack has no text at all.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.iter().all(|segment| segment.is_empty())
+    }
+
+    /// Converts a byte offset into the haystack to `(segment_index, offset)` coordinates.
+    ///
+    /// An offset at the boundary between two segments is placed at the start of the later
+    /// segment, skipping empty segments. The offset of the end of the haystack is placed at the
+    /// end of the last segment.
+    ///
+    /// # Returns
+    /// `None` if `offset` is past the end of the haystack, or if there are no segments.
+    #[must_use]
+    pub fn locate(&self, offset: usize) -> Option<(usize, usize)> {
+        let mut start = 0;
+        for (index, segment) in self.0.iter().enumerate() {
+            if offset < start + segment.len() {
+                return Some((index, offset - start));
+            }
+            start += segment.len();
+        }
+
+        let last = self.0.len().checked_sub(1)?;
+        (offset == start).then(|| (last, self.0[last].len()))
+    }
+
+    /// Converts a byte offset marking the end of some text to `(segment_index, offset)`
+    /// coordinates.
+    ///
+    /// Unlike [`JoinedHaystack::locate`], an offset at the boundary between two segments is placed
+    /// at the end of the earlier segment, so that it lies in the segment holding the text before
+    /// it.
+    fn locate_end(&self, offset: usize) -> Option<(usize, usize)> {
+        let mut start = 0;
+        for (index, segment) in self.0.iter().enumerate() {
+            if offset > start && offset <= start + segment.len() {
+                return Some((index, offset - start));
+            }
+            start += segment.len();
+        }
+
+        self.locate(offset)
+    }
+
+    /// Gets the text in the given byte range of the haystack.
+    ///
+    /// The text is borrowed if it lies within a single segment, and copied otherwise.
+    ///
+    /// # Panics
+    /// Panics if `range` is out of bounds, or does not fall on character boundaries.
+    #[must_use]
+    pub fn text(&self, range: Range<usize>) -> Cow<'a, str> {
+        let mut pieces = Vec::new();
+        let mut start = 0;
+        for segment in self.0 {
+            let end = start + segment.len();
+            if range.start < end && range.end > start {
+                let from = range.start.saturating_sub(start);
+                let to = range.end.min(end) - start;
+                pieces.push(&segment[from..to]);
+            }
+            start = end;
+        }
+        assert!(
+            range.start <= range.end && range.end <= start,
+            "range out of bounds"
+        );
+
+        match pieces[..] {
+            [] => Cow::Borrowed(""),
+            [piece] => Cow::Borrowed(piece),
+            _ => Cow::Owned(pieces.concat()),
+        }
+    }
+}
+
+/// A match found by [`Regex::regexec_joined`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct JoinedMatch {
+    /// Byte offsets of the match in the logical concatenation of the segments
+    pub range: Range<usize>,
+
+    /// `(segment_index, offset)` of the start of the match
+    pub start: (usize, usize),
+
+    /// `(segment_index, offset)` of the end of the match; this is in the segment holding the last
+    /// character of the match, or the same as `start` for an empty match
+    pub end: (usize, usize),
+}
+
+/// Reading state for [`tre_reguexec`](tre_regex_sys::tre_reguexec).
+struct Cursor<'h> {
+    segments: &'h [&'h str],
+
+    // Offset of the start of each segment
+    starts: Vec<usize>,
+
+    // Offset of the next character to read
+    pos: usize,
+}
+
+impl Cursor<'_> {
+    /// Gets the index of the segment holding the byte at `pos`, if any.
+    fn segment(&self, pos: usize) -> Option<usize> {
+        let index = self
+            .starts
+            .partition_point(|&start| start <= pos)
+            .checked_sub(1)?;
+        (pos < self.starts[index] + self.segments[index].len()).then_some(index)
+    }
+
+    /// Returns an iterator over the bytes of the haystack, starting at `pos`.
+    fn bytes_from(&self, pos: usize) -> impl Iterator<Item = u8> + '_ {
+        let index = self.segment(pos).unwrap_or(self.segments.len());
+        let skip = self.starts.get(index).map_or(0, |start| pos - start);
+        self.segments[index..]
+            .iter()
+            .flat_map(|segment| segment.bytes())
+            .skip(skip)
+    }
+}
+
+/// Reads the next character for TRE, returning nonzero at the end of the haystack.
+unsafe extern "C" fn next_char(
+    c: *mut tre::tre_char_t,
+    pos_add: *mut c_uint,
+    context: *mut c_void,
+) -> c_int {
+    // SAFETY: context points to the Cursor passed to tre_reguexec, which outlives the call. c and
+    // pos_add are valid for writes, as TRE passes pointers to its own locals.
+    let cursor = &mut *context.cast::<Cursor<'_>>();
+    let ch = cursor.segment(cursor.pos).and_then(|index| {
+        cursor.segments[index][cursor.pos - cursor.starts[index]..]
+            .chars()
+            .next()
+    });
+
+    let Some(ch) = ch else {
+        *c = 0;
+        *pos_add = 1;
+        return 1;
+    };
+
+    // tre_char_t is unsigned on some platforms and signed on others; every char fits either way.
+    #[allow(clippy::unnecessary_fallible_conversions)]
+    let wide = tre::tre_char_t::try_from(u32::from(ch)).unwrap_or_default();
+    *c = wide;
+
+    // A char is at most 4 bytes long.
+    #[allow(clippy::cast_possible_truncation)]
+    let len = ch.len_utf8() as c_uint;
+    *pos_add = len;
+    cursor.pos += ch.len_utf8();
+    0
+}
+
+/// Moves the read position for TRE.
+unsafe extern "C" fn rewind(pos: usize, context: *mut c_void) {
+    // SAFETY: see next_char.
+    let cursor = &mut *context.cast::<Cursor<'_>>();
+    cursor.pos = pos;
+}
+
+/// Compares two runs of the haystack for TRE's back references, returning zero if they are equal.
+unsafe extern "C" fn compare(pos1: usize, pos2: usize, len: usize, context: *mut c_void) -> c_int {
+    // SAFETY: see next_char.
+    let cursor = &*context.cast::<Cursor<'_>>();
+    let equal = cursor
+        .bytes_from(pos1)
+        .take(len)
+        .eq(cursor.bytes_from(pos2).take(len));
+    c_int::from(!equal)
+}
+
+impl Regex {
+    /// Performs a regex search over several string segments as if they were concatenated,
+    /// returning `nmatches` results.
+    ///
+    /// The segments are fed to TRE one character at a time through
+    /// [`tre_reguexec`](tre_regex_sys::tre_reguexec), so no joined copy of the haystack is made.
+    /// Matches may span several segments.
+    ///
+    /// # Arguments
+    /// * `haystack`: [`JoinedHaystack`] to match against `compiled_reg`
+    /// * `nmatches`: number of matches to return
+    /// * `flags`: [`RegexecFlags`] to pass to [`tre_reguexec`](tre_regex_sys::tre_reguexec).
+    ///
+    /// # Returns
+    /// If no error was found, a [`Vec`] of [`Option`]s will be returned.
+    ///
+    /// If a given match index is empty, The `Option` will be `None`. Otherwise, a
+    /// [`JoinedMatch`] will be returned, giving the position of the match both as a range in the
+    /// whole haystack and as `(segment_index, offset)` coordinates. Use
+    /// [`JoinedHaystack::text`] to get the matched text.
+    ///
+    /// # Errors
+    /// If an error is encountered during matching, it returns a [`RegexError`]. An error is also
+    /// returned if the haystack is too long; see [Input length](crate::Regex#input-length).
+    ///
+    /// # Caveats
+    /// Reading one character at a time through callbacks is slower than matching a contiguous
+    /// string. If the haystack is small, joining it may well be faster.
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use tre_regex::{JoinedHaystack, RegcompFlags, RegexecFlags, Regex};
+    ///
+    /// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    /// let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    ///
+    /// let compiled_reg = Regex::new("wor(ld)", regcomp_flags)?;
+    /// let haystack = JoinedHaystack(&["hello w", "or", "ld!"]);
+    /// let matches = compiled_reg.regexec_joined(haystack, 2, regexec_flags)?;
+    ///
+    /// let matched = matches[0].as_ref().unwrap();
+    /// assert_eq!(matched.range, 6..11);
+    /// assert_eq!(matched.start, (0, 6));
+    /// assert_eq!(matched.end, (2, 2));
+    /// assert_eq!(haystack.text(matched.range.clone()), "world");
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`RegexError`]: crate::RegexError
+    pub fn regexec_joined(
+        &self,
+        haystack: JoinedHaystack<'_>,
+        nmatches: usize,
+        flags: RegexecFlags,
+    ) -> Result<Vec<Option<JoinedMatch>>> {
+        let Some(compiled_reg_obj) = self.get() else {
+            return Err(RegexError::new(
+                ErrorKind::Binding(BindingErrorCode::REGEX_VACANT),
+                "Attempted to unwrap a vacant Regex object"
+            ));
+        };
+        check_len(haystack.len())?;
+
+        let mut cursor = Cursor {
+            segments: haystack.0,
+            starts: haystack
+                .0
+                .iter()
+                .scan(0, |start, segment| {
+                    let this = *start;
+                    *start += segment.len();
+                    Some(this)
+                })
+                .collect(),
+            pos: 0,
+        };
+        let source = tre::tre_str_source {
+            get_next_char: Some(next_char),
+            rewind: Some(rewind),
+            compare: Some(compare),
+            context: addr_of_mut!(cursor).cast(),
+        };
+        let mut match_vec = vec![tre::regmatch_t { rm_so: 0, rm_eo: 0 }; nmatches];
+        let pmatch_ptr = if match_vec.is_empty() {
+            null_mut()
+        } else {
+            match_vec.as_mut_ptr()
+        };
+
+        // SAFETY: compiled_reg is a wrapped type (see safety concerns for Regex). source and the
+        // cursor it points to live until the call returns. match_vec has enough room for
+        // everything. flags also cannot wrap around.
+        let result = unsafe {
+            tre::tre_reguexec(compiled_reg_obj, &source, nmatches, pmatch_ptr, flags.get())
+        };
+        if result != 0 {
+            return Err(self.exec_error(result));
+        }
+
+        Ok(match_vec
+            .into_iter()
+            .map(|pmatch| {
+                let (so, eo) = match_range(pmatch, 0)?;
+                let start = haystack.locate(so)?;
+                let end = if so == eo {
+                    start
+                } else {
+                    haystack.locate_end(eo)?
+                };
+                Some(JoinedMatch {
+                    range: so..eo,
+                    start,
+                    end,
+                })
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,165 @@
+use crate::{
+    err::{BindingErrorCode, ErrorKind, RegexError, Result},
+    flags::{RegcompFlags, RegexecFlags},
+    Regex,
+};
+
+pub type RegMatchLatin1 = Vec<Option<String>>;
+
+impl Regex {
+    /// Compiles a regex for matching Latin-1 (ISO-8859-1) text.
+    ///
+    /// The pattern is encoded to Latin-1 and compiled with [`RegcompFlags::USEBYTES`], so that TRE
+    /// treats both the pattern and the input as single bytes rather than UTF-8. Use
+    /// [`Regex::regexec_latin1`] to match with it.
+    ///
+    /// # Arguments
+    /// * `reg`: regular expression to compile, as a string. Every character must be in Latin-1,
+    ///   that is, no greater than U+00FF.
+    /// * `flags`: [`RegcompFlags`] to pass to the function.
+    ///
+    /// # Returns
+    /// An opaque [`Regex`] object will be returned. It will be freed automatically when dropped.
+    ///
+    /// # Errors
+    /// Will return a [`RegexError`] upon failure. A [`BindingErrorCode::ENCODING`] error is
+    /// returned if `reg` contains a character outside Latin-1.
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use tre_regex::{RegcompFlags, RegexecFlags, Regex};
+    ///
+    /// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    /// let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    ///
+    /// let compiled_reg = Regex::new_latin1("caf(é|e)", regcomp_flags)?;
+    /// let matches = compiled_reg.regexec_latin1(b"un caf\xe9 noir", 2, regexec_flags)?;
+    ///
+    /// assert_eq!(matches[0].as_deref(), Some("café"));
+    /// assert_eq!(matches[1].as_deref(), Some("é"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`BindingErrorCode::ENCODING`]: crate::BindingErrorCode::ENCODING
+    /// [`RegexError`]: crate::RegexError
+    pub fn new_latin1(reg: &str, flags: RegcompFlags) -> Result<Self> {
+        let reg = reg
+            .chars()
+            .map(|c| {
+                u8::try_from(c).map_err(|_| {
+                    RegexError::new(
+                        ErrorKind::Binding(BindingErrorCode::ENCODING),
+                        &format!("Latin-1 encoding error: {c:?} is not in Latin-1"),
+                    )
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Self::new_bytes(&reg, flags.add(RegcompFlags::USEBYTES))
+    }
+
+    /// Performs a regex search on the passed Latin-1 bytes, returning `nmatches` results.
+    ///
+    /// Each match is decoded from Latin-1 (ISO-8859-1) into a [`String`], by mapping every byte to
+    /// the code point of the same value. Unlike [`Regex::regexec`], this never fails with an
+    /// encoding error, since every byte is valid Latin-1.
+    ///
+    /// The object should have been compiled with [`Regex::new_latin1`], or otherwise with
+    /// [`RegcompFlags::USEBYTES`], so that TRE does not try to decode the input as UTF-8.
+    ///
+    /// # Arguments
+    /// * `data`: Latin-1 bytes to match against `compiled_reg`
+    /// * `nmatches`: number of matches to return
+    /// * `flags`: [`RegexecFlags`] to pass to [`tre_regnexec`](tre_regex_sys::tre_regnexec).
+    ///
+    /// # Returns
+    /// If no error was found, a [`Vec`] of [`Option`]s will be returned.
+    ///
+    /// If a given match index is empty, The `Option` will be `None`. Otherwise, the `Option` will
+    /// contain the decoded [`String`].
+    ///
+    /// # Errors
+    /// If an error is encountered during matching, it returns a [`RegexError`]. An error is also
+    /// returned if `data` is too long; see [Input length](crate::Regex#input-length).
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use tre_regex::{RegcompFlags, RegexecFlags, Regex};
+    ///
+    /// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    /// let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    ///
+    /// let compiled_reg = Regex::new_latin1("^([^:]+): (.*)$", regcomp_flags)?;
+    /// let matches = compiled_reg.regexec_latin1(b"Nom: Fran\xe7ois", 3, regexec_flags)?;
+    ///
+    /// assert_eq!(matches[2].as_deref(), Some("François"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`RegexError`]: crate::RegexError
+    pub fn regexec_latin1(
+        &self,
+        data: &[u8],
+        nmatches: usize,
+        flags: RegexecFlags,
+    ) -> Result<RegMatchLatin1> {
+        let match_results = self.regexec_bytes(data, nmatches, flags)?;
+
+        Ok(match_results
+            .into_iter()
+            .map(|pmatch| pmatch.map(|pmatch| pmatch.iter().copied().map(char::from).collect()))
+            .collect())
+    }
+}
+
+/// Performs a regex search on the passed Latin-1 bytes, returning `nmatches` results.
+///
+/// This is a thin wrapper around [`Regex::regexec_latin1`].
+///
+/// # Arguments
+/// * `compiled_reg`: the compiled [`Regex`] object.
+/// * `data`: Latin-1 bytes to match against `compiled_reg`
+/// * `nmatches`: number of matches to return
+/// * `flags`: [`RegexecFlags`] to pass to [`tre_regnexec`](tre_regex_sys::tre_regnexec).
+///
+/// # Returns
+/// If no error was found, a [`Vec`] of [`Option`]s will be returned.
+///
+/// If a given match index is empty, The `Option` will be `None`. Otherwise, a decoded
+/// [`String`] will be returned.
+///
+/// # Errors
+/// If an error is encountered during matching, it returns a [`RegexError`].
+///
+/// # Examples
+/// ```
+/// # use tre_regex::Result;
+/// # fn main() -> Result<()> {
+/// use tre_regex::{RegcompFlags, RegexecFlags, Regex, regexec_latin1};
+///
+/// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+/// let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+///
+/// let compiled_reg = Regex::new_latin1("[àâä]", regcomp_flags)?;
+/// let matches = regexec_latin1(&compiled_reg, b"voil\xe0", 1, regexec_flags)?;
+///
+/// assert_eq!(matches[0].as_deref(), Some("à"));
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`RegexError`]: crate::RegexError
+#[inline]
+pub fn regexec_latin1(
+    compiled_reg: &Regex,
+    data: &[u8],
+    nmatches: usize,
+    flags: RegexecFlags,
+) -> Result<RegMatchLatin1> {
+    compiled_reg.regexec_latin1(data, nmatches, flags)
+}
@@ -1,8 +1,9 @@
 //! These are safe bindings to the [`tre_regex_sys`] module.
 //!
 //! These bindings are designed to provide an idiomatic Rust-like API to the [TRE library] as much
-//! as possible. Most of the TRE API is suported, except [`reguexec`] from TRE; that is tricky to
-//! implement, although should be fairly simple to use yourself.
+//! as possible. Most of the TRE API is suported. [`reguexec`] from TRE is only exposed through
+//! [`Regex::regexec_joined`], for matching over several strings at once; other custom sources
+//! should be fairly simple to implement yourself.
 //!
 //! This library uses Rust [`std::borrow::Cow`] strings to enable zero-copy of regex matches.
 //!
@@ -64,6 +65,36 @@
 //! # }
 //! ```
 //!
+//! ## Approximate matching
+//! With the `approx` feature, matches may be allowed to differ from the pattern by a limited
+//! number of edits.
+//! ```
+//! # use tre_regex::Result;
+//! # fn main() -> Result<()> {
+//! # #[cfg(feature = "approx")]
+//! # {
+//! use tre_regex::{RegApproxParams, RegcompFlags, RegexecFlags, Regex};
+//!
+//! let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+//! let regaexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+//! let regaexec_params = RegApproxParams::new().cost_subst(1).max_cost(1).max_err(1);
+//!
+//! let compiled_reg = Regex::new("^(hello) (world)$", regcomp_flags)?;
+//! let result = compiled_reg.regaexec("hullo world", &regaexec_params, 3, regaexec_flags)?;
+//!
+//! println!("Cost: {}", result.cost());
+//! for matched in &result {
+//!     match matched {
+//!         Some(Ok(substr)) => println!("Match: '{substr}'"),
+//!         Some(Err(e)) => println!("Match: <Error: {e}>"),
+//!         None => println!("Match: <None>"),
+//!     }
+//! }
+//! # }
+//! # Ok(())
+//! # }
+//! ```
+//!
 //! [TRE library]: <https://laurikari.net/tre/>
 //! [`reguexec`]: tre_regex_sys::tre_reguexec
 
@@ -74,23 +105,71 @@
 /// Public re-export of the [`tre_regex_sys`] module.
 pub use tre_regex_sys as tre;
 
+use std::fmt;
+
 #[cfg(feature = "approx")]
 mod approx;
+#[cfg(feature = "bstr")]
+mod bstr;
+mod builder;
+mod byteset;
+mod captures;
+mod chars;
 mod comp;
 mod err;
 mod exec;
 mod flags;
+mod highlight;
+mod info;
+mod iter;
+#[cfg(feature = "wchar")]
+mod joined;
+mod latin1;
+#[cfg(unix)]
+mod os;
+mod owned;
+mod replace;
+mod scanner;
+mod set;
+#[cfg(feature = "serde")]
+mod serde;
+#[cfg(feature = "smallvec")]
+mod smallvec;
+mod stream;
 #[cfg(test)]
 mod tests;
+mod util;
 #[cfg(feature = "wchar")]
 mod wchar;
 
 #[cfg(feature = "approx")]
 pub use crate::approx::*;
+#[cfg(feature = "bstr")]
+pub use crate::bstr::*;
+pub use crate::builder::*;
+pub use crate::byteset::*;
+pub use crate::captures::*;
+pub use crate::chars::*;
 pub use crate::comp::*;
 pub use crate::err::*;
 pub use crate::exec::*;
 pub use crate::flags::*;
+pub use crate::highlight::*;
+pub use crate::info::*;
+pub use crate::iter::*;
+#[cfg(feature = "wchar")]
+pub use crate::joined::*;
+pub use crate::latin1::*;
+#[cfg(unix)]
+pub use crate::os::*;
+pub use crate::owned::*;
+pub use crate::replace::*;
+pub use crate::scanner::*;
+pub use crate::set::*;
+#[cfg(feature = "smallvec")]
+pub use crate::smallvec::*;
+pub use crate::stream::*;
+pub use crate::util::*;
 #[cfg(feature = "wchar")]
 pub use crate::wchar::*;
 
@@ -101,8 +180,43 @@ pub use crate::wchar::*;
 ///
 /// This object provides an API similar to the function API. See the documentation on the
 /// individual functions for more information.
-#[derive(Debug)]
-pub struct Regex(Option<tre::regex_t>);
+///
+/// # Input length
+/// TRE reports match offsets as a [`regoff_t`](tre_regex_sys::regoff_t), which is a C `int` on
+/// most platforms. Inputs longer than [`regoff_t::MAX`](tre_regex_sys::regoff_t) (usually a little
+/// under 2 GiB) are therefore rejected with [`BindingErrorCode::INPUT_TOO_LONG`], rather than
+/// producing wrong offsets. Matching over a large memory-mapped file works without copying, as long
+/// as the mapped slice is within this limit; larger files must be matched in chunks.
+///
+/// # Serialization
+/// There is no way to serialize a compiled [`Regex`] and load it back later. The wrapped
+/// [`regex_t`](tre_regex_sys::regex_t) only holds a pointer to TRE's internal automaton, which is
+/// a graph of heap allocations full of pointers to one another; its layout is private to TRE and
+/// may change between versions.
+///
+/// If startup time matters, store the pattern source and its [`RegcompFlags`] instead, and
+/// compile each pattern on demand the first time it is needed, rather than compiling all of them
+/// up front.
+///
+/// # Narrow and wide strings
+/// TRE always compiles patterns to the same internal form, whether the pattern was given as bytes
+/// or as a wide string. This means a single [`Regex`] can be matched against both narrow and wide
+/// strings: a pattern compiled with [`Regex::new`] may be passed to `regwexec`, and one compiled
+/// with `new_wide` may be passed to [`Regex::regexec`]. There is no need to compile the same
+/// pattern twice.
+///
+/// [`BindingErrorCode::INPUT_TOO_LONG`]: crate::BindingErrorCode::INPUT_TOO_LONG
+/// [`RegcompFlags`]: crate::RegcompFlags
+#[allow(clippy::struct_field_names)]
+pub struct Regex {
+    regex: Option<tre::regex_t>,
+    pattern: Option<Box<[u8]>>,
+    flags: flags::RegcompFlags,
+    literal: bool,
+    #[cfg(feature = "wchar")]
+    wide_pattern: Option<widestring::WideString>,
+    owned: bool,
+}
 
 impl Regex {
     /// Create a new [`Regex`] object from the given [`regex_t`](tre_regex_sys::regex_t).
@@ -120,7 +234,8 @@ impl Regex {
     /// * `regex`: A [`regex_t`](tre_regex_sys::regex_t) to wrap.
     ///
     /// # Returns
-    /// A new [`Regex`] object, containing the passed-in [`regex_t`](tre_regex_sys::regex_t).
+    /// A new [`Regex`] object, containing the passed-in [`regex_t`](tre_regex_sys::regex_t). It has
+    /// no source pattern, so [`Regex::as_str`] and [`Regex::as_bytes`] will return `None`.
     ///
     /// # Safety
     /// The `regex` parameter must have been initalised by [`tre_regcomp`](tre_regex_sys::tre_regcomp)
@@ -130,13 +245,69 @@ impl Regex {
     #[must_use]
     #[inline]
     pub const unsafe fn new_from(regex: tre::regex_t) -> Self {
-        Self(Some(regex))
+        Self {
+            regex: Some(regex),
+            pattern: None,
+            flags: flags::RegcompFlags::new(),
+            literal: false,
+            #[cfg(feature = "wchar")]
+            wide_pattern: None,
+            owned: true,
+        }
+    }
+
+    /// Create a new [`Regex`] object borrowing a [`regex_t`](tre_regex_sys::regex_t) owned
+    /// elsewhere.
+    ///
+    /// This function is for advanced use only. It is meant for matching with a compiled regex
+    /// owned by other code, such as a C library. Unlike [`Regex::new_from`], the returned object
+    /// does not own `regex`: dropping it does not call
+    /// [`tre_regfree`](tre_regex_sys::tre_regfree). Freeing the regex remains the owner's job.
+    ///
+    /// # Arguments
+    /// * `regex`: A [`regex_t`](tre_regex_sys::regex_t) to borrow.
+    ///
+    /// # Returns
+    /// A new [`Regex`] object, containing a copy of the passed-in
+    /// [`regex_t`](tre_regex_sys::regex_t). It has no source pattern, so [`Regex::as_str`] and
+    /// [`Regex::as_bytes`] will return `None`.
+    ///
+    /// # Safety
+    /// The `regex` parameter must have been initalised by [`tre_regcomp`](tre_regex_sys::tre_regcomp).
+    ///
+    /// The returned object is not tied to the lifetime of `regex`, since the
+    /// [`regex_t`](tre_regex_sys::regex_t) only holds pointers to TRE's internal data. The caller
+    /// must ensure the owner does not free or recompile `regex` until the returned object (and
+    /// anything borrowed from it) has been dropped, and that nothing else modifies it while the
+    /// returned object is in use.
+    #[must_use]
+    #[inline]
+    pub const unsafe fn new_borrowed(regex: &tre::regex_t) -> Self {
+        Self {
+            regex: Some(*regex),
+            pattern: None,
+            flags: flags::RegcompFlags::new(),
+            literal: false,
+            #[cfg(feature = "wchar")]
+            wide_pattern: None,
+            owned: false,
+        }
+    }
+
+    /// Checks whether this object was created with [`Regex::new_borrowed`], and so does not free
+    /// its [`regex_t`](tre_regex_sys::regex_t) when dropped.
+    #[must_use]
+    #[inline]
+    pub const fn is_borrowed(&self) -> bool {
+        !self.owned
     }
 
     /// Relinquish the underlying [`regex_t`](tre_regex_sys::regex_t) object.
     ///
     /// This is an advanced function and should not be used unless you know what you are doing.
     ///
+    /// The source pattern is discarded along with it.
+    ///
     /// # Returns
     /// `None` if the object is vacant, otherwise `Some(`[`regex_t`](tre_regex_sys::regex_t)`)`.
     ///
@@ -146,23 +317,256 @@ impl Regex {
     #[must_use]
     #[inline]
     pub unsafe fn release(&mut self) -> Option<tre::regex_t> {
-        let regex = self.0;
-        self.0 = None;
+        let regex = self.regex;
+        self.regex = None;
+        self.pattern = None;
         regex
     }
 
+    /// Frees the underlying [`regex_t`](tre_regex_sys::regex_t) now, leaving the object vacant.
+    ///
+    /// This does what dropping the object would do, at a point of the caller's choosing: it calls
+    /// [`tre_regfree`](tre_regex_sys::tre_regfree), unless the regex is borrowed (see
+    /// [`Regex::new_borrowed`]). The source pattern is discarded along with it. Afterwards, every
+    /// matching function returns a [`BindingErrorCode::REGEX_VACANT`] error, until the object is
+    /// given a new regex with [`Regex::recompile`].
+    ///
+    /// Calling this on a vacant object does nothing, so it is safe to call more than once.
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use tre_regex::{RegcompFlags, RegexecFlags, Regex};
+    ///
+    /// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    /// let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    ///
+    /// let mut compiled_reg = Regex::new("hello", regcomp_flags)?;
+    /// compiled_reg.free();
+    /// compiled_reg.free();
+    ///
+    /// assert!(compiled_reg.get().is_none());
+    /// assert!(compiled_reg.is_match("hello", regexec_flags).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`BindingErrorCode::REGEX_VACANT`]: crate::BindingErrorCode::REGEX_VACANT
+    pub fn free(&mut self) {
+        self.pattern = None;
+        self.literal = false;
+        #[cfg(feature = "wchar")]
+        {
+            self.wide_pattern = None;
+        }
+        let Some(mut compiled_reg) = self.regex.take() else { return; };
+        if !self.owned {
+            return;
+        }
+
+        // SAFETY: freeing data passed into the struct previously.
+        // If the data came from our API, this is safe. Otherwise, the user must opt into storing
+        // the regex here. It has been taken out of the struct, so it cannot be freed twice.
+        unsafe {
+            tre::tre_regfree(&mut compiled_reg);
+        }
+    }
+
     /// Gets an immutable reference to the underlying [`regex_t`](tre_regex_sys::regex_t) object.
     #[must_use]
     #[inline]
     pub const fn get(&self) -> &Option<tre::regex_t> {
-        &self.0
+        &self.regex
     }
 
     /// Gets a mutable reference to the underlying [`regex_t`](tre_regex_sys::regex_t) object.
     #[must_use]
     #[inline]
     pub fn get_mut(&mut self) -> &mut Option<tre::regex_t> {
-        &mut self.0
+        &mut self.regex
+    }
+
+    /// Gets the pattern this object was compiled from, as a string.
+    ///
+    /// # Returns
+    /// `None` if the object has no stored pattern (for instance, if it was created with
+    /// [`Regex::new_from`]), or if the pattern is not valid UTF-8.
+    #[must_use]
+    #[inline]
+    pub fn as_str(&self) -> Option<&str> {
+        std::str::from_utf8(self.as_bytes()?).ok()
+    }
+
+    /// Gets the pattern this object was compiled from, as bytes.
+    ///
+    /// # Returns
+    /// `None` if the object has no stored pattern (for instance, if it was created with
+    /// [`Regex::new_from`]).
+    #[must_use]
+    #[inline]
+    pub fn as_bytes(&self) -> Option<&[u8]> {
+        self.pattern.as_deref()
+    }
+
+    /// Gets the pattern this object was compiled from, as bytes, or an empty slice if there is
+    /// none.
+    ///
+    /// This is [`Regex::as_bytes`] for when a missing pattern need not be told apart from an empty
+    /// one, such as for logging. Patterns compiled from a wide string are given encoded as UTF-8;
+    /// see [`Regex::is_wide`].
+    #[must_use]
+    #[inline]
+    pub fn as_pattern_bytes(&self) -> &[u8] {
+        self.as_bytes().unwrap_or_default()
+    }
+
+    /// Checks whether this object was compiled from a wide string, with `new_wide`.
+    ///
+    /// The pattern of such an object is given by [`Regex::as_str`] and [`Regex::as_bytes`] encoded
+    /// as UTF-8, or not at all if it is not valid Unicode (for instance, if it contains a lone
+    /// surrogate). The wide string itself is kept as well, and is what [`Regex::try_clone`]
+    /// compiles again.
+    #[must_use]
+    #[inline]
+    pub const fn is_wide(&self) -> bool {
+        #[cfg(feature = "wchar")]
+        {
+            self.wide_pattern.is_some()
+        }
+        #[cfg(not(feature = "wchar"))]
+        {
+            false
+        }
+    }
+
+    /// Gets the number of capture groups in the pattern, including group 0 (the whole match).
+    ///
+    /// This is the number of matches to request from [`Regex::regexec`] or [`Regex::regaexec`] to
+    /// get every group; asking for fewer silently leaves out the last groups. It is read from
+    /// [`re_nsub`](tre_regex_sys::regex_t::re_nsub), which TRE fills in when compiling, whether
+    /// the pattern is then used for exact or approximate matching.
+    ///
+    /// # Errors
+    /// If the object is vacant, a [`BindingErrorCode::REGEX_VACANT`] error is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use tre_regex::{RegcompFlags, Regex};
+    ///
+    /// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    /// let compiled_reg = Regex::new("(a)(b(c))?", regcomp_flags)?;
+    ///
+    /// assert_eq!(compiled_reg.captures_len()?, 4);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`BindingErrorCode::REGEX_VACANT`]: crate::BindingErrorCode::REGEX_VACANT
+    pub fn captures_len(&self) -> Result<usize> {
+        Ok(self.nsub()? + 1)
+    }
+
+    /// Gets the number of parenthesised subexpressions in the pattern, not counting group 0.
+    ///
+    /// This is [`re_nsub`](tre_regex_sys::regex_t::re_nsub) as filled in by TRE when compiling.
+    /// See [`Regex::captures_len`] for the number of matches to request to get every group.
+    ///
+    /// # Errors
+    /// If the object is vacant, a [`BindingErrorCode::REGEX_VACANT`] error is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use tre_regex::{RegcompFlags, Regex};
+    ///
+    /// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    /// let compiled_reg = Regex::new("(a)(b(c))?", regcomp_flags)?;
+    ///
+    /// assert_eq!(compiled_reg.nsub()?, 3);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`BindingErrorCode::REGEX_VACANT`]: crate::BindingErrorCode::REGEX_VACANT
+    pub fn nsub(&self) -> Result<usize> {
+        let Some(compiled_reg_obj) = self.get() else {
+            return Err(RegexError::new(
+                ErrorKind::Binding(BindingErrorCode::REGEX_VACANT),
+                "Attempted to unwrap a vacant Regex object"
+            ));
+        };
+        Ok(compiled_reg_obj.re_nsub)
+    }
+
+    /// Checks whether the pattern uses back references, such as `\1`.
+    ///
+    /// This is [`tre_have_backrefs`](tre_regex_sys::tre_have_backrefs). Patterns with back
+    /// references cannot be matched with TRE's fast parallel matcher, and fall back to a
+    /// backtracking matcher, which may be much slower on long inputs.
+    ///
+    /// # Errors
+    /// If the object is vacant, a [`BindingErrorCode::REGEX_VACANT`] error is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use tre_regex::{RegcompFlags, Regex};
+    ///
+    /// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    ///
+    /// assert!(Regex::new("(a+)\\1", regcomp_flags)?.has_backrefs()?);
+    /// assert!(!Regex::new("(a+)b", regcomp_flags)?.has_backrefs()?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`BindingErrorCode::REGEX_VACANT`]: crate::BindingErrorCode::REGEX_VACANT
+    pub fn has_backrefs(&self) -> Result<bool> {
+        let Some(compiled_reg_obj) = self.get() else {
+            return Err(RegexError::new(
+                ErrorKind::Binding(BindingErrorCode::REGEX_VACANT),
+                "Attempted to unwrap a vacant Regex object"
+            ));
+        };
+
+        // SAFETY: compiled_reg is a wrapped type (see safety concerns for Regex).
+        Ok(unsafe { tre::tre_have_backrefs(compiled_reg_obj) } != 0)
+    }
+
+    /// Checks whether the pattern uses approximate matching features, such as `{~1}`.
+    ///
+    /// This is [`tre_have_approx`](tre_regex_sys::tre_have_approx). Approximate patterns are
+    /// matched with TRE's approximate matcher, which is slower than the exact one.
+    ///
+    /// # Errors
+    /// If the object is vacant, a [`BindingErrorCode::REGEX_VACANT`] error is returned.
+    ///
+    /// [`BindingErrorCode::REGEX_VACANT`]: crate::BindingErrorCode::REGEX_VACANT
+    pub fn has_approx(&self) -> Result<bool> {
+        let Some(compiled_reg_obj) = self.get() else {
+            return Err(RegexError::new(
+                ErrorKind::Binding(BindingErrorCode::REGEX_VACANT),
+                "Attempted to unwrap a vacant Regex object"
+            ));
+        };
+
+        // SAFETY: compiled_reg is a wrapped type (see safety concerns for Regex).
+        Ok(unsafe { tre::tre_have_approx(compiled_reg_obj) } != 0)
+    }
+
+    /// Gets the pattern if it is a plain literal, which can be searched for without TRE.
+    #[inline]
+    pub(crate) fn literal(&self) -> Option<&str> {
+        if self.literal {
+            self.as_str()
+        } else {
+            None
+        }
     }
 }
 
@@ -170,16 +574,72 @@ impl Drop for Regex {
     /// Executes the destructor for this type.
     ///
     /// The destructor will call [`tre_regfree`](tre_regex_sys::tre_regfree) on the internal
-    /// [`regex_t`](tre_regex_sys::regex_t).
+    /// [`regex_t`](tre_regex_sys::regex_t), unless it is borrowed (see [`Regex::new_borrowed`]).
     #[inline]
     fn drop(&mut self) {
-        let Some(compiled_reg) = self.get_mut() else { return; };
+        self.free();
+    }
+}
 
-        // SAFETY: freeing data passed into the struct previously.
-        // If the data came from our API, this is safe. Otherwise, the user must opt into storing
-        // the regex here.
-        unsafe {
-            tre::tre_regfree(compiled_reg);
+// The wrapped regex_t is left out on purpose, as its fields are meaningless outside TRE.
+#[allow(clippy::missing_fields_in_debug)]
+impl fmt::Debug for Regex {
+    /// Formats the pattern and flags of this object, such as
+    /// `Regex { pattern: "foo.*", flags: EXTENDED | ICASE }`.
+    ///
+    /// A pattern that is not valid UTF-8 is shown as an escaped byte string, and a missing pattern
+    /// (for instance, with [`Regex::new_from`]) is shown as `None`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        struct Pattern<'a>(Option<&'a [u8]>);
+
+        impl fmt::Debug for Pattern<'_> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                match self.0 {
+                    None => f.write_str("None"),
+                    Some(bytes) => match std::str::from_utf8(bytes) {
+                        Ok(pattern) => fmt::Debug::fmt(pattern, f),
+                        Err(_) => write!(f, "b\"{}\"", bytes.escape_ascii()),
+                    },
+                }
+            }
+        }
+
+        f.debug_struct("Regex")
+            .field("pattern", &Pattern(self.as_bytes()))
+            .field("flags", &format_args!("{}", self.flags))
+            .finish()
+    }
+}
+
+impl Clone for Regex {
+    /// Makes a copy of this [`Regex`] object by compiling its pattern again.
+    ///
+    /// This is the same as [`Regex::try_clone`], except in two cases where no compilation is
+    /// needed: a vacant object is copied as another vacant object, and a borrowed object (see
+    /// [`Regex::new_borrowed`]) is copied as another object borrowing the same
+    /// [`regex_t`](tre_regex_sys::regex_t).
+    ///
+    /// # Panics
+    /// Panics if the object owns its [`regex_t`](tre_regex_sys::regex_t) but has no stored
+    /// pattern to compile (for instance, if it was created with [`Regex::new_from`]). Use
+    /// [`Regex::try_clone`] to handle this case. Also panics if compiling the pattern again fails,
+    /// which should not happen, as it compiled once already.
+    fn clone(&self) -> Self {
+        if self.regex.is_none() || !self.owned {
+            return Self {
+                regex: self.regex,
+                pattern: self.pattern.clone(),
+                flags: self.flags,
+                literal: self.literal,
+                #[cfg(feature = "wchar")]
+                wide_pattern: self.wide_pattern.clone(),
+                owned: self.owned,
+            };
+        }
+
+        match self.try_clone() {
+            Ok(compiled_reg) => compiled_reg,
+            Err(e) => panic!("Failed to compile the pattern again to clone a Regex: {e}"),
         }
     }
 }
@@ -0,0 +1,75 @@
+use std::ffi::OsStr;
+use std::os::unix::ffi::OsStrExt;
+
+use crate::{err::Result, exec::match_range, flags::RegexecFlags, Regex};
+
+pub type RegMatchOsStr<'a> = Vec<Option<&'a OsStr>>;
+
+impl Regex {
+    /// Performs a regex search on the passed [`OsStr`], returning `nmatches` results.
+    ///
+    /// On Unix, an [`OsStr`] such as a file name is an arbitrary sequence of bytes, which need not
+    /// be valid UTF-8. This matches against those bytes directly, as [`Regex::regexec_bytes`]
+    /// does, so that no lossy conversion to a string is needed.
+    ///
+    /// # Arguments
+    /// * `string`: [`OsStr`] to match against `compiled_reg`
+    /// * `nmatches`: number of matches to return
+    /// * `flags`: [`RegexecFlags`] to pass to [`tre_regnexec`](tre_regex_sys::tre_regnexec).
+    ///
+    /// # Returns
+    /// If no error was found, a [`Vec`] of [`Option`]s will be returned.
+    ///
+    /// If a given match index is empty, The `Option` will be `None`. Otherwise, [`OsStr`] slices
+    /// of `string` will be returned.
+    ///
+    /// # Errors
+    /// If an error is encountered during matching, it returns a [`RegexError`]. An error is also
+    /// returned if `string` is too long; see [Input length](crate::Regex#input-length).
+    ///
+    /// # Caveats
+    /// The pattern is matched against the raw bytes, so unless it was compiled with
+    /// [`RegcompFlags::USEBYTES`], bytes which are not valid UTF-8 are matched as TRE sees fit.
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use std::ffi::OsStr;
+    /// use std::os::unix::ffi::OsStrExt;
+    /// use tre_regex::{RegcompFlags, RegexecFlags, Regex};
+    ///
+    /// let regcomp_flags = RegcompFlags::new()
+    ///     .add(RegcompFlags::EXTENDED)
+    ///     .add(RegcompFlags::USEBYTES);
+    /// let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    ///
+    /// let compiled_reg = Regex::new("^(.*)\\.txt$", regcomp_flags)?;
+    /// let file_name = OsStr::from_bytes(b"caf\xe9.txt");
+    /// let matches = compiled_reg.regexec_os(file_name, 2, regexec_flags)?;
+    ///
+    /// assert_eq!(matches[1], Some(OsStr::from_bytes(b"caf\xe9")));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`RegcompFlags::USEBYTES`]: crate::RegcompFlags::USEBYTES
+    /// [`RegexError`]: crate::RegexError
+    pub fn regexec_os<'a>(
+        &self,
+        string: &'a OsStr,
+        nmatches: usize,
+        flags: RegexecFlags,
+    ) -> Result<RegMatchOsStr<'a>> {
+        let data = string.as_bytes();
+        let match_vec = self.regexec_raw(data, nmatches, flags)?;
+
+        Ok(match_vec
+            .into_iter()
+            .map(|pmatch| {
+                let (start, end) = match_range(pmatch, 0)?;
+                Some(OsStr::from_bytes(&data[start..end]))
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,73 @@
+use std::borrow::Cow;
+
+use crate::{
+    err::Result,
+    exec::{RegMatchBytes, RegMatchBytesChecked, RegMatchStr},
+};
+
+pub type RegMatchStrOwned = Vec<Option<Result<String>>>;
+pub type RegMatchBytesOwned = Vec<Option<Vec<u8>>>;
+pub type RegMatchBytesCheckedOwned = Vec<Option<Result<Vec<u8>>>>;
+
+/// Conversion of match results borrowing from the input into owned results.
+///
+/// Match results such as [`RegMatchStr`] borrow from the string that was searched, for
+/// efficiency. This copies every match, so that the results can outlive it, as with
+/// [`Cow::into_owned`](std::borrow::Cow::into_owned).
+///
+/// # Examples
+/// ```
+/// # use tre_regex::Result;
+/// # fn main() -> Result<()> {
+/// use tre_regex::{IntoOwnedMatches, RegMatchStrOwned, RegcompFlags, RegexecFlags, Regex};
+///
+/// fn first_word(compiled_reg: &Regex) -> Result<RegMatchStrOwned> {
+///     let haystack = String::from("hello world");
+///     let matches = compiled_reg.regexec(&haystack, 1, RegexecFlags::new())?;
+///     Ok(matches.into_owned())
+/// }
+///
+/// let compiled_reg = Regex::new("[a-z]+", RegcompFlags::new().add(RegcompFlags::EXTENDED))?;
+/// let matches = first_word(&compiled_reg)?;
+/// assert_eq!(matches[0].as_ref().unwrap().as_deref().ok(), Some("hello"));
+/// # Ok(())
+/// # }
+/// ```
+pub trait IntoOwnedMatches {
+    /// The owned form of the results
+    type Owned;
+
+    /// Copies every match into owned storage.
+    #[must_use]
+    fn into_owned(self) -> Self::Owned;
+}
+
+impl IntoOwnedMatches for RegMatchStr<'_> {
+    type Owned = RegMatchStrOwned;
+
+    fn into_owned(self) -> Self::Owned {
+        self.into_iter()
+            .map(|matched| matched.map(|res| res.map(Cow::into_owned)))
+            .collect()
+    }
+}
+
+impl IntoOwnedMatches for RegMatchBytes<'_> {
+    type Owned = RegMatchBytesOwned;
+
+    fn into_owned(self) -> Self::Owned {
+        self.into_iter()
+            .map(|matched| matched.map(Cow::into_owned))
+            .collect()
+    }
+}
+
+impl IntoOwnedMatches for RegMatchBytesChecked<'_> {
+    type Owned = RegMatchBytesCheckedOwned;
+
+    fn into_owned(self) -> Self::Owned {
+        self.into_iter()
+            .map(|matched| matched.map(|res| res.map(Cow::into_owned)))
+            .collect()
+    }
+}
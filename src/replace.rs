@@ -0,0 +1,388 @@
+use std::borrow::Cow;
+
+use crate::{
+    captures::Captures,
+    err::Result,
+    exec::match_range,
+    flags::RegexecFlags,
+    tre, Regex,
+};
+
+impl Regex {
+    /// Replaces at most the first `limit` matches in `haystack` with the template `rep`.
+    ///
+    /// Matches are found as by [`find_iter`]. In `rep`, `$0` to `$9` are replaced with the text
+    /// of the corresponding capture group, `${n}` with the text of group `n` (which may have more
+    /// than one digit), and `$$` with a literal `$`. A group which did not participate in the
+    /// match, or which does not exist, expands to nothing. Any other `$` is copied as-is. Use
+    /// [`escape_replacement`] to insert arbitrary literal text.
+    ///
+    /// # Arguments
+    /// * `haystack`: string to search
+    /// * `rep`: replacement template
+    /// * `limit`: maximum number of matches to replace; the rest of `haystack` is left unchanged
+    /// * `flags`: [`RegexecFlags`] to pass to [`tre_regnexec`](tre_regex_sys::tre_regnexec).
+    ///
+    /// # Returns
+    /// The string with the replacements made. If nothing was replaced, `haystack` is returned
+    /// borrowed, without allocating.
+    ///
+    /// # Errors
+    /// If an error is encountered during matching, it returns a [`RegexError`]. An error is also
+    /// returned if a match does not fall on UTF-8 character boundaries.
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use tre_regex::{RegcompFlags, RegexecFlags, Regex};
+    ///
+    /// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    /// let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    ///
+    /// let compiled_reg = Regex::new("([[:alpha:]]+)=([[:digit:]]+)", regcomp_flags)?;
+    /// let result = compiled_reg.replacen("a=1 b=2 c=3", "$2=$1", 2, regexec_flags)?;
+    ///
+    /// assert_eq!(result, "1=a 2=b c=3");
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`find_iter`]: crate::Regex::find_iter
+    /// [`RegexError`]: crate::RegexError
+    pub fn replacen<'h>(
+        &self,
+        haystack: &'h str,
+        rep: &str,
+        limit: usize,
+        flags: RegexecFlags,
+    ) -> Result<Cow<'h, str>> {
+        self.replace_impl(haystack, limit, flags, |caps, dst| expand(rep, caps, dst))
+    }
+
+    /// Replaces the first match in `haystack` with the template `rep`.
+    ///
+    /// This is the same as [`Regex::replacen`] with a `limit` of 1; see there for the template
+    /// syntax.
+    ///
+    /// # Arguments
+    /// * `haystack`: string to search
+    /// * `rep`: replacement template
+    /// * `flags`: [`RegexecFlags`] to pass to [`tre_regnexec`](tre_regex_sys::tre_regnexec).
+    ///
+    /// # Returns
+    /// The string with the replacement made. If there was no match, `haystack` is returned
+    /// borrowed, without allocating.
+    ///
+    /// # Errors
+    /// If an error is encountered during matching, it returns a [`RegexError`]. An error is also
+    /// returned if the match does not fall on UTF-8 character boundaries.
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use tre_regex::{RegcompFlags, RegexecFlags, Regex};
+    ///
+    /// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    /// let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    ///
+    /// let compiled_reg = Regex::new("([[:digit:]]+) USD", regcomp_flags)?;
+    /// let result = compiled_reg.replace("5 USD, 7 USD", "$$$1", regexec_flags)?;
+    ///
+    /// assert_eq!(result, "$5, 7 USD");
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`RegexError`]: crate::RegexError
+    #[inline]
+    pub fn replace<'h>(
+        &self,
+        haystack: &'h str,
+        rep: &str,
+        flags: RegexecFlags,
+    ) -> Result<Cow<'h, str>> {
+        self.replacen(haystack, rep, 1, flags)
+    }
+
+    /// Replaces every match in `haystack` with the template `rep`.
+    ///
+    /// This is the same as [`Regex::replacen`] with no limit; see there for the template syntax.
+    ///
+    /// # Arguments
+    /// * `haystack`: string to search
+    /// * `rep`: replacement template
+    /// * `flags`: [`RegexecFlags`] to pass to [`tre_regnexec`](tre_regex_sys::tre_regnexec).
+    ///
+    /// # Returns
+    /// The string with the replacements made. If there were no matches, `haystack` is returned
+    /// borrowed, without allocating.
+    ///
+    /// # Errors
+    /// If an error is encountered during matching, it returns a [`RegexError`]. An error is also
+    /// returned if a match does not fall on UTF-8 character boundaries.
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use tre_regex::{RegcompFlags, RegexecFlags, Regex};
+    ///
+    /// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    /// let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    ///
+    /// let compiled_reg = Regex::new("([[:digit:]]+) USD", regcomp_flags)?;
+    /// let result = compiled_reg.replace_all("5 USD, 7 USD", "$$${1}", regexec_flags)?;
+    ///
+    /// assert_eq!(result, "$5, $7");
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`RegexError`]: crate::RegexError
+    #[inline]
+    pub fn replace_all<'h>(
+        &self,
+        haystack: &'h str,
+        rep: &str,
+        flags: RegexecFlags,
+    ) -> Result<Cow<'h, str>> {
+        self.replacen(haystack, rep, usize::MAX, flags)
+    }
+
+    /// Replaces every match in `haystack` with the string returned by `f`.
+    ///
+    /// This is the most flexible form of substitution: `f` is called with the [`Captures`] of
+    /// each match, as found by [`find_iter`], and its return value is inserted in place of the
+    /// match. Every capture group of the pattern is available to `f`.
+    ///
+    /// # Arguments
+    /// * `haystack`: string to search
+    /// * `flags`: [`RegexecFlags`] to pass to [`tre_regnexec`](tre_regex_sys::tre_regnexec).
+    /// * `f`: closure computing the replacement for each match
+    ///
+    /// # Returns
+    /// The string with the replacements made. If there were no matches, `haystack` is returned
+    /// borrowed, without allocating.
+    ///
+    /// # Errors
+    /// If an error is encountered during matching, it returns a [`RegexError`]. An error is also
+    /// returned if a match does not fall on UTF-8 character boundaries.
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use tre_regex::{RegcompFlags, RegexecFlags, Regex};
+    ///
+    /// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    /// let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    ///
+    /// let compiled_reg = Regex::new("([[:alpha:]]+)=([[:digit:]]+)", regcomp_flags)?;
+    /// let result = compiled_reg.replace_all_with("a=1 b=2", regexec_flags, |caps| {
+    ///     let doubled = caps.get(2).unwrap_or("0").parse::<u32>().unwrap_or(0) * 2;
+    ///     format!("{}={doubled}", caps.get(1).unwrap_or("").to_uppercase())
+    /// })?;
+    ///
+    /// assert_eq!(result, "A=2 B=4");
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`find_iter`]: crate::Regex::find_iter
+    /// [`RegexError`]: crate::RegexError
+    pub fn replace_all_with<'h, F>(
+        &self,
+        haystack: &'h str,
+        flags: RegexecFlags,
+        mut f: F,
+    ) -> Result<Cow<'h, str>>
+    where
+        F: FnMut(&Captures<'h>) -> String,
+    {
+        self.replace_impl(haystack, usize::MAX, flags, |caps, dst| {
+            dst.push_str(&f(caps));
+        })
+    }
+
+    /// Replaces at most the first `limit` matches in `haystack` with the string returned by `f`.
+    ///
+    /// `f` is called with the text of every capture group of each match, as found by
+    /// [`find_iter`], with `None` for groups which did not participate in the match. Its return
+    /// value is inserted in place of the match.
+    ///
+    /// Unlike [`Regex::replacen`], a `limit` of zero means no limit, so every match is replaced.
+    ///
+    /// # Arguments
+    /// * `haystack`: string to search
+    /// * `limit`: maximum number of matches to replace, or zero to replace them all
+    /// * `flags`: [`RegexecFlags`] to pass to [`tre_regnexec`](tre_regex_sys::tre_regnexec).
+    /// * `f`: closure computing the replacement for each match
+    ///
+    /// # Returns
+    /// The string with the replacements made. If there were no matches, `haystack` is returned
+    /// borrowed, without allocating.
+    ///
+    /// # Errors
+    /// If an error is encountered during matching, it returns a [`RegexError`]. An error is also
+    /// returned if a match does not fall on UTF-8 character boundaries.
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use tre_regex::{RegcompFlags, RegexecFlags, Regex};
+    ///
+    /// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    /// let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    ///
+    /// let compiled_reg = Regex::new("([[:alpha:]]+)(!)?", regcomp_flags)?;
+    /// let result = compiled_reg.replacen_with("hey you!", 0, regexec_flags, |groups| {
+    ///     match groups[2] {
+    ///         Some(_) => groups[1].unwrap_or("").to_uppercase(),
+    ///         None => groups[1].unwrap_or("").to_string(),
+    ///     }
+    /// })?;
+    ///
+    /// assert_eq!(result, "hey YOU");
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`find_iter`]: crate::Regex::find_iter
+    /// [`RegexError`]: crate::RegexError
+    pub fn replacen_with<'h, F>(
+        &self,
+        haystack: &'h str,
+        limit: usize,
+        flags: RegexecFlags,
+        mut f: F,
+    ) -> Result<Cow<'h, str>>
+    where
+        F: FnMut(&[Option<&'h str>]) -> String,
+    {
+        let limit = if limit == 0 { usize::MAX } else { limit };
+        let mut groups = Vec::new();
+        self.replace_impl(haystack, limit, flags, |caps, dst| {
+            groups.clear();
+            groups.extend(caps.iter());
+            dst.push_str(&f(&groups));
+        })
+    }
+
+    /// Replaces at most `limit` matches, calling `f` to append the replacement for each one.
+    fn replace_impl<'h, F>(
+        &self,
+        haystack: &'h str,
+        limit: usize,
+        flags: RegexecFlags,
+        mut f: F,
+    ) -> Result<Cow<'h, str>>
+    where
+        F: FnMut(&Captures<'h>, &mut String),
+    {
+        let nmatches = self.captures_len()?;
+        let mut pmatch = vec![tre::regmatch_t { rm_so: 0, rm_eo: 0 }; nmatches];
+        let data = haystack.as_bytes();
+
+        // Matches are found as by FindIter, but with all groups filled in by the same search.
+        let mut result = String::new();
+        let mut last = 0;
+        let mut pos = 0;
+        let mut last_end = None;
+        let mut replaced = 0;
+        while replaced < limit && pos <= data.len() {
+            if !self.exec_from(data, pos, &mut pmatch, flags)? {
+                break;
+            }
+            let locs: Vec<_> = pmatch.iter().map(|&m| match_range(m, pos)).collect();
+            let Some((start, end)) = locs[0] else {
+                break;
+            };
+
+            if start == end {
+                pos = haystack[end..]
+                    .chars()
+                    .next()
+                    .map_or(end + 1, |c| end + c.len_utf8());
+                if last_end == Some(end) {
+                    // Empty match right after the previous match; skip it.
+                    continue;
+                }
+            } else {
+                pos = end;
+            }
+            last_end = Some(end);
+
+            let caps = Captures::new(haystack, locs)?;
+            result.push_str(&haystack[last..start]);
+            f(&caps, &mut result);
+            last = end;
+            replaced += 1;
+        }
+
+        if replaced == 0 {
+            return Ok(Cow::Borrowed(haystack));
+        }
+
+        result.push_str(&haystack[last..]);
+        Ok(Cow::Owned(result))
+    }
+}
+
+/// Appends the expansion of the replacement template `rep` for `caps` to `dst`.
+fn expand(rep: &str, caps: &Captures<'_>, dst: &mut String) {
+    let mut rest = rep;
+    while let Some(i) = rest.find('$') {
+        dst.push_str(&rest[..i]);
+        rest = &rest[i + 1..];
+
+        let bytes = rest.as_bytes();
+        match bytes.first() {
+            Some(b'$') => {
+                dst.push('$');
+                rest = &rest[1..];
+            }
+            Some(c) if c.is_ascii_digit() => {
+                dst.push_str(caps.get(usize::from(c - b'0')).unwrap_or(""));
+                rest = &rest[1..];
+            }
+            Some(b'{') => {
+                let group = rest[1..]
+                    .find('}')
+                    .filter(|&len| len > 0)
+                    .and_then(|len| Some((rest[1..=len].parse::<usize>().ok()?, len)));
+                if let Some((group, len)) = group {
+                    dst.push_str(caps.get(group).unwrap_or(""));
+                    rest = &rest[len + 2..];
+                } else {
+                    dst.push('$');
+                }
+            }
+            _ => dst.push('$'),
+        }
+    }
+    dst.push_str(rest);
+}
+
+/// Escapes a string for use as literal text in a replacement template.
+///
+/// Every `$` is doubled into `$$`, so that it is not taken as a reference to a capture group.
+///
+/// # Arguments
+/// * `string`: literal text to escape.
+///
+/// # Returns
+/// The escaped text.
+///
+/// # Examples
+/// ```
+/// use tre_regex::escape_replacement;
+///
+/// assert_eq!(escape_replacement("costs $5"), "costs $$5");
+/// ```
+#[must_use]
+pub fn escape_replacement(string: &str) -> String {
+    string.replace('$', "$$")
+}
@@ -0,0 +1,155 @@
+use std::ops::Range;
+
+use crate::{
+    err::{BindingErrorCode, ErrorKind, RegexError, Result},
+    flags::RegexecFlags,
+    Regex,
+};
+
+/// A simple lexer, splitting input into tokens with an ordered list of patterns.
+///
+/// At each position in the input, every rule is tried in order, anchored at that position (see
+/// [`Regex::captures_at`]). The first rule with a non-empty match produces a token, and scanning
+/// resumes after it. Rules should be ordered from most to least specific; for instance, keywords
+/// before identifiers. Prefixing each pattern with `^` lets TRE give up early when a rule does not
+/// match.
+///
+/// Empty matches never produce a token, so a rule such as `[[:space:]]*` cannot stall the
+/// scanner.
+#[derive(Debug)]
+pub struct Scanner<K> {
+    rules: Vec<(K, Regex)>,
+    flags: RegexecFlags,
+}
+
+impl<K: Clone> Scanner<K> {
+    /// Creates a new [`Scanner`].
+    ///
+    /// # Arguments
+    /// * `rules`: pairs of token kinds and the patterns that produce them, in priority order.
+    /// * `flags`: [`RegexecFlags`] to pass to [`tre_regnexec`](tre_regex_sys::tre_regnexec).
+    #[must_use]
+    pub const fn new(rules: Vec<(K, Regex)>, flags: RegexecFlags) -> Self {
+        Self { rules, flags }
+    }
+
+    /// Returns an iterator over the tokens in `input`.
+    ///
+    /// Each token is its kind, its text, and its byte range in `input`.
+    ///
+    /// # Arguments
+    /// * `input`: string to tokenize
+    ///
+    /// # Returns
+    /// A [`Tokens`] iterator yielding [`Result`]s of tokens.
+    ///
+    /// # Errors
+    /// If no rule matches at some position, the iterator yields a
+    /// [`BindingErrorCode::NO_TOKEN`] error and then stops. It also yields an error and stops if
+    /// an error is encountered during matching.
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use tre_regex::{RegcompFlags, RegexecFlags, Regex, Scanner};
+    ///
+    /// #[derive(Clone, Debug, PartialEq)]
+    /// enum Kind {
+    ///     Number,
+    ///     Operator,
+    ///     Space,
+    /// }
+    ///
+    /// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    /// let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    ///
+    /// let scanner = Scanner::new(
+    ///     vec![
+    ///         (Kind::Number, Regex::new("^[[:digit:]]+", regcomp_flags)?),
+    ///         (Kind::Operator, Regex::new("^[-+*/]", regcomp_flags)?),
+    ///         (Kind::Space, Regex::new("^[[:space:]]+", regcomp_flags)?),
+    ///     ],
+    ///     regexec_flags,
+    /// );
+    /// let tokens = scanner.tokens("12 + 3").collect::<Result<Vec<_>>>()?;
+    ///
+    /// assert_eq!(tokens[0], (Kind::Number, "12", 0..2));
+    /// assert_eq!(tokens[2], (Kind::Operator, "+", 3..4));
+    /// assert!(scanner.tokens("12 % 3").any(|token| token.is_err()));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`BindingErrorCode::NO_TOKEN`]: crate::BindingErrorCode::NO_TOKEN
+    #[must_use]
+    #[inline]
+    pub const fn tokens<'s, 'h>(&'s self, input: &'h str) -> Tokens<'s, 'h, K> {
+        Tokens {
+            scanner: self,
+            input,
+            pos: 0,
+            done: false,
+        }
+    }
+
+    /// Finds the first rule with a non-empty match at `pos`.
+    fn token_at<'h>(
+        &self,
+        input: &'h str,
+        pos: usize,
+    ) -> Result<Option<(K, &'h str, Range<usize>)>> {
+        for (kind, regex) in &self.rules {
+            let Some(caps) = regex.captures_at(input, pos, 1, self.flags)? else {
+                continue;
+            };
+            let Some((start, end)) = caps.pos(0) else {
+                continue;
+            };
+            if start == end {
+                continue;
+            }
+
+            return Ok(Some((kind.clone(), &input[start..end], start..end)));
+        }
+
+        Ok(None)
+    }
+}
+
+/// Iterator over tokens, returned by [`Scanner::tokens`].
+#[derive(Debug)]
+pub struct Tokens<'s, 'h, K> {
+    scanner: &'s Scanner<K>,
+    input: &'h str,
+    pos: usize,
+    done: bool,
+}
+
+impl<'h, K: Clone> Iterator for Tokens<'_, 'h, K> {
+    type Item = Result<(K, &'h str, Range<usize>)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done || self.pos >= self.input.len() {
+            return None;
+        }
+
+        match self.scanner.token_at(self.input, self.pos) {
+            Ok(Some(token)) => {
+                self.pos = token.2.end;
+                Some(Ok(token))
+            }
+            Ok(None) => {
+                self.done = true;
+                Some(Err(RegexError::new(
+                    ErrorKind::Binding(BindingErrorCode::NO_TOKEN),
+                    &format!("No token matches at offset {}", self.pos),
+                )))
+            }
+            Err(e) => {
+                self.done = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
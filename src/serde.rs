@@ -0,0 +1,95 @@
+use std::fmt;
+
+use ::serde_core::{
+    de::{Error as _, SeqAccess, Visitor},
+    ser::Error as _,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::flags::{RegFlags, RegcompFlags, RegexecFlags};
+
+impl Serialize for RegcompFlags {
+    /// Serializes the flags as a list of their names, such as `["EXTENDED", "ICASE"]`.
+    ///
+    /// # Errors
+    /// An error is returned if any set bits do not correspond to a known flag (see
+    /// [`RegcompFlags::unknown_bits`]), as they have no name.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_flags(serializer, self.iter_names(), self.unknown_bits())
+    }
+}
+
+impl<'de> Deserialize<'de> for RegcompFlags {
+    /// Deserializes the flags from a list of their names, such as `["EXTENDED", "ICASE"]`.
+    ///
+    /// # Errors
+    /// An error is returned if a name is not that of a flag.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let bits = deserializer.deserialize_seq(FlagsVisitor(Self::NAMES))?;
+        Ok(Self::new().add(bits))
+    }
+}
+
+impl Serialize for RegexecFlags {
+    /// Serializes the flags as a list of their names, such as `["NOTBOL", "NOTEOL"]`.
+    ///
+    /// # Errors
+    /// An error is returned if any set bits do not correspond to a known flag (see
+    /// [`RegexecFlags::unknown_bits`]), as they have no name.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_flags(serializer, self.iter_names(), self.unknown_bits())
+    }
+}
+
+impl<'de> Deserialize<'de> for RegexecFlags {
+    /// Deserializes the flags from a list of their names, such as `["NOTBOL", "NOTEOL"]`.
+    ///
+    /// # Errors
+    /// An error is returned if a name is not that of a flag.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let bits = deserializer.deserialize_seq(FlagsVisitor(Self::NAMES))?;
+        Ok(Self::new().add(bits))
+    }
+}
+
+/// Serializes flag names as a sequence, failing if there are bits without a name.
+fn serialize_flags<S: Serializer>(
+    serializer: S,
+    names: impl Iterator<Item = &'static str>,
+    unknown: RegFlags,
+) -> Result<S::Ok, S::Error> {
+    if unknown != 0 {
+        return Err(S::Error::custom(format!(
+            "cannot serialize unknown flag bits {unknown:#x}"
+        )));
+    }
+
+    serializer.collect_seq(names)
+}
+
+/// Collects a sequence of flag names from `table` into their bits.
+struct FlagsVisitor(&'static [(&'static str, RegFlags)]);
+
+impl<'de> Visitor<'de> for FlagsVisitor {
+    type Value = RegFlags;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a list of flag names")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut bits = 0;
+        while let Some(name) = seq.next_element::<String>()? {
+            let Some(&(_, flag)) = self.0.iter().find(|&&(known, _)| known == name) else {
+                let expected: Vec<_> = self.0.iter().map(|&(known, _)| known).collect();
+                return Err(A::Error::custom(format!(
+                    "unknown flag `{name}`, expected one of {}",
+                    expected.join(", ")
+                )));
+            };
+            bits |= flag;
+        }
+
+        Ok(bits)
+    }
+}
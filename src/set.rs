@@ -0,0 +1,143 @@
+use std::ops::Range;
+
+use crate::{
+    err::{BindingErrorCode, ErrorKind, RegexError, Result},
+    flags::{RegcompFlags, RegexecFlags},
+    Regex,
+};
+
+/// An ordered set of compiled patterns, for trying several patterns at once.
+///
+/// The patterns are kept as separate [`Regex`] objects, in the order they were given; the index
+/// of a pattern in the set is its position in that order.
+#[derive(Debug)]
+pub struct RegexSet {
+    regexes: Vec<Regex>,
+}
+
+impl RegexSet {
+    /// Compiles each of `patterns` into a new [`RegexSet`].
+    ///
+    /// # Arguments
+    /// * `patterns`: regular expressions to compile, in priority order
+    /// * `flags`: [`RegcompFlags`] to compile every pattern with
+    ///
+    /// # Returns
+    /// A new [`RegexSet`] holding one [`Regex`] per pattern.
+    ///
+    /// # Errors
+    /// If any pattern fails to compile, the error for the first one that failed is returned.
+    pub fn new(patterns: &[&str], flags: RegcompFlags) -> Result<Self> {
+        let regexes = patterns
+            .iter()
+            .map(|pattern| Regex::new(pattern, flags))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self { regexes })
+    }
+
+    /// Creates a new [`RegexSet`] from already compiled patterns, in priority order.
+    #[must_use]
+    pub const fn from_regexes(regexes: Vec<Regex>) -> Self {
+        Self { regexes }
+    }
+
+    /// Gets the number of patterns in the set.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.regexes.len()
+    }
+
+    /// Checks whether the set has no patterns.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.regexes.is_empty()
+    }
+
+    /// Gets the compiled patterns in the set, in priority order.
+    #[must_use]
+    pub fn regexes(&self) -> &[Regex] {
+        &self.regexes
+    }
+
+    /// Finds the pattern with the longest match anchored at `pos`.
+    ///
+    /// Every pattern is tried at `pos` as with [`Regex::captures_at`], and the one whose match
+    /// (group 0) is longest wins. Ties are broken by pattern order, so the earliest pattern wins.
+    /// This is the "maximal munch" rule used by most lexers; for instance, with the patterns
+    /// `if` and `[a-z]+`, `iffy` is matched by the second, but `if` by the first.
+    ///
+    /// # Arguments
+    /// * `string`: string to match against the patterns
+    /// * `pos`: byte offset to anchor the matches at
+    /// * `flags`: [`RegexecFlags`] to pass to [`tre_regnexec`](tre_regex_sys::tre_regnexec).
+    ///
+    /// # Returns
+    /// `None` if no pattern matches at `pos`, otherwise the index of the winning pattern and the
+    /// byte range of its match in `string`. An empty match is only returned if no pattern has a
+    /// non-empty match.
+    ///
+    /// # Errors
+    /// If an error is encountered during matching, it returns a [`RegexError`]. An error is also
+    /// returned if `pos` is out of bounds or not on a character boundary.
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use tre_regex::{RegcompFlags, RegexecFlags, RegexSet};
+    ///
+    /// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    /// let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    ///
+    /// let set = RegexSet::new(&["^if", "^[a-z]+", "^[[:space:]]+"], regcomp_flags)?;
+    /// assert_eq!(set.longest_match_at("if iffy", 0, regexec_flags)?, Some((0, 0..2)));
+    /// assert_eq!(set.longest_match_at("if iffy", 3, regexec_flags)?, Some((1, 3..7)));
+    /// assert_eq!(set.longest_match_at("if iffy", 2, regexec_flags)?, Some((2, 2..3)));
+    /// assert_eq!(set.longest_match_at("if 42", 3, regexec_flags)?, None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`RegexError`]: crate::RegexError
+    pub fn longest_match_at(
+        &self,
+        string: &str,
+        pos: usize,
+        flags: RegexecFlags,
+    ) -> Result<Option<(usize, Range<usize>)>> {
+        if pos > string.len() {
+            return Err(RegexError::new(
+                ErrorKind::Binding(BindingErrorCode::OUT_OF_BOUNDS),
+                &format!(
+                    "Offset {pos} is out of bounds for input of length {}",
+                    string.len()
+                ),
+            ));
+        }
+        if !string.is_char_boundary(pos) {
+            return Err(RegexError::new(
+                ErrorKind::Binding(BindingErrorCode::ENCODING),
+                &format!("UTF-8 encoding error: offset {pos} is not on a character boundary"),
+            ));
+        }
+
+        let mut best: Option<(usize, Range<usize>)> = None;
+        for (index, regex) in self.regexes.iter().enumerate() {
+            let Some(caps) = regex.captures_at(string, pos, 1, flags)? else {
+                continue;
+            };
+            let Some((start, end)) = caps.pos(0) else {
+                continue;
+            };
+
+            if best
+                .as_ref()
+                .map_or(true, |(_, range)| end - start > range.len())
+            {
+                best = Some((index, start..end));
+            }
+        }
+
+        Ok(best)
+    }
+}
@@ -0,0 +1,86 @@
+use ::smallvec::{smallvec, SmallVec};
+
+use crate::{
+    err::{BindingErrorCode, ErrorKind, RegexError, Result},
+    exec::match_range,
+    flags::RegexecFlags,
+    tre, ErrorInt, Regex,
+};
+
+/// Number of groups stored inline by [`RegMatchSmall`].
+pub const SMALL_MATCHES: usize = 8;
+
+pub type RegMatchSmall<'a> = SmallVec<[Option<&'a str>; SMALL_MATCHES]>;
+
+impl Regex {
+    /// Performs a regex search on the passed string, returning `nmatches` results in a
+    /// [`SmallVec`].
+    ///
+    /// This is [`Regex::regexec`] without the heap allocations for the common case: up to
+    /// [`SMALL_MATCHES`] groups are stored inline, both for the offsets from TRE and for the
+    /// results.
+    ///
+    /// # Arguments
+    /// * `string`: string to match against `compiled_reg`
+    /// * `nmatches`: number of matches to return
+    /// * `flags`: [`RegexecFlags`] to pass to [`tre_regnexec`](tre_regex_sys::tre_regnexec).
+    ///
+    /// # Returns
+    /// If no error was found, a [`SmallVec`] of [`Option`]s will be returned, with `None` for each
+    /// group which did not take part in the match.
+    ///
+    /// # Errors
+    /// If an error is encountered during matching, it returns a [`RegexError`]. Unlike with
+    /// [`Regex::regexec`], an error is also returned if any match is not on character boundaries.
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use tre_regex::{RegcompFlags, RegexecFlags, Regex};
+    ///
+    /// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    /// let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    ///
+    /// let compiled_reg = Regex::new("^([a-z]+)=([0-9]+)?$", regcomp_flags)?;
+    /// let matches = compiled_reg.regexec_small("key=", 3, regexec_flags)?;
+    ///
+    /// assert!(!matches.spilled());
+    /// assert_eq!(matches.as_slice(), &[Some("key="), Some("key"), None]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`RegexError`]: crate::RegexError
+    pub fn regexec_small<'a>(
+        &self,
+        string: &'a str,
+        nmatches: usize,
+        flags: RegexecFlags,
+    ) -> Result<RegMatchSmall<'a>> {
+        let mut match_vec: SmallVec<[tre::regmatch_t; SMALL_MATCHES]> =
+            smallvec![tre::regmatch_t { rm_so: 0, rm_eo: 0 }; nmatches];
+        if !self.exec_at(string.as_bytes(), 0, &mut match_vec, flags)? {
+            // Value cannot ever wrap around.
+            #[allow(clippy::cast_possible_wrap)]
+            return Err(self.exec_error(tre::reg_errcode_t::REG_NOMATCH.0 as ErrorInt));
+        }
+
+        match_vec
+            .into_iter()
+            .map(|pmatch| {
+                let Some((start, end)) = match_range(pmatch, 0) else {
+                    return Ok(None);
+                };
+                string.get(start..end).map(Some).ok_or_else(|| {
+                    RegexError::new(
+                        ErrorKind::Binding(BindingErrorCode::ENCODING),
+                        &format!(
+                            "UTF-8 encoding error: match {start}..{end} is not on a character boundary"
+                        ),
+                    )
+                })
+            })
+            .collect()
+    }
+}
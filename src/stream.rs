@@ -0,0 +1,119 @@
+use std::ops::Range;
+
+use crate::{
+    err::{BindingErrorCode, ErrorKind, RegexError, Result},
+    flags::RegexecFlags,
+    Regex,
+};
+
+/// A match found by [`Regex::find_stream`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct StreamMatch {
+    /// Byte offsets of the match in the buffer
+    pub range: Range<usize>,
+
+    /// Whether the match touches the end of a buffer that more input may follow, so that it may
+    /// still change once that input arrives
+    pub provisional: bool,
+}
+
+impl Regex {
+    /// Finds the first match in a buffer holding a prefix of a stream, starting at `start`.
+    ///
+    /// When `more_input` is `true`, [`RegexecFlags::NOTEOL`] is added so that `$` cannot match at
+    /// the end of `data`, and a match reaching the end of `data` is marked as
+    /// [provisional](StreamMatch::provisional): more input could extend it (for example, `[0-9]+`
+    /// matching `12` when the next chunk begins with `3`). When `more_input` is `false`, `data` is
+    /// treated as the final part of the stream and no match is provisional.
+    ///
+    /// The text before `start` is taken into account, so that `^` and `\<` only match at `start`
+    /// if they would in a search over all of `data`.
+    ///
+    /// # Arguments
+    /// * `data`: buffered input to match against `compiled_reg`
+    /// * `start`: byte offset in `data` to start searching at
+    /// * `more_input`: whether more input may follow `data`
+    /// * `flags`: [`RegexecFlags`] to pass to [`tre_regnexec`](tre_regex_sys::tre_regnexec).
+    ///
+    /// # Returns
+    /// `None` if there was no match, otherwise a [`StreamMatch`].
+    ///
+    /// # Errors
+    /// If an error is encountered during matching, it returns a [`RegexError`]. An error is also
+    /// returned if `start` is past the end of `data`.
+    ///
+    /// # Caveats
+    /// The recommended pattern is to commit only matches that are not provisional, and to keep the
+    /// buffer from the start of a provisional match onwards, searching again once more input has
+    /// been appended. When no match is found, the tail of the buffer should be kept in the same
+    /// way, since a match may begin there.
+    ///
+    /// A match that does not touch the end of `data` is final only if no longer match could begin
+    /// at or before it. With alternations such as `ab|abcd`, or with patterns such as `x.*y|b`,
+    /// later input can still produce a longer or earlier match. If the length of a match is
+    /// bounded, keep at least that many bytes of lookahead before committing a match.
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use tre_regex::{RegcompFlags, RegexecFlags, Regex};
+    ///
+    /// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    /// let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    ///
+    /// let compiled_reg = Regex::new("[[:digit:]]+", regcomp_flags)?;
+    ///
+    /// let mut buffer = b"a 1 22".to_vec();
+    /// let mut committed = Vec::new();
+    /// let mut start = 0;
+    /// while let Some(matched) = compiled_reg.find_stream(&buffer, start, true, regexec_flags)? {
+    ///     if matched.provisional {
+    ///         break;
+    ///     }
+    ///     committed.push(buffer[matched.range.clone()].to_vec());
+    ///     start = matched.range.end;
+    /// }
+    /// assert_eq!(committed, vec![b"1".to_vec()]);
+    ///
+    /// // "22" might have continued; it is only committed once more input arrives.
+    /// buffer.extend_from_slice(b"3 end");
+    /// let matched = compiled_reg.find_stream(&buffer, start, true, regexec_flags)?;
+    /// let matched = matched.expect("match");
+    /// assert!(!matched.provisional);
+    /// assert_eq!(&buffer[matched.range], b"223");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn find_stream(
+        &self,
+        data: &[u8],
+        start: usize,
+        more_input: bool,
+        flags: RegexecFlags,
+    ) -> Result<Option<StreamMatch>> {
+        if start > data.len() {
+            return Err(RegexError::new(
+                ErrorKind::Binding(BindingErrorCode::OUT_OF_BOUNDS),
+                &format!(
+                    "Offset {start} is out of bounds for input of length {}",
+                    data.len()
+                ),
+            ));
+        }
+
+        let flags = if more_input {
+            flags.add(RegexecFlags::NOTEOL)
+        } else {
+            flags
+        };
+        let Some((so, eo)) = self.find_at_bytes(data, start, flags)? else {
+            return Ok(None);
+        };
+
+        Ok(Some(StreamMatch {
+            range: so..eo,
+            provisional: more_input && eo == data.len(),
+        }))
+    }
+}
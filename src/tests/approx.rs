@@ -1,4 +1,10 @@
-use crate::{RegApproxParams, RegcompFlags, Regex, RegexecFlags};
+use std::cmp::Ordering;
+use std::ffi::c_int;
+
+use crate::{
+    tre, ApproxMatcher, EditStats, RegApproxMatch, RegApproxMatchBytes, RegApproxParams,
+    RegcompFlags, Regex, RegexecFlags,
+};
 
 #[test]
 fn test_regaexec() {
@@ -84,3 +90,314 @@ fn test_regaexec_bytes() {
     assert!(matched_2.is_some());
     assert_eq!(matched_2.unwrap().as_ref(), b"warld");
 }
+
+#[test]
+fn regaparams_defaults_work() {
+    let params = RegApproxParams::new();
+    assert_eq!(params.get().max_cost, 0);
+    assert_eq!(params.get().cost_ins, 0);
+
+    let params = RegApproxParams::tre_defaults();
+    assert_eq!(params.get().cost_ins, 1);
+    assert_eq!(params.get().cost_del, 1);
+    assert_eq!(params.get().cost_subst, 1);
+    assert_eq!(params.get().max_cost, c_int::MAX);
+    assert_eq!(params.get().max_ins, c_int::MAX);
+    assert_eq!(params.get().max_del, c_int::MAX);
+    assert_eq!(params.get().max_subst, c_int::MAX);
+    assert_eq!(params.get().max_err, c_int::MAX);
+}
+
+#[test]
+fn edit_stats_works() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regaexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    let regaexec_params = RegApproxParams::new()
+        .cost_ins(1)
+        .cost_del(1)
+        .cost_subst(1)
+        .max_cost(2)
+        .max_del(2)
+        .max_ins(2)
+        .max_subst(2)
+        .max_err(2);
+
+    let compiled_reg = Regex::new("^hello$", regcomp_flags).expect("Regex::new");
+    let result = compiled_reg
+        .regaexec("hullo", &regaexec_params, 1, regaexec_flags)
+        .expect("regaexec");
+
+    let stats = result.edit_stats();
+    assert_eq!(
+        stats,
+        EditStats {
+            cost: result.cost(),
+            num_ins: result.num_ins(),
+            num_del: result.num_del(),
+            num_subst: result.num_subst(),
+            total_edits: result.num_ins() + result.num_del() + result.num_subst(),
+        }
+    );
+    assert_eq!(stats.cost, 1);
+    assert_eq!(stats.num_subst, 1);
+    assert_eq!(stats.total_edits, 1);
+
+    // The total saturates instead of overflowing
+    let amatch = tre::regamatch_t {
+        num_ins: c_int::MAX,
+        num_del: 1,
+        num_subst: 1,
+        ..Default::default()
+    };
+    let result = RegApproxMatchBytes::new(b"".as_slice(), Vec::new(), amatch);
+    assert_eq!(result.edit_stats().total_edits, c_int::MAX);
+}
+
+#[test]
+fn approx_match_into_iter_works() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regaexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    let regaexec_params = RegApproxParams::new()
+        .cost_subst(1)
+        .max_cost(1)
+        .max_subst(1)
+        .max_err(1);
+
+    let compiled_reg = Regex::new("^(h)(e)?llo$", regcomp_flags).expect("Regex::new");
+    let result = compiled_reg
+        .regaexec("hullo", &regaexec_params, 3, regaexec_flags)
+        .expect("regaexec");
+
+    let mut count = 0;
+    for matched in &result {
+        assert_eq!(matched.is_some(), result.get_matches()[count].is_some());
+        count += 1;
+    }
+    assert_eq!(count, 3);
+    assert_eq!(result.iter().count(), 3);
+}
+
+#[test]
+fn approx_matcher_works() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regaexec_params = RegApproxParams::new()
+        .cost_ins(1)
+        .cost_del(1)
+        .cost_subst(1)
+        .max_cost(1)
+        .max_err(1);
+
+    let compiled_reg = Regex::new("^(hello)$", regcomp_flags).expect("Regex::new");
+    let matcher = ApproxMatcher::new(&compiled_reg, regaexec_params)
+        .flags(RegexecFlags::new().add(RegexecFlags::NONE))
+        .nmatches(2);
+
+    let result = matcher.exec("hullo").expect("exec");
+    assert_eq!(result.cost(), 1);
+    assert_eq!(result.get_matches().len(), 2);
+    assert_eq!(
+        *result.get_matches()[1].as_ref().unwrap().as_ref().unwrap(),
+        "hullo"
+    );
+
+    let result = matcher.exec_bytes(b"hello").expect("exec_bytes");
+    assert_eq!(result.cost(), 0);
+
+    assert!(matcher.exec("jello!").is_err());
+}
+
+#[test]
+fn regaexec_ordering() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regaexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    let regaexec_params = RegApproxParams::new().cost_subst(1).max_cost(2).max_err(2);
+
+    let compiled_reg = Regex::new("hello", regcomp_flags).expect("Regex::new");
+    let mut results: Vec<_> = ["xxhxllx", "hullo", "xhello", "hello"]
+        .into_iter()
+        .map(|s| {
+            compiled_reg
+                .regaexec(s, &regaexec_params, 1, regaexec_flags)
+                .expect("regaexec")
+        })
+        .collect();
+    results.sort_by(RegApproxMatch::cmp_by_cost);
+
+    let keys: Vec<_> = results
+        .iter()
+        .map(|result| (result.cost(), result.start()))
+        .collect();
+    assert_eq!(
+        keys,
+        vec![(0, Some(0)), (0, Some(1)), (1, Some(0)), (2, Some(2))]
+    );
+    assert_eq!(results[0].cmp_by_cost(&results[1]), Ordering::Less);
+}
+
+#[test]
+fn regaexec_best_works() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regaexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+
+    let compiled_reg = Regex::new("^(hello) (world)$", regcomp_flags).expect("Regex::new");
+    let (result, stats) = compiled_reg
+        .regaexec_best("jelly wormd", regaexec_flags)
+        .expect("regaexec_best");
+    assert_eq!(stats, result.edit_stats());
+    assert_eq!(stats.cost, 3);
+    assert_eq!(result.get_matches().len(), 3);
+
+    let (_, stats) = compiled_reg
+        .regaexec_best("hello world", regaexec_flags)
+        .expect("regaexec_best");
+    assert_eq!(stats.cost, 0);
+}
+
+#[test]
+fn regaexec_auto_returns_all_groups() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regaexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    let regaexec_params = RegApproxParams::new()
+        .cost_ins(1)
+        .cost_del(1)
+        .cost_subst(1)
+        .max_cost(2)
+        .max_err(2);
+
+    let compiled_reg = Regex::new("^(a+)-((b+)-(c+))$", regcomp_flags).expect("Regex::new");
+    assert_eq!(compiled_reg.captures_len().expect("captures_len"), 5);
+
+    let result = compiled_reg
+        .regaexec_auto("aa-bbb-cx", &regaexec_params, regaexec_flags)
+        .expect("regaexec_auto");
+    let matches = result.get_matches();
+    assert_eq!(matches.len(), 5);
+    assert!(matches.iter().all(Option::is_some));
+    assert_eq!(matches[1].as_ref().unwrap().as_ref().unwrap(), "aa");
+    assert_eq!(matches[3].as_ref().unwrap().as_ref().unwrap(), "bbb");
+}
+
+#[test]
+fn regaexec_trailing_edits() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regaexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    let regaexec_params = RegApproxParams::new()
+        .cost_ins(1)
+        .cost_del(1)
+        .cost_subst(1)
+        .max_cost(1)
+        .max_ins(1)
+        .max_del(1)
+        .max_subst(1)
+        .max_err(1);
+
+    // Unanchored: the trailing space is left out of the match for free
+    let compiled_reg = Regex::new("hello", regcomp_flags).expect("Regex::new");
+    let result = compiled_reg
+        .regaexec("hello ", &regaexec_params, 1, regaexec_flags)
+        .expect("regaexec");
+    assert_eq!(result.cost(), 0);
+    assert_eq!(
+        *result.get_matches()[0].as_ref().unwrap().as_ref().unwrap(),
+        "hello"
+    );
+
+    // Anchored: the trailing space is an insertion
+    let compiled_reg = Regex::new("^hello$", regcomp_flags).expect("Regex::new");
+    let result = compiled_reg
+        .regaexec("hello ", &regaexec_params, 1, regaexec_flags)
+        .expect("regaexec");
+    assert_eq!(result.cost(), 1);
+    assert_eq!(result.num_ins(), 1);
+    assert_eq!(
+        *result.get_matches()[0].as_ref().unwrap().as_ref().unwrap(),
+        "hello "
+    );
+
+    // ...so it does not match if no insertions are allowed
+    assert!(compiled_reg
+        .regaexec("hello ", &regaexec_params.max_ins(0), 1, regaexec_flags)
+        .is_err());
+
+    // A missing trailing character is a deletion, anchored or not
+    for pattern in ["hello", "^hello$"] {
+        let compiled_reg = Regex::new(pattern, regcomp_flags).expect("Regex::new");
+        let result = compiled_reg
+            .regaexec("hell", &regaexec_params, 1, regaexec_flags)
+            .expect("regaexec");
+        assert_eq!(result.cost(), 1);
+        assert_eq!(result.num_del(), 1);
+
+        assert!(compiled_reg
+            .regaexec("hell", &regaexec_params.max_del(0), 1, regaexec_flags)
+            .is_err());
+    }
+}
+
+#[test]
+fn effective_params_echoes_input() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regaexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    let regaexec_params = RegApproxParams::new()
+        .cost_subst(1)
+        .max_cost(2)
+        .max_subst(2)
+        .max_err(2);
+
+    let compiled_reg = Regex::new("^hello$", regcomp_flags).expect("Regex::new");
+    let result = compiled_reg
+        .regaexec("hxllx", &regaexec_params, 1, regaexec_flags)
+        .expect("regaexec");
+    let params = result.effective_params().get();
+    assert_eq!(params.cost_subst, 1);
+    assert_eq!(params.max_cost, 2);
+    assert_eq!(params.max_subst, 2);
+    assert_eq!(params.max_ins, 0);
+    assert_eq!(result.num_subst(), 2);
+
+    let result = compiled_reg
+        .regaexec_bytes(b"hullo", &regaexec_params, 1, regaexec_flags)
+        .expect("regaexec_bytes");
+    assert_eq!(result.effective_params().get().max_err, 2);
+}
+
+#[test]
+fn describe_edits_works() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regaexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    let regaexec_params = RegApproxParams::tre_defaults()
+        .cost_ins(2)
+        .cost_del(2)
+        .max_cost(3);
+
+    let compiled_reg = Regex::new("^necessary$", regcomp_flags).expect("Regex::new");
+    for (string, expected) in [
+        ("necessary", "exact match"),
+        ("necesary", "1 deletion (cost 2)"),
+        ("neccessary", "1 insertion (cost 2)"),
+        ("nexessarx", "2 substitutions (cost 2)"),
+        ("nxcesssary", "1 insertion, 1 substitution (cost 3)"),
+    ] {
+        let result = compiled_reg
+            .regaexec(string, &regaexec_params, 1, regaexec_flags)
+            .expect("regaexec");
+        assert_eq!(result.describe_edits(), expected, "{string}");
+    }
+
+    // Free edits are still described
+    let regaexec_params = regaexec_params.cost_subst(0);
+    let result = compiled_reg
+        .regaexec("nexessary", &regaexec_params, 1, regaexec_flags)
+        .expect("regaexec");
+    assert_eq!(result.describe_edits(), "1 substitution");
+}
+
+#[test]
+fn has_approx_works() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let compiled_reg = Regex::new("(abc){~1}", regcomp_flags).expect("Regex::new");
+    assert!(compiled_reg.has_approx().expect("has_approx"));
+
+    let compiled_reg = Regex::new("abc", regcomp_flags).expect("Regex::new");
+    assert!(!compiled_reg.has_approx().expect("has_approx"));
+}
@@ -0,0 +1,19 @@
+use bstr::ByteSlice;
+
+use crate::{regexec_bstr, RegcompFlags, Regex, RegexecFlags};
+
+#[test]
+fn regexec_bstr_works() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+
+    let compiled_reg = Regex::new("^([[:alpha:]]+) (.*)$", regcomp_flags).expect("Regex::new");
+    let matches = compiled_reg
+        .regexec_bstr(b"hello w\xffrld".as_bstr(), 3, regexec_flags)
+        .expect("regexec_bstr");
+    assert_eq!(matches[0].as_deref(), Some(b"hello w\xffrld".as_bstr()));
+    assert_eq!(matches[1].as_deref(), Some(b"hello".as_bstr()));
+    assert_eq!(matches[2].as_deref(), Some(b"w\xffrld".as_bstr()));
+
+    assert!(regexec_bstr(&compiled_reg, b"12".as_bstr(), 1, regexec_flags).is_err());
+}
@@ -0,0 +1,41 @@
+use crate::{RegcompFlags, RegexBuilder, RegexecFlags};
+
+#[test]
+fn regex_builder_works() {
+    let builder = RegexBuilder::new()
+        .extended(true)
+        .case_insensitive(true)
+        .newline(true)
+        .ungreedy(true);
+    assert_eq!(
+        builder.flags().get(),
+        RegcompFlags::EXTENDED
+            | RegcompFlags::ICASE
+            | RegcompFlags::NEWLINE
+            | RegcompFlags::UNGREEDY
+    );
+    assert_eq!(
+        builder.ungreedy(false).newline(false).flags().get(),
+        RegcompFlags::EXTENDED | RegcompFlags::ICASE
+    );
+    assert!(RegexBuilder::default().flags().is_empty());
+
+    let regexec_flags = RegexecFlags::new();
+    let compiled_reg = builder.build("^(a+)$").expect("build");
+    assert!(compiled_reg
+        .is_match("foo\nAAA", regexec_flags)
+        .expect("is_match"));
+
+    let compiled_reg = RegexBuilder::new()
+        .literal(true)
+        .build("a+")
+        .expect("build");
+    assert!(compiled_reg
+        .is_match("xa+", regexec_flags)
+        .expect("is_match"));
+    assert!(!compiled_reg
+        .is_match("aa", regexec_flags)
+        .expect("is_match"));
+
+    assert!(RegexBuilder::new().extended(true).build("(a").is_err());
+}
@@ -0,0 +1,47 @@
+use crate::{ByteSet, RegcompFlags, Regex};
+
+#[test]
+fn byte_set_works() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+
+    let set = ByteSet::new("[],a-c-]", regcomp_flags).expect("ByteSet::new");
+    for b in *b"],abc-" {
+        assert!(set.contains(b));
+    }
+    assert!(!set.contains(b'd'));
+    assert_eq!(set.find(b"xyzb"), Some(3));
+    assert_eq!(set.find(b"xyz"), None);
+
+    let set = ByteSet::new("[^a-z]", regcomp_flags).expect("ByteSet::new");
+    assert!(!set.contains(b'q'));
+    assert!(set.contains(b'Q'));
+    assert!(set.contains(b'\n'));
+    assert!(set.contains(0xff));
+
+    let newline_flags = regcomp_flags.add(RegcompFlags::NEWLINE);
+    let set = ByteSet::new("[^a-z]", newline_flags).expect("ByteSet::new");
+    assert!(!set.contains(b'\n'));
+
+    for pattern in [
+        "a",
+        "[a",
+        "[]",
+        "[^]",
+        "[a]b]",
+        "[[:alpha:]]",
+        "[z-a]",
+        "[é]",
+        "[a]*",
+        "[!-]]",
+    ] {
+        assert!(ByteSet::new(pattern, regcomp_flags).is_none(), "{pattern}");
+    }
+    assert!(ByteSet::new("[a]", regcomp_flags.add(RegcompFlags::LITERAL)).is_none());
+
+    let compiled_reg =
+        Regex::new("[a-f]", regcomp_flags.add(RegcompFlags::ICASE)).expect("Regex::new");
+    let set = compiled_reg.byte_set().expect("byte_set");
+    assert!(set.contains(b'c'));
+    assert!(set.contains(b'C'));
+    assert!(!set.contains(b'G'));
+}
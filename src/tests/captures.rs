@@ -0,0 +1,296 @@
+use crate::{BindingErrorCode, Captures, ErrorKind, RegcompFlags, Regex, RegexecFlags};
+
+#[test]
+fn captures_at_works() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    let compiled_reg =
+        Regex::new("([[:alpha:]]+)(-([[:digit:]]+))?", regcomp_flags).expect("Regex::new");
+
+    let caps = compiled_reg
+        .captures_at("12 abc-34", 3, 4, regexec_flags)
+        .expect("captures_at")
+        .expect("no match");
+    assert_eq!(caps.len(), 4);
+    assert_eq!(caps.get(0), Some("abc-34"));
+    assert_eq!(caps.pos(0), Some((3, 9)));
+    assert_eq!(caps.get(1), Some("abc"));
+    assert_eq!(caps.get(3), Some("34"));
+
+    let caps = compiled_reg
+        .captures_at("12 abc", 3, 4, regexec_flags)
+        .expect("captures_at")
+        .expect("no match");
+    assert_eq!(caps.get(1), Some("abc"));
+    assert_eq!(caps.get(2), None);
+
+    // The match is further along than the cursor
+    assert!(compiled_reg
+        .captures_at("12 abc", 2, 4, regexec_flags)
+        .expect("captures_at")
+        .is_none());
+}
+
+#[test]
+fn captures_at_anchor_works() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    let compiled_reg = Regex::new("^b", regcomp_flags).expect("Regex::new");
+    assert!(compiled_reg
+        .captures_at("abc", 1, 1, regexec_flags)
+        .expect("captures_at")
+        .is_some());
+    assert!(compiled_reg
+        .captures_at("abc", 1, 1, regexec_flags.add(RegexecFlags::NOTBOL))
+        .expect("captures_at")
+        .is_none());
+    let err = compiled_reg
+        .captures_at("abc", 4, 1, regexec_flags)
+        .expect_err("captures_at should fail");
+    assert_eq!(err.kind, ErrorKind::Binding(BindingErrorCode::OUT_OF_BOUNDS));
+    let err = compiled_reg
+        .captures_at("\u{e9}", 1, 1, regexec_flags)
+        .expect_err("captures_at should fail");
+    assert_eq!(err.kind, ErrorKind::Binding(BindingErrorCode::ENCODING));
+}
+
+#[test]
+fn captures_read_works() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    let compiled_reg =
+        Regex::new("^([[:alpha:]]+)(=([[:digit:]]+))?$", regcomp_flags).expect("Regex::new");
+
+    let mut caps = Captures::with_len(4);
+    assert_eq!(caps.len(), 4);
+    assert_eq!(caps.get(0), None);
+
+    assert!(compiled_reg
+        .captures_read(&mut caps, "key=42", regexec_flags)
+        .expect("captures_read"));
+    assert_eq!(caps.get(1), Some("key"));
+    assert_eq!(caps.get(3), Some("42"));
+
+    assert!(compiled_reg
+        .captures_read(&mut caps, "flag", regexec_flags)
+        .expect("captures_read"));
+    assert_eq!(caps.len(), 4);
+    assert_eq!(caps.get(1), Some("flag"));
+    assert_eq!(caps.get(3), None);
+
+    assert!(!compiled_reg
+        .captures_read(&mut caps, "1=2", regexec_flags)
+        .expect("captures_read"));
+    assert_eq!(caps.len(), 4);
+    assert_eq!(caps.get(0), None);
+}
+
+#[test]
+fn captures_read_error_clears_buffer() {
+    let regcomp_flags = RegcompFlags::new()
+        .add(RegcompFlags::EXTENDED)
+        .add(RegcompFlags::USEBYTES);
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    let compiled_reg = Regex::new("^(.)", regcomp_flags).expect("Regex::new");
+
+    let mut caps = Captures::with_len(2);
+    assert!(compiled_reg
+        .captures_read(&mut caps, "ab", regexec_flags)
+        .expect("captures_read"));
+    assert_eq!(caps.get(1), Some("a"));
+
+    // The match splits the character, so nothing from it may be left behind
+    assert!(compiled_reg
+        .captures_read(&mut caps, "\u{e9}", regexec_flags)
+        .is_err());
+    assert_eq!(caps.len(), 2);
+    assert_eq!(caps.get(0), None);
+    assert_eq!(caps.get(1), None);
+}
+
+#[test]
+fn captures_read_reuses_captures_at() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    let compiled_reg =
+        Regex::new("([[:alpha:]]+)=([[:digit:]]+)", regcomp_flags).expect("Regex::new");
+
+    let mut caps = compiled_reg
+        .captures_at("a=1", 0, 3, regexec_flags)
+        .expect("captures_at")
+        .expect("no match");
+    assert_eq!(caps.len(), 3);
+
+    assert!(compiled_reg
+        .captures_read(&mut caps, "b=22", regexec_flags)
+        .expect("captures_read"));
+    assert_eq!(caps.len(), 3);
+    assert_eq!(caps.get(1), Some("b"));
+    assert_eq!(caps.get(2), Some("22"));
+
+    let mut buf = caps.reset();
+    assert_eq!(buf.len(), 3);
+    assert_eq!(buf.get(0), None);
+    {
+        let line = String::from("c=333");
+        assert!(compiled_reg
+            .captures_read(&mut buf, &line, regexec_flags)
+            .expect("captures_read"));
+        assert_eq!(buf.get(2), Some("333"));
+        buf = buf.reset();
+    }
+    assert_eq!(buf.get(2), None);
+}
+
+#[test]
+fn captures_array_works() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    let compiled_reg =
+        Regex::new("^([[:alpha:]]+)(=([[:digit:]]+))?$", regcomp_flags).expect("Regex::new");
+
+    let [key, _, value] = compiled_reg
+        .captures_array("key=42", regexec_flags)
+        .expect("captures_array")
+        .expect("no match");
+    assert_eq!(key, Some("key"));
+    assert_eq!(value, Some("42"));
+
+    let [key] = compiled_reg
+        .captures_array("flag", regexec_flags)
+        .expect("captures_array")
+        .expect("no match");
+    assert_eq!(key, Some("flag"));
+
+    assert!(compiled_reg
+        .captures_array::<2>("1=2", regexec_flags)
+        .expect("captures_array")
+        .is_none());
+
+    let err = compiled_reg
+        .captures_array::<4>("key=42", regexec_flags)
+        .expect_err("captures_array should fail");
+    assert_eq!(err.kind, ErrorKind::Binding(BindingErrorCode::GROUP_COUNT));
+}
+
+#[test]
+fn overall_and_subgroups_work() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    let compiled_reg =
+        Regex::new("([[:alpha:]]+)(-([[:digit:]]+))?", regcomp_flags).expect("Regex::new");
+
+    let caps = compiled_reg
+        .captures_at("abc", 0, 4, regexec_flags)
+        .expect("captures_at")
+        .expect("no match");
+    assert_eq!(caps.overall(), Some("abc"));
+    assert_eq!(
+        caps.subgroups().collect::<Vec<_>>(),
+        vec![Some("abc"), None, None]
+    );
+
+    let caps = Captures::with_len(1);
+    assert_eq!(caps.overall(), None);
+    assert_eq!(caps.subgroups().count(), 0);
+}
+
+#[test]
+fn from_captures_works() {
+    #[derive(Debug, PartialEq)]
+    struct Entry {
+        name: String,
+        count: u32,
+    }
+
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    let compiled_reg =
+        Regex::new("([[:alpha:]]+)=([^ ]+)( .*)?", regcomp_flags).expect("Regex::new");
+
+    let caps = compiled_reg
+        .captures_at("apples=12", 0, 4, regexec_flags)
+        .expect("captures_at")
+        .expect("no match");
+    let entry = crate::from_captures!(caps, Entry { name: 1, count: 2 }).expect("from_captures");
+    assert_eq!(
+        entry,
+        Entry {
+            name: "apples".to_string(),
+            count: 12
+        }
+    );
+
+    let caps = compiled_reg
+        .captures_at("apples=many", 0, 4, regexec_flags)
+        .expect("captures_at")
+        .expect("no match");
+    let err = crate::from_captures!(caps, Entry { name: 1, count: 2 })
+        .expect_err("from_captures should fail");
+    assert_eq!(
+        err.kind,
+        ErrorKind::Binding(BindingErrorCode::CAPTURE_PARSE)
+    );
+
+    // Group 3 did not participate
+    let err = caps.parse::<String>(3).expect_err("parse should fail");
+    assert_eq!(
+        err.kind,
+        ErrorKind::Binding(BindingErrorCode::CAPTURE_PARSE)
+    );
+}
+
+#[test]
+fn into_owned_present_works() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    let compiled_reg =
+        Regex::new("([[:alpha:]]+)(-([[:digit:]]+))?(!)?", regcomp_flags).expect("Regex::new");
+
+    let caps = compiled_reg
+        .captures_at("abc-12", 0, 5, regexec_flags)
+        .expect("captures_at")
+        .expect("no match");
+    assert_eq!(
+        caps.into_owned_present(),
+        vec!["abc-12", "abc", "-12", "12"]
+    );
+
+    assert!(Captures::with_len(3).into_owned_present().is_empty());
+}
+
+#[test]
+fn captures_at_bytes_works() {
+    let regcomp_flags = RegcompFlags::new()
+        .add(RegcompFlags::EXTENDED)
+        .add(RegcompFlags::USEBYTES);
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    let compiled_reg = Regex::new("\x01([^\x02]*)\x02(x)?", regcomp_flags).expect("Regex::new");
+
+    let data = b"\xff\x01\xfe\xfd\x02\x01\x02";
+    let groups = compiled_reg
+        .captures_at_bytes(data, 1, 3, regexec_flags)
+        .expect("captures_at_bytes")
+        .expect("no match");
+    assert_eq!(groups.len(), 3);
+    assert_eq!(groups[0], Some(&b"\x01\xfe\xfd\x02"[..]));
+    assert_eq!(groups[1], Some(&b"\xfe\xfd"[..]));
+    assert_eq!(groups[2], None);
+
+    let groups = compiled_reg
+        .captures_at_bytes(data, 5, 2, regexec_flags)
+        .expect("captures_at_bytes")
+        .expect("no match");
+    assert_eq!(groups[1], Some(&b""[..]));
+
+    // The match is further along than the cursor
+    assert!(compiled_reg
+        .captures_at_bytes(data, 0, 2, regexec_flags)
+        .expect("captures_at_bytes")
+        .is_none());
+
+    let err = compiled_reg
+        .captures_at_bytes(data, data.len() + 1, 2, regexec_flags)
+        .expect_err("captures_at_bytes should fail");
+    assert_eq!(err.kind, ErrorKind::Binding(BindingErrorCode::OUT_OF_BOUNDS));
+}
@@ -0,0 +1,47 @@
+use crate::{RegcompFlags, Regex, RegexecFlags, Result};
+
+#[test]
+fn find_iter_chars_works() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    let compiled_reg = Regex::new("[[:digit:]]+", regcomp_flags).expect("Regex::new");
+
+    let haystack = "ナ1 22 ナナ333 4444 5";
+    let expected = compiled_reg
+        .find_iter(haystack, regexec_flags)
+        .map(|found| {
+            found.map(|(start, end)| {
+                haystack[..start].chars().count()..haystack[..end].chars().count()
+            })
+        })
+        .collect::<Result<Vec<_>>>()
+        .expect("find_iter");
+
+    for chunk in [0, 1, 2, 3, 5, 100] {
+        let ranges = compiled_reg
+            .find_iter_chars(haystack.chars(), chunk, regexec_flags)
+            .collect::<Result<Vec<_>>>()
+            .expect("find_iter_chars");
+        assert_eq!(ranges, expected, "chunk {chunk}");
+    }
+}
+
+#[test]
+fn find_iter_chars_anchors() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+
+    let compiled_reg = Regex::new("^a", regcomp_flags).expect("Regex::new");
+    let ranges = compiled_reg
+        .find_iter_chars("aaaaaa".chars(), 1, regexec_flags)
+        .collect::<Result<Vec<_>>>()
+        .expect("find_iter_chars");
+    assert_eq!(ranges, vec![0..1]);
+
+    let compiled_reg = Regex::new("b*", regcomp_flags).expect("Regex::new");
+    let ranges = compiled_reg
+        .find_iter_chars("abb".chars(), 1, regexec_flags)
+        .collect::<Result<Vec<_>>>()
+        .expect("find_iter_chars");
+    assert_eq!(ranges, vec![0..0, 1..3]);
+}
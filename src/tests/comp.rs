@@ -1,4 +1,7 @@
-use crate::{regcomp, regcomp_bytes, RegcompFlags};
+use crate::{
+    compile_all, count_groups, regcomp, regcomp_bytes, tre, BindingErrorCode, ErrorKind,
+    RegcompFlags, Regex, RegexecFlags, Result,
+};
 
 #[test]
 fn regcomp_flags_works() {
@@ -56,3 +59,404 @@ fn regcomp_bytes_works() {
         "regcomp"
     );
 }
+
+#[test]
+fn recompile_works() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    let mut compiled_reg = Regex::new("hello", regcomp_flags).expect("Regex::new");
+
+    compiled_reg
+        .recompile("wor(ld)", regcomp_flags)
+        .expect("recompile");
+    let result = compiled_reg
+        .regexec("hello world", 2, regexec_flags)
+        .expect("regexec");
+    assert_eq!(*result[0].as_ref().unwrap().as_ref().unwrap(), "world");
+    assert_eq!(*result[1].as_ref().unwrap().as_ref().unwrap(), "ld");
+
+    // A failed recompile leaves the old regex in place
+    assert!(compiled_reg.recompile("[a", regcomp_flags).is_err());
+    let result = compiled_reg
+        .regexec("hello world", 1, regexec_flags)
+        .expect("regexec");
+    assert_eq!(*result[0].as_ref().unwrap().as_ref().unwrap(), "world");
+}
+
+#[test]
+fn pattern_accessors_work() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let compiled_reg = Regex::new("^(hello).*(world)$", regcomp_flags).expect("Regex::new");
+    assert_eq!(compiled_reg.as_str(), Some("^(hello).*(world)$"));
+    assert_eq!(compiled_reg.as_bytes(), Some(&b"^(hello).*(world)$"[..]));
+
+    let compiled_reg = Regex::new_bytes(b"caf\xe9", regcomp_flags).expect("Regex::new_bytes");
+    assert_eq!(compiled_reg.as_str(), None);
+    assert_eq!(compiled_reg.as_bytes(), Some(&b"caf\xe9"[..]));
+}
+
+#[test]
+fn try_clone_works() {
+    let regcomp_flags = RegcompFlags::new()
+        .add(RegcompFlags::EXTENDED)
+        .add(RegcompFlags::ICASE);
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    let compiled_reg = Regex::new("^(hello).*(world)$", regcomp_flags).expect("Regex::new");
+    let mut copy = compiled_reg.try_clone().expect("try_clone");
+    drop(compiled_reg);
+
+    // Flags are carried over too
+    let result = copy
+        .regexec("HELLO WORLD", 3, regexec_flags)
+        .expect("regexec");
+    assert_eq!(*result[2].as_ref().unwrap().as_ref().unwrap(), "WORLD");
+
+    // SAFETY: the regex_t is handed over to, and freed by, the new Regex.
+    let regex = unsafe { copy.release() }.expect("release");
+    let wrapped = unsafe { Regex::new_from(regex) };
+    let err = wrapped.try_clone().expect_err("try_clone");
+    assert_eq!(err.kind, ErrorKind::Binding(BindingErrorCode::NO_PATTERN));
+}
+
+#[test]
+fn new_restricted_works() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+
+    Regex::new_restricted("(a+)b", regcomp_flags).expect("Regex::new_restricted");
+    // Escaped backslash followed by a literal digit
+    Regex::new_restricted(r"(a+)\\1", regcomp_flags).expect("Regex::new_restricted");
+
+    let err = Regex::new_restricted(r"(a+)b\1", regcomp_flags).expect_err("backreference");
+    assert_eq!(err.kind, ErrorKind::Binding(BindingErrorCode::RESTRICTED));
+    let err = Regex::new_restricted(r"(a+)\\\1", regcomp_flags).expect_err("backreference");
+    assert_eq!(err.kind, ErrorKind::Binding(BindingErrorCode::RESTRICTED));
+}
+
+#[test]
+fn new_multiline_works() {
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    let haystack = "INFO: start\nERROR: one\nWARN: ERROR: not here\nERROR: two";
+
+    let compiled_reg =
+        Regex::new_multiline("^ERROR: [a-z]+$", RegcompFlags::new()).expect("Regex::new_multiline");
+    let matches = compiled_reg
+        .find_iter(haystack, regexec_flags)
+        .map(|matched| matched.map(|(start, end)| &haystack[start..end]))
+        .collect::<Result<Vec<_>>>()
+        .expect("find_iter");
+    assert_eq!(matches, vec!["ERROR: one", "ERROR: two"]);
+
+    // Without NEWLINE, ^ only anchors at the start of the string
+    let compiled_reg =
+        Regex::new("^ERROR", RegcompFlags::new().add(RegcompFlags::EXTENDED)).expect("Regex::new");
+    assert!(!compiled_reg
+        .is_match(haystack, regexec_flags)
+        .expect("is_match"));
+}
+
+#[test]
+fn compile_all_works() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let results = compile_all(&["^a+$", "[b", "c{2}", "(d"], regcomp_flags);
+    assert_eq!(results.len(), 4);
+    assert!(results[0].is_ok());
+    assert!(results[1].is_err());
+    assert!(results[2].is_ok());
+    assert!(results[3].is_err());
+}
+
+#[test]
+fn complexity_hint_works() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+
+    let simple = Regex::new("abc", regcomp_flags).expect("Regex::new");
+    let repeated = Regex::new("(abc){50}", regcomp_flags).expect("Regex::new");
+    let nested = Regex::new("((abc){50}){50}", regcomp_flags).expect("Regex::new");
+    assert!(simple.complexity_hint() > 0);
+    assert!(repeated.complexity_hint() >= 50 * simple.complexity_hint());
+    assert!(nested.complexity_hint() >= 50 * repeated.complexity_hint());
+
+    // Escaped braces are literals in extended syntax, and operators in basic syntax
+    let literal = Regex::new("a\\{50\\}", regcomp_flags).expect("Regex::new");
+    let basic = Regex::new("a\\{50\\}", RegcompFlags::new()).expect("Regex::new");
+    assert!(literal.complexity_hint() < 10);
+    assert!(basic.complexity_hint() >= 50);
+}
+
+#[test]
+fn estimate_complexity_works() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+
+    let compiled_reg = Regex::new("(abc){50}", regcomp_flags).expect("Regex::new");
+    assert_eq!(
+        Regex::estimate_complexity("(abc){50}", regcomp_flags),
+        compiled_reg.complexity_hint()
+    );
+
+    // Deeply nested repeats saturate rather than overflowing
+    let pattern = format!("{}a{}", "(".repeat(12), "){255}".repeat(12));
+    let huge = Regex::estimate_complexity(&pattern, regcomp_flags);
+    assert!(huge > u32::MAX as usize);
+    assert_eq!(
+        Regex::estimate_complexity(&format!("{pattern}b{{255}}"), regcomp_flags),
+        usize::MAX
+    );
+}
+
+#[test]
+fn new_borrowed_works() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    let owner = Regex::new("^hello", regcomp_flags).expect("Regex::new");
+    assert!(!owner.is_borrowed());
+
+    let compiled_reg = owner.get().as_ref().expect("vacant Regex");
+    // SAFETY: owner outlives borrowed.
+    let borrowed = unsafe { Regex::new_borrowed(compiled_reg) };
+    assert!(borrowed.is_borrowed());
+    assert!(borrowed
+        .is_match("hello world", regexec_flags)
+        .expect("is_match"));
+    drop(borrowed);
+
+    // The owner must still be usable after the borrowed object is dropped.
+    assert!(owner
+        .is_match("hello world", regexec_flags)
+        .expect("is_match"));
+}
+
+#[test]
+fn count_groups_works() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    assert_eq!(count_groups("abc", regcomp_flags).expect("count_groups"), 0);
+    assert_eq!(
+        count_groups("(a)(b(c))?", regcomp_flags).expect("count_groups"),
+        3
+    );
+
+    // Parentheses are literal in basic syntax
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::BASIC);
+    assert_eq!(
+        count_groups("(a)\\(b\\)", regcomp_flags).expect("count_groups"),
+        1
+    );
+
+    let err = count_groups("(a", RegcompFlags::new().add(RegcompFlags::EXTENDED))
+        .expect_err("count_groups should fail");
+    assert!(matches!(err.kind, ErrorKind::Tre(_)));
+}
+
+#[test]
+fn free_works() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+
+    // A literal pattern would otherwise be matched without TRE
+    let mut compiled_reg = Regex::new("hello", regcomp_flags).expect("Regex::new");
+    compiled_reg.free();
+    assert!(compiled_reg.get().is_none());
+    assert_eq!(compiled_reg.as_str(), None);
+    let err = compiled_reg
+        .is_match("hello", regexec_flags)
+        .expect_err("is_match should fail");
+    assert_eq!(err.kind, ErrorKind::Binding(BindingErrorCode::REGEX_VACANT));
+
+    // Freeing twice, then dropping, is fine
+    compiled_reg.free();
+    compiled_reg
+        .recompile("wor(ld)", regcomp_flags)
+        .expect("recompile");
+    assert!(compiled_reg
+        .is_match("world", regexec_flags)
+        .expect("is_match"));
+
+    // Freeing a borrowed regex leaves the owner alone
+    let owner = Regex::new("^hello", regcomp_flags).expect("Regex::new");
+    let compiled_reg = owner.get().as_ref().expect("vacant Regex");
+    // SAFETY: owner outlives borrowed.
+    let mut borrowed = unsafe { Regex::new_borrowed(compiled_reg) };
+    borrowed.free();
+    assert!(borrowed.get().is_none());
+    assert!(owner
+        .is_match("hello world", regexec_flags)
+        .expect("is_match"));
+}
+
+#[test]
+fn union_works() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+
+    let compiled_reg =
+        Regex::union(&["^([0-9]+)$", "^[a-z]+$", "^(x)(y)$"], regcomp_flags).expect("union");
+    assert_eq!(compiled_reg.captures_len().expect("captures_len"), 7);
+    for (string, group) in [("123", 1), ("abc", 3), ("xy", 4)] {
+        let matches = compiled_reg
+            .regexec(string, 7, regexec_flags)
+            .expect("regexec");
+        let present = [1, 3, 4]
+            .into_iter()
+            .filter(|&i| matches[i].is_some())
+            .collect::<Vec<_>>();
+        assert_eq!(present, vec![group]);
+    }
+    assert!(!compiled_reg
+        .is_match("abc1", regexec_flags)
+        .expect("is_match"));
+
+    // Basic syntax
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::BASIC);
+    let compiled_reg = Regex::union(&["a\\{2\\}", "b"], regcomp_flags).expect("union");
+    let matches = compiled_reg
+        .regexec("xaa", 3, regexec_flags)
+        .expect("regexec");
+    assert!(matches[1].is_some());
+    assert!(matches[2].is_none());
+
+    // Literal patterns are escaped
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::LITERAL);
+    let compiled_reg = Regex::union(&["a.b", "(c)"], regcomp_flags).expect("union");
+    assert!(compiled_reg
+        .is_match("(c)", regexec_flags)
+        .expect("is_match"));
+    assert!(!compiled_reg
+        .is_match("axb", regexec_flags)
+        .expect("is_match"));
+
+    // A pattern cannot break out of its group
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    assert!(Regex::union(&["a)|(b"], regcomp_flags).is_err());
+
+    let err = Regex::union(&[], regcomp_flags).expect_err("union should fail");
+    assert_eq!(err.kind, ErrorKind::Binding(BindingErrorCode::NO_PATTERN));
+}
+
+#[test]
+fn nsub_works() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    for (pattern, nsub) in [("abc", 0), ("(a)|(b)", 2), ("((a)(b(c)))?", 4)] {
+        let compiled_reg = Regex::new(pattern, regcomp_flags).expect("Regex::new");
+        assert_eq!(compiled_reg.nsub().expect("nsub"), nsub, "{pattern}");
+        assert_eq!(
+            compiled_reg.captures_len().expect("captures_len"),
+            nsub + 1,
+            "{pattern}"
+        );
+    }
+
+    let mut compiled_reg = Regex::new("(a)", regcomp_flags).expect("Regex::new");
+    compiled_reg.free();
+    let Err(err) = compiled_reg.nsub() else {
+        panic!("nsub on a vacant Regex");
+    };
+    assert_eq!(err.kind, ErrorKind::Binding(BindingErrorCode::REGEX_VACANT));
+}
+
+#[test]
+fn clone_works() {
+    #[derive(Clone)]
+    struct Holder {
+        regex: Regex,
+    }
+
+    let regcomp_flags = RegcompFlags::new()
+        .add(RegcompFlags::EXTENDED)
+        .add(RegcompFlags::ICASE);
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    let holder = Holder {
+        regex: Regex::new("^(hello)", regcomp_flags).expect("Regex::new"),
+    };
+    let copy = holder.clone();
+    drop(holder);
+    assert_eq!(copy.regex.as_str(), Some("^(hello)"));
+    assert!(copy
+        .regex
+        .is_match("HELLO world", regexec_flags)
+        .expect("is_match"));
+
+    // Borrowed objects are copied without compiling again
+    let compiled_reg = copy.regex.get().as_ref().expect("vacant Regex");
+    // SAFETY: copy outlives borrowed and its clone.
+    let borrowed = unsafe { Regex::new_borrowed(compiled_reg) };
+    let borrowed_copy = borrowed.clone();
+    assert!(borrowed_copy.is_borrowed());
+    assert!(borrowed_copy
+        .is_match("hello", regexec_flags)
+        .expect("is_match"));
+    drop(borrowed);
+
+    // Vacant objects stay vacant
+    let mut vacant = Regex::new("a", regcomp_flags).expect("Regex::new");
+    vacant.free();
+    let vacant_copy = vacant.clone();
+    assert!(vacant_copy.get().is_none());
+    assert!(vacant.get().is_none());
+}
+
+#[test]
+fn as_pattern_bytes_works() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let compiled_reg = Regex::new_bytes(b"caf\xe9", regcomp_flags).expect("Regex::new_bytes");
+    assert!(!compiled_reg.is_wide());
+    assert_eq!(compiled_reg.as_pattern_bytes(), b"caf\xe9");
+    assert_eq!(compiled_reg.as_str(), None);
+
+    let mut compiled_reg = Regex::new("abc", regcomp_flags).expect("Regex::new");
+    assert_eq!(compiled_reg.as_pattern_bytes(), b"abc");
+    compiled_reg.free();
+    assert_eq!(compiled_reg.as_pattern_bytes(), b"");
+}
+
+#[test]
+fn debug_shows_pattern_and_flags() {
+    let regcomp_flags = RegcompFlags::new()
+        .add(RegcompFlags::EXTENDED)
+        .add(RegcompFlags::ICASE);
+    let compiled_reg = Regex::new("foo.*", regcomp_flags).expect("Regex::new");
+    assert_eq!(
+        format!("{compiled_reg:?}"),
+        r#"Regex { pattern: "foo.*", flags: EXTENDED | ICASE }"#
+    );
+
+    let compiled_reg = Regex::new_bytes(b"caf\xe9", RegcompFlags::new()).expect("Regex::new_bytes");
+    assert_eq!(
+        format!("{compiled_reg:?}"),
+        r#"Regex { pattern: b"caf\xe9", flags: NONE }"#
+    );
+}
+
+#[test]
+fn has_backrefs_works() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let compiled_reg = Regex::new("(a|b)c\\1", regcomp_flags).expect("Regex::new");
+    assert!(compiled_reg.has_backrefs().expect("has_backrefs"));
+    assert!(!compiled_reg.has_approx().expect("has_approx"));
+
+    let mut compiled_reg = Regex::new("(a|b)c", regcomp_flags).expect("Regex::new");
+    assert!(!compiled_reg.has_backrefs().expect("has_backrefs"));
+
+    compiled_reg.free();
+    let Err(err) = compiled_reg.has_backrefs() else {
+        panic!("has_backrefs on a vacant Regex");
+    };
+    assert_eq!(err.kind, ErrorKind::Binding(BindingErrorCode::REGEX_VACANT));
+    let Err(err) = compiled_reg.has_approx() else {
+        panic!("has_approx on a vacant Regex");
+    };
+    assert_eq!(err.kind, ErrorKind::Binding(BindingErrorCode::REGEX_VACANT));
+}
+
+#[test]
+fn from_str_works() {
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    let compiled_reg: Regex = "^(ab)+$".parse().expect("parse");
+    assert_eq!(compiled_reg.as_str(), Some("^(ab)+$"));
+    assert!(compiled_reg
+        .is_match("abab", regexec_flags)
+        .expect("is_match"));
+
+    let Err(err) = "(ab".parse::<Regex>() else {
+        panic!("parse with an unbalanced parenthesis");
+    };
+    assert_eq!(err.kind, ErrorKind::Tre(tre::reg_errcode_t::REG_EPAREN));
+}
@@ -1,4 +1,9 @@
-use crate::{regcomp, tre, ErrorKind, RegcompFlags, RegexecFlags};
+use std::error::Error;
+
+use crate::{
+    regcomp, tre, BindingErrorCode, ErrorInt, ErrorKind, ErrorPhase, RegcompFlags, Regex,
+    RegexError, RegexecFlags, TreErrorCode,
+};
 
 #[test]
 fn regerror_works() {
@@ -10,3 +15,142 @@ fn regerror_works() {
         }
     }
 }
+
+#[test]
+fn regerror_into_works() {
+    let compiled_reg = Regex::new("a", RegcompFlags::new()).expect("Regex::new");
+    let mut buf = String::from("stale contents");
+    let capacity = buf.capacity();
+
+    let code = ErrorInt::try_from(tre::reg_errcode_t::REG_EBRACK.0).expect("code");
+    compiled_reg
+        .regerror_into(code, &mut buf)
+        .expect("regerror_into");
+    assert_eq!(buf, "Missing ']'");
+    assert!(buf.capacity() >= capacity);
+}
+
+#[test]
+fn regex_error_display_works() {
+    let Err(e) = regcomp("[a", RegcompFlags::new()) else {
+        panic!("regcomp");
+    };
+    assert_eq!(e.tre_code(), Some(TreErrorCode::Bracket));
+    assert_eq!(
+        e.to_string(),
+        "Missing ']' (bad bracket expression) in pattern \"[a\""
+    );
+    assert_eq!(e.pattern(), Some("[a"));
+
+    let e = RegexError::new(
+        ErrorKind::Tre(tre::reg_errcode_t::REG_EBRACK),
+        "Missing ']'",
+    );
+    assert_eq!(e.to_string(), "Missing ']' (bad bracket expression)");
+
+    let e = e.with_pattern(&format!("[{}", "a".repeat(40)));
+    assert_eq!(
+        e.to_string(),
+        format!(
+            "Missing ']' (bad bracket expression) in pattern \"[{}\"...",
+            "a".repeat(31)
+        )
+    );
+
+    // The attached pattern is not compared
+    let plain = RegexError::new(
+        ErrorKind::Tre(tre::reg_errcode_t::REG_EBRACK),
+        "Missing ']'",
+    );
+    assert_eq!(e, plain);
+}
+
+#[test]
+fn regex_error_category_works() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let Err(e) = regcomp("(a", regcomp_flags) else {
+        panic!("regcomp");
+    };
+    assert_eq!(e.category(), Some("unbalanced_parenthesis"));
+
+    let Err(e) = Regex::new_restricted(r"(a)\1", regcomp_flags) else {
+        panic!("Regex::new_restricted");
+    };
+    assert_eq!(e.category(), None);
+}
+
+#[test]
+fn from_utf8_error_works() {
+    let data = b"caf\xe9".to_vec();
+    let e = RegexError::from(std::str::from_utf8(&data).unwrap_err());
+    assert_eq!(e.kind, ErrorKind::Binding(BindingErrorCode::ENCODING));
+
+    let e = RegexError::from(String::from_utf8(data).unwrap_err());
+    assert_eq!(e.kind, ErrorKind::Binding(BindingErrorCode::ENCODING));
+}
+
+#[test]
+fn error_phase_works() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+
+    let err = Regex::new("(a", regcomp_flags).expect_err("Regex::new should fail");
+    assert_eq!(err.phase(), Some(ErrorPhase::Compile));
+
+    let err = Regex::new_restricted(r"(a)\1", regcomp_flags)
+        .expect_err("Regex::new_restricted should fail");
+    assert_eq!(err.phase(), Some(ErrorPhase::Compile));
+
+    let compiled_reg = Regex::new("^a$", regcomp_flags).expect("Regex::new");
+    let err = compiled_reg
+        .regexec("b", 1, regexec_flags)
+        .expect_err("regexec should fail");
+    assert_eq!(err.tre_code(), Some(TreErrorCode::NoMatch));
+    assert_eq!(err.phase(), Some(ErrorPhase::Exec));
+
+    let err = RegexError::new(
+        ErrorKind::Binding(BindingErrorCode::REGEX_VACANT),
+        "Attempted to unwrap a vacant Regex object",
+    );
+    assert_eq!(err.phase(), None);
+    assert_ne!(
+        err,
+        RegexError::new(
+            ErrorKind::Binding(BindingErrorCode::REGEX_VACANT),
+            "Attempted to unwrap a vacant Regex object",
+        )
+        .with_phase(ErrorPhase::Exec)
+    );
+}
+
+#[test]
+fn error_source_works() {
+    let data = b"caf\xe9".to_vec();
+    let utf8_error = std::str::from_utf8(&data).unwrap_err();
+    let e = RegexError::from(utf8_error);
+    let source = e.source().expect("source");
+    assert_eq!(source.downcast_ref(), Some(&utf8_error));
+
+    let e = RegexError::new(ErrorKind::Binding(BindingErrorCode::ENCODING), "error");
+    assert!(e.source().is_none());
+    let e = e.with_source(utf8_error);
+    assert!(e.source().is_some());
+    assert_eq!(
+        e,
+        RegexError::new(ErrorKind::Binding(BindingErrorCode::ENCODING), "error")
+    );
+
+    // Decoding errors in match results keep their source
+    let regcomp_flags = RegcompFlags::new()
+        .add(RegcompFlags::EXTENDED)
+        .add(RegcompFlags::USEBYTES);
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    let compiled_reg = Regex::new("^.", regcomp_flags).expect("Regex::new");
+    let matches = compiled_reg
+        .regexec("é", 1, regexec_flags)
+        .expect("regexec");
+    let Some(Err(e)) = &matches[0] else {
+        panic!("match on half a character");
+    };
+    assert!(e.source().expect("source").is::<std::str::Utf8Error>());
+}
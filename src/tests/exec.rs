@@ -1,4 +1,7 @@
-use crate::{regcomp, regexec, regexec_bytes, RegcompFlags, RegexecFlags};
+use crate::{
+    exec::check_len, regcomp, regexec, regexec_bytes, regexec_mixed, scan, tre, BindingErrorCode,
+    ErrorKind, MatchText, RegcompFlags, Regex, RegexecFlags, Result,
+};
 
 #[test]
 fn regexec_flags_works() {
@@ -54,3 +57,325 @@ fn regex_multibyte_works() {
     assert!(result[1].as_ref().unwrap().is_ok());
     assert_eq!(*result[1].as_ref().unwrap().as_ref().unwrap(), "エリザベス");
 }
+
+#[test]
+fn regexec_empty_vs_unset_works() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+
+    // Optional group that does not participate
+    let Ok(compiled_reg) = regcomp("(a)?b", regcomp_flags) else { panic!("regcomp"); };
+    let Ok(result) = regexec(&compiled_reg, "b", 2, regexec_flags) else { panic!("regexec"); };
+    assert_eq!(*result[0].as_ref().unwrap().as_ref().unwrap(), "b");
+    assert!(result[1].is_none());
+
+    // Group that participates but matches nothing
+    let Ok(compiled_reg) = regcomp("(a*)b", regcomp_flags) else { panic!("regcomp"); };
+    let Ok(result) = regexec(&compiled_reg, "b", 2, regexec_flags) else { panic!("regexec"); };
+    assert_eq!(*result[0].as_ref().unwrap().as_ref().unwrap(), "b");
+    assert!(result[1].is_some());
+    assert_eq!(*result[1].as_ref().unwrap().as_ref().unwrap(), "");
+
+    let Ok(result) = regexec_bytes(&compiled_reg, b"b", 2, regexec_flags) else { panic!("regexec_bytes"); };
+    assert_eq!(result[1].as_ref().unwrap().as_ref(), b"");
+}
+
+#[test]
+fn regexec_raw_works() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    let Ok(compiled_reg) = regcomp("(a)?(b+)", regcomp_flags) else { panic!("regcomp"); };
+    let Ok(result) = compiled_reg.regexec_raw(b"xxbb", 3, regexec_flags) else { panic!("regexec_raw"); };
+    assert_eq!(result.len(), 3);
+    assert_eq!((result[0].rm_so, result[0].rm_eo), (2, 4));
+    assert!(result[1].rm_so < 0 && result[1].rm_eo < 0);
+    assert_eq!((result[2].rm_so, result[2].rm_eo), (2, 4));
+
+    let result = compiled_reg.regexec_raw(b"xyz", 3, regexec_flags);
+    assert!(result.is_err());
+}
+
+#[test]
+fn regexec_bytes_checked_works() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    let Ok(compiled_reg) = regcomp("(a)?(b+)", regcomp_flags) else { panic!("regcomp"); };
+    let Ok(result) = compiled_reg.regexec_bytes_checked(b"xxbb\xff", 3, regexec_flags) else { panic!("regexec_bytes_checked"); };
+    assert_eq!(result.len(), 3);
+    assert_eq!(*result[0].as_ref().unwrap().as_ref().unwrap(), &b"bb"[..]);
+    assert!(result[1].is_none());
+    assert_eq!(*result[2].as_ref().unwrap().as_ref().unwrap(), &b"bb"[..]);
+
+    let result = compiled_reg.regexec_bytes_checked(b"xyz", 3, regexec_flags);
+    assert!(result.is_err());
+}
+
+#[test]
+fn regexec_mixed_works() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    let Ok(compiled_reg) = regcomp("^([a-z]+) (.*)$", regcomp_flags) else { panic!("regcomp"); };
+    let Ok(result) = regexec_mixed(&compiled_reg, b"name caf\xe9", 4, regexec_flags) else { panic!("regexec_mixed"); };
+    assert_eq!(result[0], Some(MatchText::Bytes(b"name caf\xe9")));
+    assert_eq!(result[1], Some(MatchText::Str("name")));
+    assert_eq!(result[1].unwrap().as_str(), Some("name"));
+    assert_eq!(result[2], Some(MatchText::Bytes(b"caf\xe9")));
+    assert_eq!(result[2].unwrap().as_bytes(), b"caf\xe9");
+    assert_eq!(result[3], None);
+}
+
+#[test]
+fn match_mask_works() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    let compiled_reg = regcomp("^beta-", regcomp_flags).expect("regcomp");
+
+    assert!(compiled_reg.is_match("beta-ui", regexec_flags).expect("is_match"));
+    assert!(!compiled_reg.is_match("stable", regexec_flags).expect("is_match"));
+
+    let mask = compiled_reg
+        .match_mask(&["beta-ui", "stable", "", "beta-api"], regexec_flags)
+        .expect("match_mask");
+    assert_eq!(mask, vec![true, false, false, true]);
+}
+
+#[test]
+fn is_match_works() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    let mut compiled_reg = regcomp("^[[:digit:]]+$", regcomp_flags).expect("regcomp");
+    assert!(compiled_reg
+        .is_match("12345", regexec_flags)
+        .expect("is_match"));
+    assert!(!compiled_reg
+        .is_match("12a45", regexec_flags)
+        .expect("is_match"));
+    assert!(!compiled_reg
+        .is_match("12345", regexec_flags.add(RegexecFlags::NOTBOL))
+        .expect("is_match"));
+
+    compiled_reg.free();
+    let Err(err) = compiled_reg.is_match("12345", regexec_flags) else {
+        panic!("is_match on a vacant Regex");
+    };
+    assert_eq!(err.kind, ErrorKind::Binding(BindingErrorCode::REGEX_VACANT));
+}
+
+#[test]
+fn is_match_bytes_works() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    let compiled_reg = regcomp("^GET /[a-z]+", regcomp_flags).expect("regcomp");
+    assert!(compiled_reg
+        .is_match_bytes(b"GET /admin\xff\xfe", regexec_flags)
+        .expect("is_match_bytes"));
+    assert!(!compiled_reg
+        .is_match_bytes(b"\xffGET /admin", regexec_flags)
+        .expect("is_match_bytes"));
+
+    // Literal fast path
+    let compiled_reg = regcomp("admin", regcomp_flags).expect("regcomp");
+    assert!(compiled_reg
+        .is_match_bytes(b"\x00\x01admin\xff", regexec_flags)
+        .expect("is_match_bytes"));
+    assert!(!compiled_reg
+        .is_match_bytes(b"\x00adm\xffin", regexec_flags)
+        .expect("is_match_bytes"));
+}
+
+#[test]
+fn matches_empty_works() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    for (pattern, expected) in [
+        ("a*", true),
+        ("x?", true),
+        ("^$", true),
+        ("a+", false),
+        ("abc", false),
+        ("\\<", false),
+    ] {
+        let compiled_reg = regcomp(pattern, regcomp_flags).expect("regcomp");
+        assert_eq!(
+            compiled_reg
+                .matches_empty(regexec_flags)
+                .expect("matches_empty"),
+            expected,
+            "{pattern}"
+        );
+    }
+
+    // NOTBOL stops ^ from matching
+    let compiled_reg = regcomp("^$", regcomp_flags).expect("regcomp");
+    assert!(!compiled_reg
+        .matches_empty(regexec_flags.add(RegexecFlags::NOTBOL))
+        .expect("matches_empty"));
+}
+
+#[test]
+fn check_len_works() {
+    let max = usize::try_from(tre::regoff_t::MAX).expect("regoff_t::MAX");
+    check_len(0).expect("check_len");
+    check_len(max).expect("check_len");
+
+    let err = check_len(max + 1).expect_err("check_len");
+    assert_eq!(err.kind, ErrorKind::Binding(BindingErrorCode::INPUT_TOO_LONG));
+}
+
+#[test]
+fn literal_fast_path_works() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    let literal = regcomp("ナナ", regcomp_flags).expect("regcomp");
+    // Equivalent, but goes through TRE
+    let pattern = regcomp("ナ[ナ]", regcomp_flags).expect("regcomp");
+
+    for string in ["", "ナ", "ナナナナナ", "aナナbナナ", "abc"] {
+        assert_eq!(
+            literal.is_match(string, regexec_flags).expect("is_match"),
+            pattern.is_match(string, regexec_flags).expect("is_match"),
+        );
+        assert_eq!(
+            literal
+                .find_iter(string, regexec_flags)
+                .collect::<Result<Vec<_>>>()
+                .expect("find_iter"),
+            pattern
+                .find_iter(string, regexec_flags)
+                .collect::<Result<Vec<_>>>()
+                .expect("find_iter"),
+        );
+    }
+}
+
+#[test]
+fn ascii_ci_works() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    let compiled_reg = Regex::new_ascii_ci(r"^Héllo (\W*)([A-Z]+)$", regcomp_flags)
+        .expect("Regex::new_ascii_ci");
+    assert_eq!(compiled_reg.as_str(), Some(r"^héllo (\W*)([a-z]+)$"));
+
+    let result = compiled_reg
+        .regexec_ascii_ci("HéLLO !!World", 3, regexec_flags)
+        .expect("regexec_ascii_ci");
+    assert_eq!(
+        *result[0].as_ref().unwrap().as_ref().unwrap(),
+        "HéLLO !!World"
+    );
+    assert_eq!(*result[1].as_ref().unwrap().as_ref().unwrap(), "!!");
+    assert_eq!(*result[2].as_ref().unwrap().as_ref().unwrap(), "World");
+
+    // Only ASCII is folded
+    assert!(compiled_reg
+        .regexec_ascii_ci("HÉLLO World", 1, regexec_flags)
+        .is_err());
+}
+
+#[test]
+fn ungreedy_works() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+
+    let greedy = Regex::new("a.*b", regcomp_flags).expect("Regex::new");
+    let result = greedy.regexec("axbxb", 1, regexec_flags).expect("regexec");
+    assert_eq!(*result[0].as_ref().unwrap().as_ref().unwrap(), "axbxb");
+
+    let ungreedy =
+        Regex::new("a.*b", regcomp_flags.add(RegcompFlags::UNGREEDY)).expect("Regex::new");
+    let result = ungreedy
+        .regexec("axbxb", 1, regexec_flags)
+        .expect("regexec");
+    assert_eq!(*result[0].as_ref().unwrap().as_ref().unwrap(), "axb");
+
+    // A minimal repetition operator has the same effect
+    let minimal = Regex::new("a.*?b", regcomp_flags).expect("Regex::new");
+    let result = minimal.regexec("axbxb", 1, regexec_flags).expect("regexec");
+    assert_eq!(*result[0].as_ref().unwrap().as_ref().unwrap(), "axb");
+
+    assert_eq!(RegcompFlags::UNGREEDY, tre::REG_UNGREEDY);
+}
+
+#[test]
+fn scan_works() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+
+    let result = scan(
+        "([a-z]+)(-([0-9]+))?",
+        regcomp_flags,
+        "  abc",
+        4,
+        regexec_flags,
+    )
+    .expect("scan");
+    assert_eq!(
+        result,
+        vec![Some("abc".to_string()), Some("abc".to_string()), None, None]
+    );
+
+    assert!(scan("[0-9]+", regcomp_flags, "abc", 1, regexec_flags).is_err());
+    assert!(scan("(", regcomp_flags, "abc", 1, regexec_flags).is_err());
+}
+
+#[test]
+fn regexec_lazy_works() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    let compiled_reg = Regex::new("^(h[a-z]+)(,)? (.*)$", regcomp_flags).expect("Regex::new");
+
+    let matches = compiled_reg
+        .regexec_lazy("hello wörld", 4, regexec_flags)
+        .expect("regexec_lazy");
+    assert_eq!(matches.len(), 4);
+    let matches = matches
+        .map(|matched| matched.transpose().expect("decode"))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        matches,
+        vec![Some("hello wörld"), Some("hello"), None, Some("wörld")]
+    );
+
+    let mut matches = compiled_reg
+        .regexec_lazy("hello wörld", 4, regexec_flags)
+        .expect("regexec_lazy");
+    assert_eq!(
+        matches.nth(3).flatten().transpose().expect("decode"),
+        Some("wörld")
+    );
+    assert!(matches.next().is_none());
+
+    assert!(compiled_reg
+        .regexec_lazy("goodbye", 4, regexec_flags)
+        .is_err());
+}
+
+#[test]
+fn regexec_startend_works() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    let compiled_reg = Regex::new("^(b+)$", regcomp_flags).expect("Regex::new");
+    let data = b"abbbc\0bb";
+
+    let matches = compiled_reg
+        .regexec_startend(data, 1, 4, 2, regexec_flags)
+        .expect("regexec_startend");
+    assert_eq!(matches, vec![Some(1..4), Some(1..4)]);
+    let matches = compiled_reg
+        .regexec_startend(data, 6, 8, 2, regexec_flags)
+        .expect("regexec_startend");
+    assert_eq!(matches, vec![Some(6..8), Some(6..8)]);
+
+    // The text outside the window is not seen
+    assert!(compiled_reg
+        .regexec_startend(data, 0, 4, 1, regexec_flags)
+        .is_err());
+    assert!(compiled_reg
+        .regexec_startend(data, 1, 4, 1, regexec_flags.add(RegexecFlags::NOTBOL))
+        .is_err());
+
+    let Err(err) = compiled_reg.regexec_startend(data, 4, 9, 1, regexec_flags) else {
+        panic!("regexec_startend with an out of bounds window");
+    };
+    assert_eq!(err.kind, ErrorKind::Binding(BindingErrorCode::OUT_OF_BOUNDS));
+}
@@ -0,0 +1,43 @@
+use crate::{RegcompFlags, RegexecFlags};
+
+#[test]
+fn flag_names_work() {
+    let regcomp_flags = RegcompFlags::new()
+        .add(RegcompFlags::EXTENDED)
+        .add(RegcompFlags::ICASE)
+        .add(RegcompFlags::NEWLINE);
+    let names: Vec<_> = regcomp_flags.iter_names().collect();
+    assert_eq!(names, vec!["EXTENDED", "ICASE", "NEWLINE"]);
+    assert_eq!(regcomp_flags.to_string(), "EXTENDED | ICASE | NEWLINE");
+    assert_eq!(RegcompFlags::new().to_string(), "NONE");
+
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NOTBOL).add(0x1000);
+    let names: Vec<_> = regexec_flags.iter_names().collect();
+    assert_eq!(names, vec!["NOTBOL"]);
+    assert_eq!(regexec_flags.unknown_bits(), 0x1000);
+    assert_eq!(RegexecFlags::new().unknown_bits(), 0);
+    assert_eq!(regexec_flags.to_string(), "NOTBOL | 0x1000");
+}
+
+#[test]
+fn contains_works() {
+    let regcomp_flags = RegcompFlags::new()
+        .add(RegcompFlags::EXTENDED)
+        .add(RegcompFlags::ICASE);
+    assert!(regcomp_flags.contains(RegcompFlags::ICASE));
+    assert!(regcomp_flags.contains(RegcompFlags::EXTENDED | RegcompFlags::ICASE));
+    assert!(!regcomp_flags.contains(RegcompFlags::EXTENDED | RegcompFlags::NEWLINE));
+    assert!(regcomp_flags.contains(RegcompFlags::NONE));
+    assert!(!regcomp_flags.is_empty());
+    assert!(RegcompFlags::new().is_empty());
+    assert_eq!(
+        regcomp_flags.iter_set().collect::<Vec<_>>(),
+        vec!["EXTENDED", "ICASE"]
+    );
+
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NOTEOL);
+    assert!(regexec_flags.contains(RegexecFlags::NOTEOL));
+    assert!(!regexec_flags.contains(RegexecFlags::NOTBOL));
+    assert!(regexec_flags.remove(RegexecFlags::NOTEOL).is_empty());
+    assert_eq!(regexec_flags.iter_set().collect::<Vec<_>>(), vec!["NOTEOL"]);
+}
@@ -0,0 +1,35 @@
+use crate::{Highlighter, RegcompFlags, Regex, RegexecFlags};
+
+#[test]
+fn highlight_works() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+
+    let compiled_reg = Regex::new("[[:digit:]]+", regcomp_flags).expect("Regex::new");
+    let highlighter = Highlighter::new(&compiled_reg, "<", ">", regexec_flags);
+    let result = highlighter.highlight("1 ナ22 x").expect("highlight");
+    assert_eq!(result, "<1> ナ<22> x");
+
+    // Empty matches are not marked
+    let compiled_reg = Regex::new("[[:digit:]]*", regcomp_flags).expect("Regex::new");
+    let highlighter = Highlighter::new(&compiled_reg, "<", ">", regexec_flags);
+    let result = highlighter.highlight("a1b").expect("highlight");
+    assert_eq!(result, "a<1>b");
+}
+
+#[test]
+fn highlight_writer_works() {
+    use std::fmt::Write;
+
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+
+    let compiled_reg = Regex::new("[[:digit:]]+", regcomp_flags).expect("Regex::new");
+    let highlighter = Highlighter::new(&compiled_reg, "<", ">", regexec_flags);
+    let mut writer = highlighter.writer(String::new());
+    writer.write_str("a 1").expect("write_str");
+    writer.write_str("23 b\n4").expect("write_str");
+    assert_eq!(writer.get_ref(), "a <123> b\n");
+    writer.write_str("5").expect("write_str");
+    assert_eq!(writer.finish().expect("finish"), "a <123> b\n<45>");
+}
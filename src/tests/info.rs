@@ -0,0 +1,22 @@
+use crate::{tre_version, TreConfig};
+
+#[test]
+fn tre_version_works() {
+    let version = tre_version();
+    assert!(version.starts_with("TRE "));
+    assert_eq!(tre_version(), version);
+}
+
+#[test]
+fn tre_config_works() {
+    let config = TreConfig::query();
+    assert_eq!(config, TreConfig::query());
+    assert!(tre_version().contains(config.version()));
+    // The vendored TRE is built with the features this crate enables.
+    if cfg!(all(feature = "vendored", feature = "approx")) {
+        assert!(config.approx());
+    }
+    if cfg!(all(feature = "vendored", feature = "wchar")) {
+        assert!(config.wchar());
+    }
+}
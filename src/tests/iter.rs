@@ -0,0 +1,387 @@
+use std::ops::ControlFlow;
+
+use crate::{DetailedMatch, Piece, RegcompFlags, Regex, RegexecFlags, Result};
+
+#[test]
+fn find_iter_works() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    let compiled_reg = Regex::new("[[:digit:]]+", regcomp_flags).expect("Regex::new");
+    let matches = compiled_reg
+        .find_iter("1 22 333", regexec_flags)
+        .collect::<Result<Vec<_>>>()
+        .expect("find_iter");
+    assert_eq!(matches, vec![(0, 1), (2, 4), (5, 8)]);
+}
+
+#[test]
+fn find_iter_anchor_works() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    let compiled_reg = Regex::new("^a", regcomp_flags).expect("Regex::new");
+    let matches = compiled_reg
+        .find_iter("aaa", regexec_flags)
+        .collect::<Result<Vec<_>>>()
+        .expect("find_iter");
+    assert_eq!(matches, vec![(0, 1)]);
+}
+
+#[test]
+fn find_iter_bytes_works() {
+    let regcomp_flags = RegcompFlags::new()
+        .add(RegcompFlags::EXTENDED)
+        .add(RegcompFlags::USEBYTES);
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    let compiled_reg = Regex::new("x*", regcomp_flags).expect("Regex::new");
+    let matches = compiled_reg
+        .find_iter_bytes(b"\xffx\xff", regexec_flags)
+        .collect::<Result<Vec<_>>>()
+        .expect("find_iter_bytes");
+    assert_eq!(matches, vec![(0, 0), (1, 2), (3, 3)]);
+
+    // ^ does not match again after the first match
+    let compiled_reg = Regex::new("^a", regcomp_flags).expect("Regex::new");
+    let matches = compiled_reg
+        .find_iter_bytes(b"a\xffa", regexec_flags)
+        .collect::<Result<Vec<_>>>()
+        .expect("find_iter_bytes");
+    assert_eq!(matches, vec![(0, 1)]);
+}
+
+#[test]
+fn find_iter_word_boundary_context() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    let compiled_reg = Regex::new("\\<ab", regcomp_flags).expect("Regex::new");
+    let matches = compiled_reg
+        .find_iter("abab ab", regexec_flags)
+        .collect::<Result<Vec<_>>>()
+        .expect("find_iter");
+    assert_eq!(matches, vec![(0, 2), (5, 7)]);
+}
+
+#[test]
+fn find_iter_multiline_context() {
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    let compiled_reg = Regex::new_multiline("^a\n", RegcompFlags::new()).expect("Regex::new");
+    let matches = compiled_reg
+        .find_iter("a\na\n", regexec_flags)
+        .collect::<Result<Vec<_>>>()
+        .expect("find_iter");
+    assert_eq!(matches, vec![(0, 2), (2, 4)]);
+}
+
+#[test]
+fn split_at_matches_works() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    let compiled_reg = Regex::new(",+", regcomp_flags).expect("Regex::new");
+    let pieces = compiled_reg
+        .split_at_matches(",a,,b,", regexec_flags)
+        .collect::<Result<Vec<_>>>()
+        .expect("split_at_matches");
+    assert_eq!(
+        pieces,
+        vec![
+            Piece::Field(""),
+            Piece::Separator(","),
+            Piece::Field("a"),
+            Piece::Separator(",,"),
+            Piece::Field("b"),
+            Piece::Separator(","),
+            Piece::Field(""),
+        ]
+    );
+}
+
+#[test]
+fn split_at_matches_empty_works() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    let compiled_reg = Regex::new("x*", regcomp_flags).expect("Regex::new");
+    let pieces = compiled_reg
+        .split_at_matches("axb", regexec_flags)
+        .collect::<Result<Vec<_>>>()
+        .expect("split_at_matches");
+    let rebuilt: String = pieces
+        .iter()
+        .map(|piece| match piece {
+            Piece::Field(s) | Piece::Separator(s) => *s,
+        })
+        .collect();
+    assert_eq!(rebuilt, "axb");
+    assert!(pieces.contains(&Piece::Separator("x")));
+}
+
+#[test]
+fn find_iter_overlapping_works() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    let compiled_reg = Regex::new("aa", regcomp_flags).expect("Regex::new");
+    let matches = compiled_reg
+        .find_iter_overlapping("aaaa", regexec_flags)
+        .collect::<Result<Vec<_>>>()
+        .expect("find_iter_overlapping");
+    assert_eq!(matches, vec![(0, 2), (1, 3), (2, 4)]);
+    assert_eq!(
+        compiled_reg
+            .matches_count_overlapping("aaaa", regexec_flags)
+            .expect("matches_count_overlapping"),
+        3
+    );
+    assert_eq!(compiled_reg.find_iter("aaaa", regexec_flags).count(), 2);
+}
+
+#[test]
+fn find_iter_overlapping_step_works() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    let compiled_reg = Regex::new("aa", regcomp_flags).expect("Regex::new");
+    let matches = compiled_reg
+        .find_iter_overlapping_step("aaaaaa", 3, regexec_flags)
+        .collect::<Result<Vec<_>>>()
+        .expect("find_iter_overlapping_step");
+    assert_eq!(matches, vec![(0, 2), (3, 5)]);
+
+    // A step of zero is the same as find_iter_overlapping
+    let matches = compiled_reg
+        .find_iter_overlapping_step("aaaa", 0, regexec_flags)
+        .collect::<Result<Vec<_>>>()
+        .expect("find_iter_overlapping_step");
+    assert_eq!(matches, vec![(0, 2), (1, 3), (2, 4)]);
+
+    // The new start is rounded up to a character boundary
+    let compiled_reg = Regex::new("a", regcomp_flags).expect("Regex::new");
+    let matches = compiled_reg
+        .find_iter_overlapping_step("aéaéa", 2, regexec_flags)
+        .collect::<Result<Vec<_>>>()
+        .expect("find_iter_overlapping_step");
+    assert_eq!(matches, vec![(0, 1), (3, 4), (6, 7)]);
+
+    // ...but not for bytes
+    let compiled_reg =
+        Regex::new("a.", regcomp_flags.add(RegcompFlags::USEBYTES)).expect("Regex::new");
+    let matches = compiled_reg
+        .find_iter_overlapping_step_bytes(b"a\xa9a\xa9", 1, regexec_flags)
+        .collect::<Result<Vec<_>>>()
+        .expect("find_iter_overlapping_step_bytes");
+    assert_eq!(matches, vec![(0, 2), (2, 4)]);
+}
+
+#[test]
+fn find_iter_detailed_works() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    let compiled_reg = Regex::new("[[:digit:]]+", regcomp_flags).expect("Regex::new");
+    let matches = compiled_reg
+        .find_iter_detailed("1 22 333", regexec_flags)
+        .collect::<Result<Vec<_>>>()
+        .expect("find_iter_detailed");
+    assert_eq!(
+        matches,
+        vec![
+            DetailedMatch {
+                range: 0..1,
+                at_start: true,
+                at_end: false
+            },
+            DetailedMatch {
+                range: 2..4,
+                at_start: false,
+                at_end: false
+            },
+            DetailedMatch {
+                range: 5..8,
+                at_start: false,
+                at_end: true
+            },
+        ]
+    );
+}
+
+#[test]
+fn find_iter_nonempty_works() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    let compiled_reg = Regex::new("x*", regcomp_flags).expect("Regex::new");
+    let matches = compiled_reg
+        .find_iter("axxb", regexec_flags)
+        .collect::<Result<Vec<_>>>()
+        .expect("find_iter");
+    assert_eq!(matches, vec![(0, 0), (1, 3), (4, 4)]);
+
+    let matches = compiled_reg
+        .find_iter_nonempty("axxb", regexec_flags)
+        .collect::<Result<Vec<_>>>()
+        .expect("find_iter_nonempty");
+    assert_eq!(matches, vec![(1, 3)]);
+
+    let matches = compiled_reg
+        .find_iter_nonempty("", regexec_flags)
+        .collect::<Result<Vec<_>>>()
+        .expect("find_iter_nonempty");
+    assert!(matches.is_empty());
+}
+
+#[test]
+fn find_iter_limited_works() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    let compiled_reg = Regex::new("[[:digit:]]*", regcomp_flags).expect("Regex::new");
+
+    // The skipped empty match at 1 does not count towards the limit
+    let matches = compiled_reg
+        .find_iter_limited("1 22", regexec_flags, 2)
+        .collect::<Result<Vec<_>>>()
+        .expect("find_iter_limited");
+    assert_eq!(matches, vec![(0, 1), (2, 4)]);
+
+    let matches = compiled_reg
+        .find_iter_limited("1 22", regexec_flags, 0)
+        .collect::<Result<Vec<_>>>()
+        .expect("find_iter_limited");
+    assert!(matches.is_empty());
+}
+
+#[test]
+fn gaps_works() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    let compiled_reg = Regex::new("[[:digit:]]+", regcomp_flags).expect("Regex::new");
+
+    let gaps = compiled_reg
+        .gaps("1a22b3", regexec_flags)
+        .collect::<Result<Vec<_>>>()
+        .expect("gaps");
+    assert_eq!(gaps, vec!["", "a", "b", ""]);
+
+    let ranges = compiled_reg
+        .gap_ranges("1a22b3", regexec_flags)
+        .collect::<Result<Vec<_>>>()
+        .expect("gap_ranges");
+    assert_eq!(ranges, vec![(0, 0), (1, 2), (4, 5), (6, 6)]);
+
+    let gaps = compiled_reg
+        .gaps("abc", regexec_flags)
+        .collect::<Result<Vec<_>>>()
+        .expect("gaps");
+    assert_eq!(gaps, vec!["abc"]);
+}
+
+#[test]
+fn split_works() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    let compiled_reg = Regex::new(",", regcomp_flags).expect("Regex::new");
+    let fields = compiled_reg
+        .split(",a,,b,", regexec_flags)
+        .collect::<Result<Vec<_>>>()
+        .expect("split");
+    assert_eq!(fields, vec!["", "a", "", "b", ""]);
+
+    // Empty matches split without removing text
+    let compiled_reg = Regex::new("x*", regcomp_flags).expect("Regex::new");
+    let fields = compiled_reg
+        .split("abxc", regexec_flags)
+        .collect::<Result<Vec<_>>>()
+        .expect("split");
+    assert_eq!(fields, vec!["", "a", "b", "c", ""]);
+}
+
+#[test]
+fn find_summary_works() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    let compiled_reg = Regex::new("[[:digit:]]+", regcomp_flags).expect("Regex::new");
+
+    let summary = compiled_reg
+        .find_summary("a 1 22 333", regexec_flags)
+        .expect("find_summary");
+    assert_eq!(summary, (true, 3, Some(2..3)));
+
+    let summary = compiled_reg
+        .find_summary("abc", regexec_flags)
+        .expect("find_summary");
+    assert_eq!(summary, (false, 0, None));
+}
+
+#[test]
+fn split_inclusive_works() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+
+    let compiled_reg = Regex::new(";", regcomp_flags).expect("Regex::new");
+    let segments = compiled_reg
+        .split_inclusive("a;b;;c;", regexec_flags)
+        .collect::<Result<Vec<_>>>()
+        .expect("split_inclusive");
+    assert_eq!(segments, vec!["a;", "b;", ";", "c;"]);
+
+    let segments = compiled_reg
+        .split_inclusive("", regexec_flags)
+        .collect::<Result<Vec<_>>>()
+        .expect("split_inclusive");
+    assert!(segments.is_empty());
+
+    // Empty matches split without a delimiter
+    let compiled_reg = Regex::new("x*", regcomp_flags).expect("Regex::new");
+    let segments = compiled_reg
+        .split_inclusive("axxbc", regexec_flags)
+        .collect::<Result<Vec<_>>>()
+        .expect("split_inclusive");
+    assert_eq!(segments, vec!["axx", "b", "c"]);
+    assert_eq!(segments.concat(), "axxbc");
+}
+
+#[test]
+fn for_each_match_works() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    let compiled_reg = Regex::new("[[:alpha:]]*", regcomp_flags).expect("Regex::new");
+
+    let mut ranges = Vec::new();
+    let flow = compiled_reg
+        .for_each_match("ab 12 cd", regexec_flags, |range| {
+            ranges.push(range);
+            ControlFlow::Continue(())
+        })
+        .expect("for_each_match");
+    assert_eq!(flow, ControlFlow::Continue(()));
+    assert_eq!(
+        ranges,
+        compiled_reg
+            .all_match_ranges("ab 12 cd", regexec_flags)
+            .expect("all_match_ranges")
+    );
+
+    let mut ranges = Vec::new();
+    let flow = compiled_reg
+        .for_each_match("ab 12 cd", regexec_flags, |range| {
+            ranges.push(range);
+            if ranges.len() == 2 {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        })
+        .expect("for_each_match");
+    assert_eq!(flow, ControlFlow::Break(()));
+    assert_eq!(ranges, vec![0..2, 3..3]);
+}
+
+#[test]
+fn all_match_ranges_works() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+
+    let compiled_reg = Regex::new("[[:alpha:]]+", regcomp_flags).expect("Regex::new");
+    let ranges = compiled_reg
+        .all_match_ranges("ab 12 cd", regexec_flags)
+        .expect("all_match_ranges");
+    assert_eq!(ranges, vec![0..2, 6..8]);
+
+    let ranges = compiled_reg
+        .all_match_ranges("12", regexec_flags)
+        .expect("all_match_ranges");
+    assert!(ranges.is_empty());
+}
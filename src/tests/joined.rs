@@ -0,0 +1,61 @@
+use crate::{JoinedHaystack, JoinedMatch, RegcompFlags, Regex, RegexecFlags};
+
+#[test]
+fn joined_haystack_works() {
+    let haystack = JoinedHaystack(&["ab", "", "cd", "é"]);
+    assert_eq!(haystack.len(), 6);
+    assert!(!haystack.is_empty());
+    assert!(JoinedHaystack(&["", ""]).is_empty());
+
+    assert_eq!(haystack.locate(0), Some((0, 0)));
+    assert_eq!(haystack.locate(2), Some((2, 0)));
+    assert_eq!(haystack.locate(6), Some((3, 2)));
+    assert_eq!(haystack.locate(7), None);
+    assert_eq!(JoinedHaystack(&[]).locate(0), None);
+
+    assert_eq!(haystack.text(1..3), "bc");
+    assert_eq!(haystack.text(2..4), "cd");
+    assert_eq!(haystack.text(3..6), "dé");
+}
+
+#[test]
+fn regexec_joined_works() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+
+    let compiled_reg = Regex::new("b(c+)é", regcomp_flags).expect("Regex::new");
+    let haystack = JoinedHaystack(&["ab", "c", "", "cé!"]);
+    let matches = compiled_reg
+        .regexec_joined(haystack, 2, regexec_flags)
+        .expect("regexec_joined");
+    assert_eq!(
+        matches[0],
+        Some(JoinedMatch {
+            range: 1..6,
+            start: (0, 1),
+            end: (3, 3),
+        })
+    );
+    assert_eq!(
+        matches[1],
+        Some(JoinedMatch {
+            range: 2..4,
+            start: (1, 0),
+            end: (3, 1),
+        })
+    );
+    assert_eq!(haystack.text(1..6), "bccé");
+
+    // Back references are compared across segments
+    let compiled_reg = Regex::new("(ab)\\1", regcomp_flags).expect("Regex::new");
+    let haystack = JoinedHaystack(&["xa", "ba", "b"]);
+    let matches = compiled_reg
+        .regexec_joined(haystack, 1, regexec_flags)
+        .expect("regexec_joined");
+    assert_eq!(matches[0].as_ref().map(|m| m.range.clone()), Some(1..5));
+
+    let haystack = JoinedHaystack(&["xa", "ba", "c"]);
+    assert!(compiled_reg
+        .regexec_joined(haystack, 1, regexec_flags)
+        .is_err());
+}
@@ -0,0 +1,23 @@
+use crate::{regexec_latin1, BindingErrorCode, ErrorKind, RegcompFlags, Regex, RegexecFlags};
+
+#[test]
+fn latin1_works() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+
+    let compiled_reg = Regex::new_latin1("^(.)(ç|c)", regcomp_flags).expect("Regex::new_latin1");
+    let matches = compiled_reg
+        .regexec_latin1(b"\xe7\xe7a", 3, regexec_flags)
+        .expect("regexec_latin1");
+    assert_eq!(matches[0].as_deref(), Some("çç"));
+    assert_eq!(matches[1].as_deref(), Some("ç"));
+    assert_eq!(matches[2].as_deref(), Some("ç"));
+
+    // Invalid UTF-8 is fine
+    let matches =
+        regexec_latin1(&compiled_reg, b"\xffc", 1, regexec_flags).expect("regexec_latin1");
+    assert_eq!(matches[0].as_deref(), Some("ÿc"));
+
+    let err = Regex::new_latin1("€", regcomp_flags).expect_err("Regex::new_latin1");
+    assert_eq!(err.kind, ErrorKind::Binding(BindingErrorCode::ENCODING));
+}
@@ -1,5 +1,32 @@
 #[cfg(feature = "approx")]
 mod approx;
+#[cfg(feature = "bstr")]
+mod bstr;
+mod builder;
+mod byteset;
+mod captures;
+mod chars;
 mod comp;
 mod err;
 mod exec;
+mod flags;
+mod highlight;
+mod info;
+mod iter;
+#[cfg(feature = "wchar")]
+mod joined;
+mod latin1;
+#[cfg(unix)]
+mod os;
+mod owned;
+mod replace;
+mod scanner;
+mod set;
+#[cfg(feature = "serde")]
+mod serde;
+#[cfg(feature = "smallvec")]
+mod smallvec;
+mod stream;
+mod util;
+#[cfg(feature = "wchar")]
+mod wchar;
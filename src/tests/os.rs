@@ -0,0 +1,26 @@
+use std::ffi::OsStr;
+use std::os::unix::ffi::OsStrExt;
+
+use crate::{RegcompFlags, Regex, RegexecFlags};
+
+#[test]
+fn regexec_os_works() {
+    let regcomp_flags = RegcompFlags::new()
+        .add(RegcompFlags::EXTENDED)
+        .add(RegcompFlags::USEBYTES);
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    let compiled_reg = Regex::new("([a-z]+)(_[0-9]+)?\\.log$", regcomp_flags).expect("Regex::new");
+
+    let path = OsStr::from_bytes(b"/var/\xff\xfe/app.log");
+    let matches = compiled_reg
+        .regexec_os(path, 3, regexec_flags)
+        .expect("regexec_os");
+    assert_eq!(
+        matches,
+        vec![Some(OsStr::new("app.log")), Some(OsStr::new("app")), None]
+    );
+
+    assert!(compiled_reg
+        .regexec_os(OsStr::new("app.txt"), 1, regexec_flags)
+        .is_err());
+}
@@ -0,0 +1,69 @@
+use crate::{IntoOwnedMatches, RegcompFlags, Regex, RegexecFlags};
+
+fn compile(pattern: &str) -> Regex {
+    Regex::new(pattern, RegcompFlags::new().add(RegcompFlags::EXTENDED)).expect("Regex::new")
+}
+
+#[test]
+fn into_owned_str_works() {
+    let compiled_reg = compile("(a)?(b+)");
+    let haystack = String::from("xbb");
+    let result = compiled_reg
+        .regexec(&haystack, 3, RegexecFlags::new())
+        .expect("regexec")
+        .into_owned();
+    drop(haystack);
+
+    assert_eq!(result.len(), 3);
+    assert_eq!(result[0].as_ref().unwrap().as_deref().ok(), Some("bb"));
+    assert!(result[1].is_none());
+    assert_eq!(result[2].as_ref().unwrap().as_deref().ok(), Some("bb"));
+}
+
+#[test]
+fn into_owned_bytes_works() {
+    let compiled_reg = compile("(a)?(b+)");
+    let haystack = b"xbb\xff".to_vec();
+    let result = compiled_reg
+        .regexec_bytes(&haystack, 3, RegexecFlags::new())
+        .expect("regexec_bytes")
+        .into_owned();
+    let checked = compiled_reg
+        .regexec_bytes_checked(&haystack, 3, RegexecFlags::new())
+        .expect("regexec_bytes_checked")
+        .into_owned();
+    drop(haystack);
+
+    assert_eq!(
+        result,
+        vec![Some(b"bb".to_vec()), None, Some(b"bb".to_vec())]
+    );
+    assert_eq!(
+        checked[0].as_ref().unwrap().as_deref().ok(),
+        Some(&b"bb"[..])
+    );
+    assert!(checked[1].is_none());
+}
+
+#[cfg(feature = "approx")]
+#[test]
+fn into_owned_approx_works() {
+    use crate::RegApproxParams;
+
+    let compiled_reg = compile("^(hello)$");
+    let params = RegApproxParams::new().cost_subst(1).max_cost(1).max_err(1);
+    let haystack = String::from("hullo");
+    let result = compiled_reg
+        .regaexec(&haystack, &params, 2, RegexecFlags::new())
+        .expect("regaexec");
+    let cost = result.cost();
+    let result = result.into_owned();
+    drop(haystack);
+
+    assert_eq!(result.get_orig_data(), "hullo");
+    assert_eq!(result.cost(), cost);
+    assert_eq!(
+        result.get_matches()[1].as_ref().unwrap().as_deref().ok(),
+        Some("hullo")
+    );
+}
@@ -0,0 +1,148 @@
+use std::borrow::Cow;
+
+use crate::{escape_replacement, RegcompFlags, Regex, RegexecFlags};
+
+#[test]
+fn escape_replacement_works() {
+    assert_eq!(escape_replacement("no dollars"), "no dollars");
+    assert_eq!(escape_replacement("$1 and ${2}"), "$$1 and $${2}");
+    assert_eq!(escape_replacement("$$"), "$$$$");
+}
+
+#[test]
+fn replacen_works() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    let compiled_reg =
+        Regex::new("([[:alpha:]]+)=([[:digit:]]+)", regcomp_flags).expect("Regex::new");
+
+    let result = compiled_reg
+        .replacen("a=1 b=2 c=3", "$2=$1", 1, regexec_flags)
+        .expect("replacen");
+    assert_eq!(result, "1=a b=2 c=3");
+
+    let result = compiled_reg
+        .replacen("a=1 b=2 c=3", "[${0}|$3|$$|$x]", 5, regexec_flags)
+        .expect("replacen");
+    assert_eq!(result, "[a=1||$|$x] [b=2||$|$x] [c=3||$|$x]");
+
+    let result = compiled_reg
+        .replacen("no pairs", "$1", 5, regexec_flags)
+        .expect("replacen");
+    assert!(matches!(result, Cow::Borrowed("no pairs")));
+
+    let result = compiled_reg
+        .replacen("a=1", "$1", 0, regexec_flags)
+        .expect("replacen");
+    assert!(matches!(result, Cow::Borrowed("a=1")));
+
+    let result = compiled_reg
+        .replacen("a=1", "", 1, regexec_flags)
+        .expect("replacen");
+    assert_eq!(result, "");
+
+    // Empty matches
+    let compiled_reg = Regex::new("x*", regcomp_flags).expect("Regex::new");
+    let result = compiled_reg
+        .replacen("abxc", "-", 10, regexec_flags)
+        .expect("replacen");
+    assert_eq!(result, "-a-b-c-");
+
+    // Word boundaries see the text before each resumed search
+    let compiled_reg = Regex::new("\\<(ab)", regcomp_flags).expect("Regex::new");
+    let result = compiled_reg
+        .replacen("abab ab", "[$1]", 10, regexec_flags)
+        .expect("replacen");
+    assert_eq!(result, "[ab]ab [ab]");
+}
+
+#[test]
+fn replace_works() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    let compiled_reg =
+        Regex::new("([[:alpha:]]+)=([[:digit:]]+)(x)?", regcomp_flags).expect("Regex::new");
+
+    let result = compiled_reg
+        .replace("a=1 b=2", "$2=$1$3", regexec_flags)
+        .expect("replace");
+    assert_eq!(result, "1=a b=2");
+
+    let result = compiled_reg
+        .replace_all("a=1 b=2", "${2}$$", regexec_flags)
+        .expect("replace_all");
+    assert_eq!(result, "1$ 2$");
+
+    let result = compiled_reg
+        .replace("none", "$1", regexec_flags)
+        .expect("replace");
+    assert!(matches!(result, Cow::Borrowed("none")));
+    let result = compiled_reg
+        .replace_all("none", "$1", regexec_flags)
+        .expect("replace_all");
+    assert!(matches!(result, Cow::Borrowed("none")));
+}
+
+#[test]
+fn replace_all_with_works() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    let compiled_reg = Regex::new("[[:alpha:]]+", regcomp_flags).expect("Regex::new");
+
+    let result = compiled_reg
+        .replace_all_with("one, two; three", regexec_flags, |caps| {
+            caps.get(0).unwrap_or("").to_uppercase()
+        })
+        .expect("replace_all_with");
+    assert_eq!(result, "ONE, TWO; THREE");
+
+    let mut calls = 0;
+    let result = compiled_reg
+        .replace_all_with("1, 2", regexec_flags, |_| {
+            calls += 1;
+            String::new()
+        })
+        .expect("replace_all_with");
+    assert!(matches!(result, Cow::Borrowed("1, 2")));
+    assert_eq!(calls, 0);
+
+    let compiled_reg =
+        Regex::new_multiline("^([[:alpha:]])", RegcompFlags::new()).expect("Regex::new");
+    let result = compiled_reg
+        .replace_all_with("ab\ncd\n", regexec_flags, |caps| {
+            caps.get(1).unwrap_or("").to_uppercase()
+        })
+        .expect("replace_all_with");
+    assert_eq!(result, "Ab\nCd\n");
+}
+
+#[test]
+fn replacen_with_works() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    let compiled_reg = Regex::new("([a-z])(x)?", regcomp_flags).expect("Regex::new");
+
+    let describe = |groups: &[Option<&str>]| {
+        assert_eq!(groups.len(), 3);
+        format!(
+            "<{}{}>",
+            groups[1].unwrap_or("?"),
+            groups[2].map_or(0, str::len)
+        )
+    };
+    let result = compiled_reg
+        .replacen_with("axb c", 2, regexec_flags, describe)
+        .expect("replacen_with");
+    assert_eq!(result, "<a1><b0> c");
+
+    // A limit of zero replaces every match
+    let result = compiled_reg
+        .replacen_with("axb c", 0, regexec_flags, describe)
+        .expect("replacen_with");
+    assert_eq!(result, "<a1><b0> <c0>");
+
+    let result = compiled_reg
+        .replacen_with("123", 0, regexec_flags, describe)
+        .expect("replacen_with");
+    assert!(matches!(result, Cow::Borrowed("123")));
+}
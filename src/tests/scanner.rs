@@ -0,0 +1,55 @@
+use crate::{BindingErrorCode, ErrorKind, RegcompFlags, Regex, RegexecFlags, Result, Scanner};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Kind {
+    Keyword,
+    Ident,
+    Space,
+}
+
+#[test]
+fn scanner_works() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    let scanner = Scanner::new(
+        vec![
+            (
+                Kind::Keyword,
+                Regex::new("^(let|in)\\>", regcomp_flags).expect("Regex::new"),
+            ),
+            (
+                Kind::Ident,
+                Regex::new("^[[:alpha:]]+", regcomp_flags).expect("Regex::new"),
+            ),
+            (
+                Kind::Space,
+                Regex::new("^[[:space:]]*", regcomp_flags).expect("Regex::new"),
+            ),
+        ],
+        regexec_flags,
+    );
+
+    let tokens = scanner
+        .tokens("let letter in x")
+        .collect::<Result<Vec<_>>>()
+        .expect("tokens");
+    assert_eq!(
+        tokens,
+        vec![
+            (Kind::Keyword, "let", 0..3),
+            (Kind::Space, " ", 3..4),
+            (Kind::Ident, "letter", 4..10),
+            (Kind::Space, " ", 10..11),
+            (Kind::Keyword, "in", 11..13),
+            (Kind::Space, " ", 13..14),
+            (Kind::Ident, "x", 14..15),
+        ]
+    );
+
+    assert_eq!(scanner.tokens("").count(), 0);
+
+    let tokens = scanner.tokens("a 1 b").collect::<Vec<_>>();
+    assert_eq!(tokens.len(), 3);
+    let err = tokens[2].as_ref().expect_err("no token");
+    assert_eq!(err.kind, ErrorKind::Binding(BindingErrorCode::NO_TOKEN));
+}
@@ -0,0 +1,41 @@
+use std::iter::Copied;
+use std::slice;
+
+use ::serde_core::{
+    de::value::{Error, SeqDeserializer},
+    Deserialize,
+};
+
+use crate::{RegcompFlags, RegexecFlags};
+
+fn names<'a>(
+    names: &'a [&'static str],
+) -> SeqDeserializer<Copied<slice::Iter<'a, &'static str>>, Error> {
+    SeqDeserializer::new(names.iter().copied())
+}
+
+#[test]
+fn deserialize_flags_works() {
+    let regcomp_flags =
+        RegcompFlags::deserialize(names(&["EXTENDED", "ICASE"])).expect("deserialize");
+    assert_eq!(
+        regcomp_flags.get(),
+        RegcompFlags::EXTENDED | RegcompFlags::ICASE
+    );
+
+    let regexec_flags = RegexecFlags::deserialize(names(&[])).expect("deserialize");
+    assert_eq!(regexec_flags, RegexecFlags::new());
+    let regexec_flags = RegexecFlags::deserialize(names(&["NOTEOL"])).expect("deserialize");
+    assert_eq!(regexec_flags, RegexecFlags::new().add(RegexecFlags::NOTEOL));
+}
+
+#[test]
+fn deserialize_flags_rejects_unknown_names() {
+    let Err(err) = RegcompFlags::deserialize(names(&["EXTENDED", "EXTENDDE"])) else {
+        panic!("deserialize with an unknown name");
+    };
+    assert!(err.to_string().contains("unknown flag `EXTENDDE`"));
+
+    // Flags of the other kind are unknown too
+    assert!(RegexecFlags::deserialize(names(&["ICASE"])).is_err());
+}
@@ -0,0 +1,49 @@
+use crate::{BindingErrorCode, ErrorKind, RegcompFlags, Regex, RegexSet, RegexecFlags};
+
+#[test]
+fn longest_match_at_works() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    let set =
+        RegexSet::new(&["^=", "^==", "^[=!]=", "^[a-z]*"], regcomp_flags).expect("RegexSet::new");
+    assert_eq!(set.len(), 4);
+    assert!(!set.is_empty());
+
+    // The longest match wins...
+    let found = set
+        .longest_match_at("a == b", 2, regexec_flags)
+        .expect("longest_match_at");
+    assert_eq!(found, Some((1, 2..4)));
+
+    // ...with ties going to the earlier pattern
+    let found = set
+        .longest_match_at("a != b", 2, regexec_flags)
+        .expect("longest_match_at");
+    assert_eq!(found, Some((2, 2..4)));
+    let found = set
+        .longest_match_at("a = b", 2, regexec_flags)
+        .expect("longest_match_at");
+    assert_eq!(found, Some((0, 2..3)));
+
+    // An empty match is only returned if nothing longer matches
+    let found = set
+        .longest_match_at("a + b", 2, regexec_flags)
+        .expect("longest_match_at");
+    assert_eq!(found, Some((3, 2..2)));
+
+    // Matches further along are ignored
+    let set = RegexSet::from_regexes(vec![Regex::new("b", regcomp_flags).expect("Regex::new")]);
+    let found = set
+        .longest_match_at("a b", 0, regexec_flags)
+        .expect("longest_match_at");
+    assert_eq!(found, None);
+
+    let Err(e) = set.longest_match_at("é", 1, regexec_flags) else {
+        panic!("longest_match_at");
+    };
+    assert_eq!(e.kind, ErrorKind::Binding(BindingErrorCode::ENCODING));
+    let Err(e) = set.longest_match_at("é", 3, regexec_flags) else {
+        panic!("longest_match_at");
+    };
+    assert_eq!(e.kind, ErrorKind::Binding(BindingErrorCode::OUT_OF_BOUNDS));
+}
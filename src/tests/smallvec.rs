@@ -0,0 +1,28 @@
+use crate::{RegcompFlags, Regex, RegexecFlags, SMALL_MATCHES};
+
+#[test]
+fn regexec_small_works() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+
+    let compiled_reg = Regex::new("^(hello)(,)? (world)$", regcomp_flags).expect("Regex::new");
+    let matches = compiled_reg
+        .regexec_small("hello world", 4, regexec_flags)
+        .expect("regexec_small");
+    assert!(!matches.spilled());
+    assert_eq!(
+        matches.as_slice(),
+        &[Some("hello world"), Some("hello"), None, Some("world")]
+    );
+
+    // More groups than fit inline
+    let matches = compiled_reg
+        .regexec_small("hello world", SMALL_MATCHES + 1, regexec_flags)
+        .expect("regexec_small");
+    assert!(matches.spilled());
+    assert_eq!(matches.len(), SMALL_MATCHES + 1);
+
+    assert!(compiled_reg
+        .regexec_small("goodbye world", 4, regexec_flags)
+        .is_err());
+}
@@ -0,0 +1,75 @@
+use crate::{BindingErrorCode, ErrorKind, RegcompFlags, Regex, RegexecFlags, StreamMatch};
+
+#[test]
+fn find_stream_marks_provisional() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    let compiled_reg = Regex::new("[[:digit:]]+", regcomp_flags).expect("Regex::new");
+
+    let found = compiled_reg
+        .find_stream(b"a 1 22", 0, true, regexec_flags)
+        .expect("find_stream");
+    assert_eq!(
+        found,
+        Some(StreamMatch {
+            range: 2..3,
+            provisional: false
+        })
+    );
+
+    let found = compiled_reg
+        .find_stream(b"a 1 22", 3, true, regexec_flags)
+        .expect("find_stream");
+    assert_eq!(
+        found,
+        Some(StreamMatch {
+            range: 4..6,
+            provisional: true
+        })
+    );
+
+    let found = compiled_reg
+        .find_stream(b"a 1 22", 3, false, regexec_flags)
+        .expect("find_stream");
+    assert_eq!(
+        found,
+        Some(StreamMatch {
+            range: 4..6,
+            provisional: false
+        })
+    );
+}
+
+#[test]
+fn find_stream_end_anchor() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    let compiled_reg = Regex::new("end$", regcomp_flags).expect("Regex::new");
+
+    let found = compiled_reg
+        .find_stream(b"the end", 0, true, regexec_flags)
+        .expect("find_stream");
+    assert_eq!(found, None);
+
+    let found = compiled_reg
+        .find_stream(b"the end", 0, false, regexec_flags)
+        .expect("find_stream");
+    assert_eq!(found.map(|matched| matched.range), Some(4..7));
+}
+
+#[test]
+fn find_stream_context() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    let compiled_reg = Regex::new("\\<ab", regcomp_flags).expect("Regex::new");
+
+    let found = compiled_reg
+        .find_stream(b"abab ab", 2, true, regexec_flags)
+        .expect("find_stream");
+    assert_eq!(found.map(|matched| matched.range), Some(5..7));
+
+    let err = compiled_reg
+        .find_stream(b"ab", 3, true, regexec_flags)
+        .expect_err("find_stream should fail");
+    assert_eq!(err.kind, ErrorKind::Binding(BindingErrorCode::OUT_OF_BOUNDS));
+}
@@ -0,0 +1,45 @@
+use std::ops::Range;
+
+use crate::{byte_to_char_index, char_range_of, normalize_eol};
+
+#[test]
+fn byte_to_char_index_works() {
+    let string = "私の名前はエリザベスです";
+    assert_eq!(byte_to_char_index(string, 0), Some(0));
+    assert_eq!(byte_to_char_index(string, 15), Some(5));
+    assert_eq!(byte_to_char_index(string, string.len()), Some(12));
+    assert_eq!(byte_to_char_index(string, 16), None);
+    assert_eq!(byte_to_char_index(string, string.len() + 1), None);
+}
+
+#[test]
+fn char_range_of_works() {
+    let string = "私の名前はエリザベスです";
+    assert_eq!(char_range_of(string, 15..30), Some(5..10));
+    assert_eq!(char_range_of(string, 15..15), Some(5..5));
+    assert_eq!(char_range_of(string, 15..31), None);
+    assert_eq!(char_range_of(string, Range { start: 30, end: 15 }), None);
+}
+
+#[test]
+fn normalize_eol_works() {
+    let data = b"ab\r\n\r\ncd\r\ne";
+    let normalized = normalize_eol(data, b"\r\n");
+    assert_eq!(normalized.as_bytes(), b"ab\n\ncd\ne");
+    assert_eq!(normalized.original_offset(0), 0);
+    assert_eq!(normalized.original_offset(2), 2);
+    assert_eq!(normalized.original_offset(3), 4);
+    assert_eq!(normalized.original_offset(4), 6);
+    assert_eq!(normalized.original_range(4..6), 6..8);
+    assert_eq!(normalized.original_range(6..8), 8..11);
+    assert_eq!(normalized.original_offset(100), data.len());
+
+    // Single-byte delimiters do not move offsets
+    let normalized = normalize_eol(b"a\0b\nc\0", b"\0");
+    assert_eq!(normalized.as_bytes(), b"a\nb\nc\n");
+    assert_eq!(normalized.original_range(2..5), 2..5);
+
+    let normalized = normalize_eol(b"a\r\nb", b"");
+    assert_eq!(normalized.as_bytes(), b"a\r\nb");
+    assert_eq!(normalized.original_offset(3), 3);
+}
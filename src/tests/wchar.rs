@@ -0,0 +1,148 @@
+use widestring::{widestr, WideString};
+
+use crate::{is_wchar_supported, RegcompFlags, Regex, RegexecFlags, WideMatchBuf};
+
+#[test]
+fn narrow_and_wide_works() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+
+    let compiled_reg = Regex::new("^(h[a-z]+)$", regcomp_flags).expect("Regex::new");
+    let result = compiled_reg
+        .regexec("hello", 2, regexec_flags)
+        .expect("regexec");
+    assert_eq!(*result[1].as_ref().unwrap().as_ref().unwrap(), "hello");
+    let result = compiled_reg
+        .regwexec(widestr!("hello"), 2, regexec_flags)
+        .expect("regwexec");
+    assert_eq!(result[1].as_deref(), Some(widestr!("hello")));
+
+    let compiled_reg =
+        Regex::new_wide(widestr!("^(h[a-z]+)$"), regcomp_flags).expect("Regex::new_wide");
+    let result = compiled_reg
+        .regexec("hello", 2, regexec_flags)
+        .expect("regexec");
+    assert_eq!(*result[1].as_ref().unwrap().as_ref().unwrap(), "hello");
+}
+
+#[test]
+fn regwexec_into_works() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    let compiled_reg =
+        Regex::new_wide(widestr!("^([a-z]+)(=(.*))?$"), regcomp_flags).expect("Regex::new_wide");
+
+    let mut buf = WideMatchBuf::new(4);
+    assert_eq!(buf.len(), 4);
+
+    let matches = compiled_reg
+        .regwexec_into(widestr!("key=value"), &mut buf, regexec_flags)
+        .expect("regwexec_into")
+        .collect::<Vec<_>>();
+    assert_eq!(matches.len(), 4);
+    assert_eq!(matches[1].as_deref(), Some(widestr!("key")));
+    assert_eq!(matches[3].as_deref(), Some(widestr!("value")));
+
+    let matches = compiled_reg
+        .regwexec_into(widestr!("flag"), &mut buf, regexec_flags)
+        .expect("regwexec_into")
+        .collect::<Vec<_>>();
+    assert_eq!(matches[1].as_deref(), Some(widestr!("flag")));
+    assert!(matches[3].is_none());
+
+    assert!(compiled_reg
+        .regwexec_into(widestr!("1"), &mut buf, regexec_flags)
+        .is_err());
+}
+
+#[test]
+fn is_wchar_supported_works() {
+    // The vendored TRE is always built with wide character support.
+    if cfg!(feature = "vendored") {
+        assert!(is_wchar_supported());
+    }
+}
+
+#[test]
+fn wide_queries_work() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    let compiled_reg =
+        Regex::new_wide(widestr!("([a-z]+)(=([0-9]+))?"), regcomp_flags).expect("Regex::new_wide");
+
+    assert!(compiled_reg
+        .is_wmatch(widestr!("ナ key=1"), regexec_flags)
+        .expect("is_wmatch"));
+    assert!(!compiled_reg
+        .is_wmatch(widestr!("ナ 123"), regexec_flags)
+        .expect("is_wmatch"));
+
+    assert_eq!(
+        compiled_reg
+            .find_wide(widestr!("ナ key=1"), regexec_flags)
+            .expect("find_wide"),
+        Some((2, 7))
+    );
+    assert_eq!(
+        compiled_reg
+            .find_wide(widestr!("ナ 123"), regexec_flags)
+            .expect("find_wide"),
+        None
+    );
+
+    let caps = compiled_reg
+        .captures_wide(widestr!("ナ key"), 4, regexec_flags)
+        .expect("captures_wide")
+        .expect("no match");
+    assert_eq!(caps.len(), 4);
+    assert_eq!(caps.get(0), Some(widestr!("key")));
+    assert_eq!(caps.pos(1), Some((2, 5)));
+    assert_eq!(caps.get(3), None);
+    assert_eq!(
+        caps.iter().collect::<Vec<_>>(),
+        vec![Some(widestr!("key")), Some(widestr!("key")), None, None]
+    );
+
+    assert!(compiled_reg
+        .captures_wide(widestr!("123"), 4, regexec_flags)
+        .expect("captures_wide")
+        .is_none());
+}
+
+#[test]
+fn wide_pattern_is_stored() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    let compiled_reg =
+        Regex::new_wide(widestr!("^caf(é)$"), regcomp_flags).expect("Regex::new_wide");
+    assert!(compiled_reg.is_wide());
+    assert_eq!(compiled_reg.as_str(), Some("^caf(é)$"));
+    assert_eq!(compiled_reg.as_pattern_bytes(), "^caf(é)$".as_bytes());
+
+    let copy = compiled_reg.try_clone().expect("try_clone");
+    assert!(copy.is_wide());
+    let result = copy
+        .regwexec(widestr!("café"), 2, regexec_flags)
+        .expect("regwexec");
+    assert_eq!(result[1].as_deref(), Some(widestr!("é")));
+}
+
+#[test]
+fn non_unicode_wide_pattern_clones() {
+    let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+
+    // A lone surrogate has no UTF-8 form
+    let pattern = WideString::from_vec(vec![0x61, 0xD800]);
+    let compiled_reg = Regex::new_wide(&pattern, regcomp_flags).expect("Regex::new_wide");
+    assert!(compiled_reg.is_wide());
+    assert_eq!(compiled_reg.as_str(), None);
+
+    let copy = compiled_reg.try_clone().expect("try_clone");
+    assert!(copy.is_wide());
+    assert!(copy.regwexec(&pattern, 1, regexec_flags).expect("regwexec")[0].is_some());
+
+    #[allow(clippy::redundant_clone)]
+    let copy = compiled_reg.clone();
+    assert!(copy.is_wide());
+}
@@ -0,0 +1,171 @@
+use std::ops::Range;
+
+use crate::exec::find_bytes;
+
+/// Converts a byte offset into `string` into a character index.
+///
+/// This is useful for turning the byte offsets returned by functions such as
+/// [`Regex::find_iter`](crate::Regex::find_iter) into character columns.
+///
+/// # Arguments
+/// * `string`: string the offset refers to
+/// * `byte`: byte offset into `string`; may be equal to its length
+///
+/// # Returns
+/// The number of characters before `byte`, or `None` if `byte` is out of bounds or not on a
+/// character boundary.
+///
+/// # Examples
+/// ```
+/// use tre_regex::byte_to_char_index;
+///
+/// assert_eq!(byte_to_char_index("héllo", 3), Some(2));
+/// assert_eq!(byte_to_char_index("héllo", 2), None);
+/// ```
+#[must_use]
+pub fn byte_to_char_index(string: &str, byte: usize) -> Option<usize> {
+    if !string.is_char_boundary(byte) {
+        return None;
+    }
+
+    Some(string[..byte].chars().count())
+}
+
+/// Converts a range of byte offsets into `string` into a range of character indices.
+///
+/// # Arguments
+/// * `string`: string the range refers to
+/// * `range`: range of byte offsets into `string`
+///
+/// # Returns
+/// The corresponding range of character indices, or `None` if either end of `range` is out of
+/// bounds or not on a character boundary, or if `range` is reversed.
+///
+/// # Examples
+/// ```
+/// use tre_regex::char_range_of;
+///
+/// assert_eq!(char_range_of("héllo", 1..4), Some(1..3));
+/// ```
+#[must_use]
+pub fn char_range_of(string: &str, range: Range<usize>) -> Option<Range<usize>> {
+    if range.start > range.end || !string.is_char_boundary(range.end) {
+        return None;
+    }
+
+    let start = byte_to_char_index(string, range.start)?;
+    let end = start + string[range].chars().count();
+    Some(start..end)
+}
+
+/// Input with its line delimiters replaced by `\n`, as returned by [`normalize_eol`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NormalizedEol {
+    data: Vec<u8>,
+    delim_len: usize,
+
+    // Offsets into data of each replaced delimiter
+    breaks: Vec<usize>,
+}
+
+impl NormalizedEol {
+    /// Gets the normalized input, to be matched against.
+    #[must_use]
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Converts a byte offset into the normalized input back into an offset into the original.
+    ///
+    /// An offset at a `\n` which replaced a delimiter maps to the start of that delimiter. An
+    /// offset past the end of the normalized input is mapped as if it were at the end.
+    #[must_use]
+    pub fn original_offset(&self, offset: usize) -> usize {
+        let offset = offset.min(self.data.len());
+        let replaced = self.breaks.partition_point(|&pos| pos < offset);
+        offset + replaced * (self.delim_len - 1)
+    }
+
+    /// Converts a range of byte offsets into the normalized input, such as a match, back into a
+    /// range in the original.
+    ///
+    /// A range ending just after a `\n` which replaced a delimiter ends after the whole
+    /// delimiter.
+    #[must_use]
+    pub fn original_range(&self, range: Range<usize>) -> Range<usize> {
+        self.original_offset(range.start)..self.original_offset(range.end)
+    }
+}
+
+/// Replaces every occurrence of `delim` in `data` with `\n`, so that it separates lines for
+/// [`RegcompFlags::NEWLINE`](crate::RegcompFlags::NEWLINE).
+///
+/// TRE only treats `\n` as a line separator, and this cannot be configured. This allows `^`, `$`,
+/// and `.` to work per record for data with other delimiters, such as `\r\n` or `\0`, without
+/// splitting it first.
+///
+/// # Arguments
+/// * `data`: input to normalize
+/// * `delim`: line delimiter used by `data`; if empty, nothing is replaced
+///
+/// # Returns
+/// A [`NormalizedEol`], holding the input to match against, and mapping offsets in it back into
+/// offsets in `data`.
+///
+/// # Caveats
+/// Offsets found by matching against [`NormalizedEol::as_bytes`] are offsets into the normalized
+/// input, not into `data`, unless `delim` is a single byte. They must be converted with
+/// [`NormalizedEol::original_offset`] or [`NormalizedEol::original_range`] before being used with
+/// `data`.
+///
+/// Any `\n` already in `data` is left alone, so it still separates lines. A pattern which
+/// matches a `\n` also matches a replaced delimiter, and the mapped range then covers the whole
+/// delimiter.
+///
+/// # Examples
+/// ```
+/// # use tre_regex::Result;
+/// # fn main() -> Result<()> {
+/// use tre_regex::{normalize_eol, RegcompFlags, RegexecFlags, Regex};
+///
+/// let regcomp_flags = RegcompFlags::new()
+///     .add(RegcompFlags::EXTENDED)
+///     .add(RegcompFlags::NEWLINE);
+/// let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+///
+/// let data = b"name=a\r\nsize=12\r\n";
+/// let normalized = normalize_eol(data, b"\r\n");
+///
+/// let compiled_reg = Regex::new("^size=[0-9]+$", regcomp_flags)?;
+/// let matched = compiled_reg
+///     .find_stream(normalized.as_bytes(), 0, false, regexec_flags)?
+///     .expect("match");
+///
+/// let range = normalized.original_range(matched.range);
+/// assert_eq!(&data[range], b"size=12");
+/// # Ok(())
+/// # }
+/// ```
+#[must_use]
+pub fn normalize_eol(data: &[u8], delim: &[u8]) -> NormalizedEol {
+    let mut normalized = NormalizedEol {
+        data: Vec::with_capacity(data.len()),
+        delim_len: delim.len().max(1),
+        breaks: Vec::new(),
+    };
+    if delim.is_empty() {
+        normalized.data.extend_from_slice(data);
+        return normalized;
+    }
+
+    let mut rest = data;
+    while let Some(i) = find_bytes(rest, delim) {
+        normalized.data.extend_from_slice(&rest[..i]);
+        normalized.breaks.push(normalized.data.len());
+        normalized.data.push(b'\n');
+        rest = &rest[i + delim.len()..];
+    }
+    normalized.data.extend_from_slice(rest);
+    normalized
+}
@@ -1,13 +1,24 @@
 use std::borrow::Cow;
 
-use widestring::WideStr;
+use widestring::{WideStr, WideString};
 
 use crate::{
     err::{BindingErrorCode, ErrorKind, RegexError, Result},
+    exec::check_len,
+    owned::IntoOwnedMatches,
     tre, RegApproxMatch, RegApproxParams, Regex, RegexecFlags,
 };
 
 pub type RegApproxMatchWideStr<'a> = RegApproxMatch<&'a WideStr, Cow<'a, WideStr>>;
+pub type RegApproxMatchWideStrOwned = RegApproxMatch<WideString, WideString>;
+
+impl IntoOwnedMatches for RegApproxMatchWideStr<'_> {
+    type Owned = RegApproxMatchWideStrOwned;
+
+    fn into_owned(self) -> Self::Owned {
+        self.map_owned(WideStr::to_ustring, Cow::into_owned)
+    }
+}
 
 impl Regex {
     /// Performs an approximate regex search on the passed wide string, returning `nmatches`
@@ -29,7 +40,8 @@ impl Regex {
     /// be returned.
     ///
     /// # Errors
-    /// If an error is encountered during matching, it returns a [`RegexError`].
+    /// If an error is encountered during matching, it returns a [`RegexError`]. An error is also
+    /// returned if `string` is too long; see [Input length](crate::Regex#input-length).
     ///
     /// # Caveats
     /// Unless copied, the match results must live at least as long as `string`. This is because they are
@@ -64,10 +76,10 @@ impl Regex {
     ///     regaexec_flags              // Flags
     /// )?;
     ///
-    /// for (i, matched) in result.get_matches().into_iter().enumerate() {
+    /// for matched in &result {
     ///     match matched {
-    ///         Some(substr) => println!("Match {i}: {}", substr.display()),
-    ///         None => println!("Match {i}: <None>"),
+    ///         Some(substr) => println!("Match: {}", substr.display()),
+    ///         None => println!("Match: <None>"),
     ///     }
     /// }
     /// # Ok(())
@@ -88,6 +100,7 @@ impl Regex {
                 "Attempted to unwrap a vacant Regex object"
             ));
         };
+        check_len(string.len())?;
         let mut match_vec: Vec<tre::regmatch_t> =
             vec![tre::regmatch_t { rm_so: 0, rm_eo: 0 }; nmatches];
         let mut amatch = tre::regamatch_t {
@@ -110,9 +123,10 @@ impl Regex {
             )
         };
         if result != 0 {
-            return Err(self.regerror(result));
+            return Err(self.exec_error(result));
         }
 
+        let start = match_vec.first().and_then(|pmatch| usize::try_from(pmatch.rm_so).ok());
         let mut result: Vec<Option<Cow<'a, WideStr>>> = Vec::with_capacity(nmatches);
         for pmatch in match_vec {
             if pmatch.rm_so < 0 || pmatch.rm_eo < 0 {
@@ -129,7 +143,9 @@ impl Regex {
             result.push(Some(Cow::Borrowed(&string[start_offset..end_offset])));
         }
 
-        Ok(RegApproxMatchWideStr::new(string, result, amatch))
+        Ok(RegApproxMatchWideStr::new(string, result, amatch)
+            .with_start(start)
+            .with_params(*params))
     }
 }
 
@@ -189,10 +205,10 @@ impl Regex {
 ///     regaexec_flags              // Flags
 /// )?;
 ///
-/// for (i, matched) in result.get_matches().into_iter().enumerate() {
+/// for matched in &result {
 ///     match matched {
-///         Some(substr) => println!("Match {i}: {}", substr.display()),
-///         None => println!("Match {i}: <None>"),
+///         Some(substr) => println!("Match: {}", substr.display()),
+///         None => println!("Match: <None>"),
 ///     }
 /// }
 /// # Ok(())
@@ -3,7 +3,7 @@ use std::mem;
 use widestring::WideStr;
 
 use crate::{
-    err::{regerror, Result},
+    err::{regerror, ErrorPhase, Result},
     flags::RegcompFlags,
     tre, Regex,
 };
@@ -11,6 +11,9 @@ use crate::{
 impl Regex {
     /// Compiles a regex contained in a [`WideStr`] and wraps it in a `Regex` object.
     ///
+    /// The resulting object can be matched against narrow strings too; see
+    /// [Narrow and wide strings](crate::Regex#narrow-and-wide-strings).
+    ///
     /// # Arguments
     /// * `reg`: regular expression to compile, as a [`WideStr`] .
     /// * `flags`: [`RegcompFlags`] to pass to the function.
@@ -19,7 +22,8 @@ impl Regex {
     /// An opaque [`Regex`] object will be returned. It will be freed automatically when dropped.
     ///
     /// # Errors
-    /// Will return a [`RegexError`] upon failure.
+    /// Will return a [`RegexError`] upon failure. This includes the case where the linked TRE was
+    /// built without wide character support; use [`is_wchar_supported`] to check for that first.
     ///
     /// # Examples
     /// ```
@@ -45,6 +49,7 @@ impl Regex {
     /// ```
     ///
     /// [`RegexError`]: crate::RegexError
+    /// [`is_wchar_supported`]: crate::is_wchar_supported
     pub fn new_wide(reg: &WideStr, flags: RegcompFlags) -> Result<Self> {
         let mut unwrapped_compiled_reg = mem::MaybeUninit::<tre::regex_t>::uninit();
 
@@ -61,9 +66,18 @@ impl Regex {
         };
 
         // SAFETY: tre::tre_regcomp fully initalises compiled_reg
-        let compiled_reg = Self(Some(unsafe { unwrapped_compiled_reg.assume_init() }));
+        let compiled_reg = Self {
+            regex: Some(unsafe { unwrapped_compiled_reg.assume_init() }),
+            pattern: reg.to_string().ok().map(|pattern| pattern.into_bytes().into()),
+            flags,
+            literal: false,
+            wide_pattern: Some(reg.to_owned()),
+            owned: true,
+        };
         if result != 0 {
-            return Err(regerror(&compiled_reg, result));
+            return Err(regerror(&compiled_reg, result)
+                .with_pattern(&reg.to_string_lossy())
+                .with_phase(ErrorPhase::Compile));
         }
 
         Ok(compiled_reg)
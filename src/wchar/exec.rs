@@ -1,10 +1,142 @@
 use std::borrow::Cow;
+use std::ffi::c_uint;
+use std::ptr::null_mut;
 
-use widestring::WideStr;
+use widestring::{WideStr, WideString};
 
-use crate::{err::*, flags::*, tre, Regex};
+use crate::{
+    err::*,
+    exec::{check_len, match_range},
+    flags::*,
+    owned::IntoOwnedMatches,
+    tre, Regex,
+};
 
 pub type RegMatchWideStr<'a> = Vec<Option<Cow<'a, WideStr>>>;
+pub type RegMatchWideStrOwned = Vec<Option<WideString>>;
+
+impl IntoOwnedMatches for RegMatchWideStr<'_> {
+    type Owned = RegMatchWideStrOwned;
+
+    fn into_owned(self) -> Self::Owned {
+        self.into_iter()
+            .map(|matched| matched.map(Cow::into_owned))
+            .collect()
+    }
+}
+
+/// Reusable storage for match offsets, for use with [`Regex::regwexec_into`].
+#[derive(Clone, Debug)]
+pub struct WideMatchBuf {
+    pmatch: Vec<tre::regmatch_t>,
+}
+
+impl WideMatchBuf {
+    /// Creates a buffer with room for `nmatches` matches.
+    #[must_use]
+    pub fn new(nmatches: usize) -> Self {
+        Self {
+            pmatch: vec![tre::regmatch_t { rm_so: -1, rm_eo: -1 }; nmatches],
+        }
+    }
+
+    /// Gets the number of matches the buffer has room for.
+    #[must_use]
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.pmatch.len()
+    }
+
+    /// Returns `true` if the buffer has no room for any matches.
+    #[must_use]
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.pmatch.is_empty()
+    }
+}
+
+/// Iterator over the matches stored in a [`WideMatchBuf`], returned by [`Regex::regwexec_into`].
+#[derive(Clone, Debug)]
+pub struct WideMatches<'a, 'b> {
+    string: &'a WideStr,
+    pmatch: std::slice::Iter<'b, tre::regmatch_t>,
+}
+
+impl<'a> Iterator for WideMatches<'a, '_> {
+    type Item = Option<Cow<'a, WideStr>>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let string = self.string;
+        self.pmatch.next().map(|&pmatch| wide_match(string, pmatch))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.pmatch.size_hint()
+    }
+}
+
+impl ExactSizeIterator for WideMatches<'_, '_> {}
+
+/// Capture groups of a single match in a wide string, returned by [`Regex::captures_wide`].
+///
+/// Group 0 is the whole match; groups 1 and up are the parenthesised subexpressions. Offsets are
+/// in wide characters.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WideCaptures<'a> {
+    string: &'a WideStr,
+    locs: Vec<Option<(usize, usize)>>,
+}
+
+impl<'a> WideCaptures<'a> {
+    /// Gets the text of group `i`, or `None` if the group did not participate in the match.
+    #[must_use]
+    pub fn get(&self, i: usize) -> Option<&'a WideStr> {
+        let (start, end) = self.pos(i)?;
+        Some(&self.string[start..end])
+    }
+
+    /// Gets the start and end offsets of group `i`, or `None` if the group did not participate
+    /// in the match.
+    #[must_use]
+    #[inline]
+    pub fn pos(&self, i: usize) -> Option<(usize, usize)> {
+        self.locs.get(i).copied().flatten()
+    }
+
+    /// Gets the number of groups, including group 0.
+    #[must_use]
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.locs.len()
+    }
+
+    /// Returns `true` if there are no groups at all.
+    #[must_use]
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.locs.is_empty()
+    }
+
+    /// Returns an iterator over the text of every group, in order.
+    pub fn iter(&self) -> impl Iterator<Item = Option<&'a WideStr>> + '_ {
+        (0..self.len()).map(|i| self.get(i))
+    }
+
+    /// Gets the string the captures were taken from.
+    #[must_use]
+    #[inline]
+    pub const fn get_orig_string(&self) -> &'a WideStr {
+        self.string
+    }
+}
+
+/// Converts a filled-in [`regmatch_t`](tre_regex_sys::regmatch_t) into a slice of `string`.
+fn wide_match(string: &WideStr, pmatch: tre::regmatch_t) -> Option<Cow<'_, WideStr>> {
+    let (start, end) = match_range(pmatch, 0)?;
+    Some(Cow::Borrowed(&string[start..end]))
+}
 
 impl Regex {
     /// Performs a regex search on the passed wide string, returning `nmatches` results.
@@ -24,7 +156,8 @@ impl Regex {
     /// contain a [`WideStr`].
     ///
     /// # Errors
-    /// If an error is encountered during matching, it returns a [`RegexError`].
+    /// If an error is encountered during matching, it returns a [`RegexError`]. An error is also
+    /// returned if `string` is too long; see [Input length](crate::Regex#input-length).
     ///
     /// # Caveats
     /// Unless copied, the match results must live at least as long as `string`. This is because
@@ -62,14 +195,241 @@ impl Regex {
         nmatches: usize,
         flags: RegexecFlags,
     ) -> Result<RegMatchWideStr<'a>> {
+        let mut match_vec: Vec<tre::regmatch_t> =
+            vec![tre::regmatch_t { rm_so: 0, rm_eo: 0 }; nmatches];
+        self.regwexec_slice_matched(string, &mut match_vec, flags)?;
+
+        Ok(match_vec
+            .into_iter()
+            .map(|pmatch| wide_match(string, pmatch))
+            .collect())
+    }
+
+    /// Performs a regex search on the passed wide string, reusing the storage in `buf`.
+    ///
+    /// This is like [`Regex::regwexec`], but does not allocate on every call, which matters when
+    /// matching a pattern against many strings. The number of matches returned is the length of
+    /// `buf`, as given to [`WideMatchBuf::new`].
+    ///
+    /// # Arguments
+    /// * `string`: [`WideStr`] to match against `compiled_reg`
+    /// * `buf`: buffer to store the match offsets in
+    /// * `flags`: [`RegexecFlags`] to pass to [`tre_regwnexec`](tre_regex_sys::tre_regwnexec).
+    ///
+    /// # Returns
+    /// If no error was found, a [`WideMatches`] iterator over the matches. As with
+    /// [`Regex::regwexec`], a given match is `None` if it is empty, otherwise it contains a
+    /// [`WideStr`] borrowed from `string`.
+    ///
+    /// # Errors
+    /// If an error is encountered during matching, it returns a [`RegexError`]. An error is also
+    /// returned if `string` is too long; see [Input length](crate::Regex#input-length).
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use tre_regex::{RegcompFlags, RegexecFlags, Regex, WideMatchBuf};
+    /// use widestring::widestr;
+    ///
+    /// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    /// let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    ///
+    /// let compiled_reg = Regex::new_wide(widestr!("^([a-z]+)=(.*)$"), regcomp_flags)?;
+    /// let mut buf = WideMatchBuf::new(3);
+    /// for value in [widestr!("path=C:\\"), widestr!("user=eliza")] {
+    ///     let mut matches = compiled_reg.regwexec_into(value, &mut buf, regexec_flags)?;
+    ///     if let Some(Some(name)) = matches.nth(1) {
+    ///         println!("Name: {}", name.display());
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`RegexError`]: crate::RegexError
+    pub fn regwexec_into<'a, 'b>(
+        &self,
+        string: &'a WideStr,
+        buf: &'b mut WideMatchBuf,
+        flags: RegexecFlags,
+    ) -> Result<WideMatches<'a, 'b>> {
+        self.regwexec_slice_matched(string, &mut buf.pmatch, flags)?;
+
+        Ok(WideMatches {
+            string,
+            pmatch: buf.pmatch.iter(),
+        })
+    }
+
+    /// Checks whether the pattern matches anywhere in the wide string `string`.
+    ///
+    /// This is the wide counterpart of [`Regex::is_match`]. No match offsets are requested from
+    /// TRE, so this does not allocate.
+    ///
+    /// # Arguments
+    /// * `string`: [`WideStr`] to match against `compiled_reg`
+    /// * `flags`: [`RegexecFlags`] to pass to [`tre_regwnexec`](tre_regex_sys::tre_regwnexec).
+    ///
+    /// # Returns
+    /// `true` if the pattern matched, `false` otherwise.
+    ///
+    /// # Errors
+    /// If an error is encountered during matching, it returns a [`RegexError`]. An error is also
+    /// returned if `string` is too long; see [Input length](crate::Regex#input-length).
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use tre_regex::{RegcompFlags, RegexecFlags, Regex};
+    /// use widestring::widestr;
+    ///
+    /// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    /// let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    ///
+    /// let compiled_reg = Regex::new_wide(widestr!("^[a-z]:\\\\"), regcomp_flags)?;
+    /// assert!(compiled_reg.is_wmatch(widestr!("c:\\Windows"), regexec_flags)?);
+    /// assert!(!compiled_reg.is_wmatch(widestr!("/usr"), regexec_flags)?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`RegexError`]: crate::RegexError
+    #[inline]
+    pub fn is_wmatch(&self, string: &WideStr, flags: RegexecFlags) -> Result<bool> {
+        self.regwexec_slice(string, &mut [], flags)
+    }
+
+    /// Finds the first match of the whole pattern in the wide string `string`.
+    ///
+    /// # Arguments
+    /// * `string`: [`WideStr`] to match against `compiled_reg`
+    /// * `flags`: [`RegexecFlags`] to pass to [`tre_regwnexec`](tre_regex_sys::tre_regwnexec).
+    ///
+    /// # Returns
+    /// `None` if there was no match, otherwise the start and end offsets of the match in
+    /// `string`, in wide characters.
+    ///
+    /// # Errors
+    /// If an error is encountered during matching, it returns a [`RegexError`]. An error is also
+    /// returned if `string` is too long; see [Input length](crate::Regex#input-length).
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use tre_regex::{RegcompFlags, RegexecFlags, Regex};
+    /// use widestring::widestr;
+    ///
+    /// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    /// let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    ///
+    /// let compiled_reg = Regex::new_wide(widestr!("[0-9]+"), regcomp_flags)?;
+    /// let found = compiled_reg.find_wide(widestr!("abc 123"), regexec_flags)?;
+    ///
+    /// assert_eq!(found, Some((4, 7)));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`RegexError`]: crate::RegexError
+    pub fn find_wide(
+        &self,
+        string: &WideStr,
+        flags: RegexecFlags,
+    ) -> Result<Option<(usize, usize)>> {
+        let mut pmatch = [tre::regmatch_t { rm_so: 0, rm_eo: 0 }];
+        if !self.regwexec_slice(string, &mut pmatch, flags)? {
+            return Ok(None);
+        }
+
+        Ok(match_range(pmatch[0], 0))
+    }
+
+    /// Performs a regex search on the passed wide string, returning its capture groups.
+    ///
+    /// This is like [`Regex::regwexec`], but returns `None` rather than an error if there is no
+    /// match, and keeps the offsets of each group.
+    ///
+    /// # Arguments
+    /// * `string`: [`WideStr`] to match against `compiled_reg`
+    /// * `nmatches`: number of groups to return, including group 0
+    /// * `flags`: [`RegexecFlags`] to pass to [`tre_regwnexec`](tre_regex_sys::tre_regwnexec).
+    ///
+    /// # Returns
+    /// `None` if there was no match, otherwise the [`WideCaptures`] of the match.
+    ///
+    /// # Errors
+    /// If an error is encountered during matching, it returns a [`RegexError`]. An error is also
+    /// returned if `string` is too long; see [Input length](crate::Regex#input-length).
+    ///
+    /// # Examples
+    /// ```
+    /// # use tre_regex::Result;
+    /// # fn main() -> Result<()> {
+    /// use tre_regex::{RegcompFlags, RegexecFlags, Regex};
+    /// use widestring::widestr;
+    ///
+    /// let regcomp_flags = RegcompFlags::new().add(RegcompFlags::EXTENDED);
+    /// let regexec_flags = RegexecFlags::new().add(RegexecFlags::NONE);
+    ///
+    /// let compiled_reg = Regex::new_wide(widestr!("([a-z]+)=([0-9]+)?"), regcomp_flags)?;
+    /// let caps = compiled_reg.captures_wide(widestr!("> size="), 3, regexec_flags)?;
+    /// let caps = caps.expect("match");
+    ///
+    /// assert_eq!(caps.get(1), Some(widestr!("size")));
+    /// assert_eq!(caps.pos(1), Some((2, 6)));
+    /// assert_eq!(caps.get(2), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`RegexError`]: crate::RegexError
+    pub fn captures_wide<'a>(
+        &self,
+        string: &'a WideStr,
+        nmatches: usize,
+        flags: RegexecFlags,
+    ) -> Result<Option<WideCaptures<'a>>> {
+        let mut match_vec = vec![tre::regmatch_t { rm_so: 0, rm_eo: 0 }; nmatches];
+        if !self.regwexec_slice(string, &mut match_vec, flags)? {
+            return Ok(None);
+        }
+
+        Ok(Some(WideCaptures {
+            string,
+            locs: match_vec
+                .into_iter()
+                .map(|pmatch| match_range(pmatch, 0))
+                .collect(),
+        }))
+    }
+
+    /// Performs a regex search on the passed wide string, filling in `match_vec`.
+    ///
+    /// If `match_vec` is empty, no offsets are requested at all.
+    ///
+    /// # Returns
+    /// `false` if there was no match, `true` otherwise.
+    fn regwexec_slice(
+        &self,
+        string: &WideStr,
+        match_vec: &mut [tre::regmatch_t],
+        flags: RegexecFlags,
+    ) -> Result<bool> {
         let Some(compiled_reg_obj) = self.get() else {
             return Err(RegexError::new(
                 ErrorKind::Binding(BindingErrorCode::REGEX_VACANT),
                 "Attempted to unwrap a vacant Regex object"
             ));
         };
-        let mut match_vec: Vec<tre::regmatch_t> =
-            vec![tre::regmatch_t { rm_so: 0, rm_eo: 0 }; nmatches];
+        check_len(string.len())?;
+        let match_ptr = if match_vec.is_empty() {
+            null_mut()
+        } else {
+            match_vec.as_mut_ptr()
+        };
 
         // SAFETY: compiled_reg is a wrapped type (see safety concerns for Regex). string is read-only.
         // match_vec has enough room for everything. flags also cannot wrap around.
@@ -79,32 +439,39 @@ impl Regex {
                 compiled_reg_obj,
                 string.as_ptr() as *const _,
                 string.len(),
-                nmatches,
-                match_vec.as_mut_ptr(),
+                match_vec.len(),
+                match_ptr,
                 flags.get(),
             )
         };
-        if result != 0 {
-            return Err(self.regerror(result));
-        }
 
-        let mut result: Vec<Option<Cow<'a, WideStr>>> = Vec::with_capacity(nmatches);
-        for pmatch in match_vec {
-            if pmatch.rm_so < 0 || pmatch.rm_eo < 0 {
-                result.push(None);
-                continue;
-            }
+        // Value cannot ever be negative.
+        #[allow(clippy::cast_sign_loss)]
+        let errcode = tre::reg_errcode_t(result as c_uint);
+        if errcode == tre::reg_errcode_t::REG_NOMATCH {
+            return Ok(false);
+        } else if result != 0 {
+            return Err(self.exec_error(result));
+        }
 
-            // Wraparound is impossible.
-            #[allow(clippy::cast_sign_loss)]
-            let start_offset = pmatch.rm_so as usize;
-            #[allow(clippy::cast_sign_loss)]
-            let end_offset = pmatch.rm_eo as usize;
+        Ok(true)
+    }
 
-            result.push(Some(Cow::Borrowed(&string[start_offset..end_offset])));
+    /// Performs a regex search on the passed wide string, returning an error if there was no
+    /// match.
+    fn regwexec_slice_matched(
+        &self,
+        string: &WideStr,
+        match_vec: &mut [tre::regmatch_t],
+        flags: RegexecFlags,
+    ) -> Result<()> {
+        if !self.regwexec_slice(string, match_vec, flags)? {
+            // Value cannot ever wrap around.
+            #[allow(clippy::cast_possible_wrap)]
+            return Err(self.exec_error(tre::reg_errcode_t::REG_NOMATCH.0 as ErrorInt));
         }
 
-        Ok(result)
+        Ok(())
     }
 }
 
@@ -1,7 +1,9 @@
 mod approx;
 mod comp;
 mod exec;
+mod support;
 
 pub use crate::wchar::approx::*;
 pub use crate::wchar::comp::*;
 pub use crate::wchar::exec::*;
+pub use crate::wchar::support::*;
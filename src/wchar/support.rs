@@ -0,0 +1,24 @@
+use crate::{info::query_flag, tre};
+
+/// Checks whether the linked TRE library was built with wide character support.
+///
+/// The wide string functions, such as [`Regex::new_wide`](crate::Regex::new_wide) and
+/// [`Regex::regwexec`](crate::Regex::regwexec), need TRE to be built with `wchar_t` support. This
+/// is the case for the vendored copy of TRE, but a system TRE may have been built without it. Call
+/// this to find out up front, instead of having the wide functions fail.
+///
+/// # Returns
+/// `true` if the linked TRE supports wide characters, `false` otherwise.
+///
+/// # Examples
+/// ```
+/// use tre_regex::is_wchar_supported;
+///
+/// if !is_wchar_supported() {
+///     println!("Wide string matching is not available");
+/// }
+/// ```
+#[must_use]
+pub fn is_wchar_supported() -> bool {
+    query_flag(tre::TRE_CONFIG_WCHAR)
+}